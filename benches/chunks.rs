@@ -0,0 +1,112 @@
+//! Microbenchmarks of the per-element cost of `Chunk`, against
+//! `slice::chunks`.
+//!
+//! Run with `cargo bench --bench chunks`. Every case checks its result, so CI
+//! runs it too.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use iter_chunks::IterChunks;
+
+// Small enough to stay in the cache, so that only the iteration is timed.
+const LEN: u64 = 1 << 14;
+const N: usize = 64;
+const REPS: usize = 64;
+const ROUNDS: usize = 50;
+
+/// Prints the best time per element of `f` over `ROUNDS` runs. `f` returns
+/// the sum of the elements, which is checked once, so that a case can't skip
+/// elements.
+fn bench(name: &str, mut f: impl FnMut() -> u64) {
+    assert_eq!(f(), (0..LEN).sum::<u64>(), "{name}");
+    let best = (0..ROUNDS)
+        .map(|_| {
+            let start = Instant::now();
+            for _ in 0..REPS {
+                black_box(f());
+            }
+            start.elapsed()
+        })
+        .min()
+        .unwrap_or(Duration::ZERO);
+    println!(
+        "{name:<32} {:>8.3} ns/element",
+        best.as_secs_f64() * 1e9 / (LEN as usize * REPS) as f64
+    );
+}
+
+fn main() {
+    let data = (0..LEN).collect::<Vec<_>>();
+
+    bench("slice::chunks, for loop", || {
+        let mut sum = 0u64;
+        for chunk in black_box(&data).chunks(N) {
+            for &v in chunk {
+                sum = sum.wrapping_add(v);
+            }
+        }
+        sum
+    });
+
+    bench("chunks over a range, for loop", || {
+        let mut sum = 0u64;
+        black_box(0..LEN).chunks(N).for_each(|chunk| {
+            for v in chunk {
+                sum = sum.wrapping_add(v);
+            }
+        });
+        sum
+    });
+
+    bench("chunks over a slice, for loop", || {
+        let mut sum = 0u64;
+        black_box(&data).iter().chunks(N).for_each(|chunk| {
+            for &v in chunk {
+                sum = sum.wrapping_add(v);
+            }
+        });
+        sum
+    });
+
+    bench("chunks of 8 over a slice, for loop", || {
+        let mut sum = 0u64;
+        black_box(&data).iter().chunks(8).for_each(|chunk| {
+            for &v in chunk {
+                sum = sum.wrapping_add(v);
+            }
+        });
+        sum
+    });
+
+    bench("chunks over a chain, for loop", || {
+        let (a, b) = black_box(&data).split_at(data.len() / 2);
+        let mut sum = 0u64;
+        a.iter().chain(b).chunks(N).for_each(|chunk| {
+            for &v in chunk {
+                sum = sum.wrapping_add(v);
+            }
+        });
+        sum
+    });
+
+    bench("chunks over a slice, while let", || {
+        let mut sum = 0u64;
+        let mut chunks = black_box(&data).iter().chunks(N);
+        while let Some(mut chunk) = chunks.next() {
+            #[allow(clippy::while_let_on_iterator)]
+            while let Some(&v) = chunk.next() {
+                sum = sum.wrapping_add(v);
+            }
+        }
+        sum
+    });
+
+    bench("chunks over a slice, sum", || {
+        let mut sum = 0u64;
+        black_box(&data).iter().chunks(N).for_each(|chunk| {
+            sum = sum.wrapping_add(chunk.sum::<u64>());
+        });
+        sum
+    });
+}
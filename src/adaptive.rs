@@ -0,0 +1,115 @@
+use std::time::{Duration, Instant};
+
+use crate::{Chunk, Chunks};
+
+/// An iterator-like struct that yields chunks whose sizes are chosen by a
+/// feedback closure.
+///
+/// This `struct` is created by [`chunks_adaptive`] method on [`IterChunks`].
+/// See its documentation for more.
+///
+/// [`chunks_adaptive`]: crate::IterChunks::chunks_adaptive
+/// [`IterChunks`]: crate::IterChunks
+pub struct AdaptiveChunks<I: Iterator, F> {
+    chunks: Chunks<I>,
+    f: F,
+    // The consumed count and the time when the last chunk was yielded.
+    last: Option<(usize, Instant)>,
+}
+
+impl<I, F> AdaptiveChunks<I, F>
+where
+    I: Iterator,
+    F: FnMut(usize, Duration) -> usize,
+{
+    pub(crate) fn new(inner: I, initial: usize, f: F) -> Self {
+        Self {
+            chunks: Chunks::new(inner, initial),
+            f,
+            last: None,
+        }
+    }
+
+    /// Returns the size of the next chunk, as chosen by the initial size or
+    /// the last feedback.
+    pub fn chunk_size(&self) -> usize {
+        self.chunks.chunk_size()
+    }
+
+    /// Similar to [`Iterator::next`], but not implements [`Iterator`] due to
+    /// lifetime.
+    ///
+    /// If a chunk was yielded before, the feedback closure is called first
+    /// with the number of elements consumed from that chunk and the time
+    /// elapsed since it was yielded, and its result becomes the size of the
+    /// next chunk.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the feedback closure returns 0.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<Chunk<'_, I>> {
+        if let Some((consumed, at)) = self.last.take() {
+            let last_len = self.chunks.consumed() - consumed;
+            let n = (self.f)(last_len, at.elapsed());
+            assert_ne!(n, 0);
+            self.chunks.n = n;
+        }
+        let first = self.chunks.next_first()?;
+        // The first element is already pulled and counts as consumed.
+        self.last = Some((self.chunks.consumed() - 1, Instant::now()));
+        Some(self.chunks.chunk_with_first(first))
+    }
+
+    /// Similar to [`Iterator::for_each`].
+    pub fn for_each(&mut self, mut f: impl FnMut(Chunk<'_, I>)) {
+        while let Some(item) = self.next() {
+            f(item)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IterChunks;
+
+    #[test]
+    fn test_chunks_adaptive() {
+        let mut feedback = vec![];
+        let mut chunks = (0..20).chunks_adaptive(1, |last_len, _| {
+            feedback.push(last_len);
+            last_len * 2
+        });
+        let mut res = vec![];
+        while let Some(chunk) = chunks.next() {
+            res.push(chunk.collect::<Vec<_>>());
+        }
+        assert_eq!(
+            res,
+            vec![
+                vec![0],
+                vec![1, 2],
+                vec![3, 4, 5, 6],
+                vec![7, 8, 9, 10, 11, 12, 13, 14],
+                vec![15, 16, 17, 18, 19],
+            ]
+        );
+        drop(chunks);
+        assert_eq!(feedback, vec![1, 2, 4, 8, 5]);
+    }
+
+    #[test]
+    fn test_chunks_adaptive_partial() {
+        let mut feedback = vec![];
+        let mut chunks = (0..10).chunks_adaptive(4, |last_len, _| {
+            feedback.push(last_len);
+            4
+        });
+        // Only consume 1 element of the first chunk.
+        assert_eq!(chunks.next().unwrap().next(), Some(0));
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+        assert_eq!(chunks.chunk_size(), 4);
+        drop(chunks);
+        assert_eq!(feedback, vec![1]);
+    }
+}
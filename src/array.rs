@@ -0,0 +1,173 @@
+use alloc::vec::Vec;
+use core::array;
+use core::mem::{self, MaybeUninit};
+use core::ptr;
+
+// Drops the initialized front of the array if the iterator panics.
+struct Guard<'a, T, const N: usize> {
+    buf: &'a mut [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl<T, const N: usize> Drop for Guard<'_, T, N> {
+    fn drop(&mut self) {
+        let init = ptr::slice_from_raw_parts_mut(self.buf.as_mut_ptr().cast::<T>(), self.len);
+        // SAFETY: The first `len` elements are initialized.
+        unsafe { ptr::drop_in_place(init) }
+    }
+}
+
+pub(crate) fn next_array_partial<I: Iterator, const N: usize>(
+    iter: &mut I,
+) -> Result<[I::Item; N], Vec<I::Item>> {
+    let mut buf = [const { MaybeUninit::uninit() }; N];
+    let mut guard = Guard {
+        buf: &mut buf,
+        len: 0,
+    };
+    while guard.len < N {
+        let Some(v) = iter.next() else {
+            let len = mem::replace(&mut guard.len, 0);
+            // SAFETY: The first `len` elements are initialized, and the guard
+            // no longer owns them.
+            let tail = guard.buf[..len]
+                .iter()
+                .map(|v| unsafe { v.assume_init_read() })
+                .collect();
+            return Err(tail);
+        };
+        guard.buf[guard.len].write(v);
+        guard.len += 1;
+    }
+    mem::forget(guard);
+    // SAFETY: All `N` elements are initialized, and `MaybeUninit<T>` has the
+    // same layout as `T`.
+    Ok(unsafe { ptr::read(buf.as_ptr().cast::<[I::Item; N]>()) })
+}
+
+/// An iterator over overlapping windows of `N` elements, as arrays.
+///
+/// This `struct` is created by [`array_windows`] method on [`IterChunks`].
+/// See its documentation for more.
+///
+/// [`array_windows`]: crate::IterChunks::array_windows
+/// [`IterChunks`]: crate::IterChunks
+#[derive(Debug, Clone)]
+pub struct ArrayWindows<I: Iterator, const N: usize> {
+    inner: I,
+    // A ring buffer of the last window, which starts at `head`.
+    buf: Option<[I::Item; N]>,
+    head: usize,
+}
+
+impl<I: Iterator, const N: usize> ArrayWindows<I, N> {
+    pub(crate) fn new(inner: I) -> Self {
+        assert_ne!(N, 0);
+        Self {
+            inner,
+            buf: None,
+            head: 0,
+        }
+    }
+}
+
+impl<I, const N: usize> Iterator for ArrayWindows<I, N>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.buf {
+            Some(buf) => {
+                // The oldest element is replaced by the new one.
+                buf[self.head] = self.inner.next()?;
+                self.head = (self.head + 1) % N;
+                let head = self.head;
+                Some(array::from_fn(|i| buf[(head + i) % N].clone()))
+            }
+            None => {
+                let buf = next_array_partial(&mut self.inner).ok()?;
+                Some(self.buf.insert(buf).clone())
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        if self.buf.is_some() {
+            (lower, upper)
+        } else {
+            (
+                lower.saturating_sub(N - 1),
+                upper.map(|v| v.saturating_sub(N - 1)),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::panic::{self, AssertUnwindSafe};
+
+    use crate::IterChunks;
+
+    #[test]
+    fn test_next_array() {
+        let mut iter = 0..7;
+        assert_eq!(iter.next_array::<3>(), Some([0, 1, 2]));
+        assert_eq!(iter.next_array::<3>(), Some([3, 4, 5]));
+        assert_eq!(iter.next_array::<3>(), None);
+        assert_eq!(iter.next_array::<0>(), Some([]));
+
+        let mut iter = ["a", "b", "c"].into_iter().map(String::from);
+        assert_eq!(iter.next_array_partial::<2>(), Ok(["a".into(), "b".into()]));
+        assert_eq!(iter.next_array_partial::<2>(), Err(vec!["c".to_string()]));
+        assert_eq!(iter.next_array_partial::<2>(), Err(vec![]));
+    }
+
+    #[test]
+    fn test_array_windows() {
+        let windows = (0..5).array_windows::<3>();
+        assert_eq!(windows.size_hint(), (3, Some(3)));
+        assert_eq!(
+            windows.collect::<Vec<_>>(),
+            [[0, 1, 2], [1, 2, 3], [2, 3, 4]]
+        );
+
+        let mut windows = ["a", "b"]
+            .into_iter()
+            .map(String::from)
+            .array_windows::<1>();
+        assert_eq!(windows.next(), Some(["a".to_string()]));
+        assert_eq!(windows.size_hint(), (1, Some(1)));
+        assert_eq!(windows.next(), Some(["b".to_string()]));
+        assert_eq!(windows.next(), None);
+
+        assert_eq!((0..2).array_windows::<3>().count(), 0);
+        assert_eq!((0..2).array_windows::<3>().size_hint(), (0, Some(0)));
+    }
+
+    #[test]
+    fn test_next_array_panic() {
+        struct Counted<'a>(&'a Cell<usize>);
+
+        impl Drop for Counted<'_> {
+            fn drop(&mut self) {
+                self.0.set(self.0.get() + 1);
+            }
+        }
+
+        // The elements pulled before a panic are dropped exactly once.
+        let dropped = Cell::new(0);
+        let mut iter = (0..3).map(|i| {
+            assert_ne!(i, 2);
+            Counted(&dropped)
+        });
+        let res = panic::catch_unwind(AssertUnwindSafe(|| iter.next_array::<3>()));
+        assert!(res.is_err());
+        assert_eq!(dropped.get(), 2);
+    }
+}
@@ -0,0 +1,100 @@
+use ndarray::Array2;
+
+use crate::{Chunks, IncompleteChunk};
+
+impl<I: Iterator> Chunks<I> {
+    /// Collects the chunks into an [`Array2`] with one row per chunk, and the
+    /// chunk size as the number of columns.
+    ///
+    /// A final chunk shorter than the chunk size is reported as
+    /// [`IncompleteChunk`]. Use [`Chunks::collect_array2_padded`] to pad it
+    /// instead.
+    ///
+    /// ```
+    /// use iter_chunks::{IncompleteChunk, IterChunks};
+    /// use ndarray::array;
+    ///
+    /// let samples = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    /// let batch = samples.into_iter().chunks(3).collect_array2().unwrap();
+    /// assert_eq!(batch, array![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    ///
+    /// let err = (0..5).chunks(3).collect_array2().unwrap_err();
+    /// assert_eq!(err, IncompleteChunk { expected: 3, got: 2 });
+    /// ```
+    pub fn collect_array2(self) -> Result<Array2<I::Item>, IncompleteChunk> {
+        let n = self.n;
+        let data = self.collect_flat();
+        let got = data.len() % n;
+        if got != 0 {
+            return Err(IncompleteChunk { expected: n, got });
+        }
+        Ok(Self::into_array2(data, n))
+    }
+
+    /// Similar to [`Chunks::collect_array2`], but a short final chunk is
+    /// padded with clones of `pad`.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    /// use ndarray::array;
+    ///
+    /// let batch = (1..=5).chunks(3).collect_array2_padded(0);
+    /// assert_eq!(batch, array![[1, 2, 3], [4, 5, 0]]);
+    /// ```
+    pub fn collect_array2_padded(self, pad: I::Item) -> Array2<I::Item>
+    where
+        I::Item: Clone,
+    {
+        let n = self.n;
+        let mut data = self.collect_flat();
+        data.resize(data.len().next_multiple_of(n), pad);
+        Self::into_array2(data, n)
+    }
+
+    /// Collects the elements of all chunks, up to the end of the underlying
+    /// iterator.
+    fn collect_flat(mut self) -> Vec<I::Item> {
+        let mut data = Vec::new();
+        while let Some(chunk) = self.next() {
+            data.extend(chunk);
+        }
+        data
+    }
+
+    fn into_array2(data: Vec<I::Item>, n: usize) -> Array2<I::Item> {
+        Array2::from_shape_vec((data.len() / n, n), data)
+            .expect("the length is a multiple of the chunk size")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ndarray::Array2;
+
+    use crate::{IncompleteChunk, IterChunks};
+
+    #[test]
+    fn test_collect_array2() {
+        let arr = (0..6).chunks(2).collect_array2().unwrap();
+        assert_eq!(arr.dim(), (3, 2));
+        assert_eq!(arr.row(2).to_vec(), vec![4, 5]);
+
+        let arr = std::iter::empty::<i32>()
+            .chunks(2)
+            .collect_array2()
+            .unwrap();
+        assert_eq!(arr, Array2::<i32>::zeros((0, 2)));
+
+        assert_eq!(
+            (0..7).chunks(3).collect_array2(),
+            Err(IncompleteChunk {
+                expected: 3,
+                got: 1
+            })
+        );
+
+        let arr = (0..7).chunks(3).collect_array2_padded(-1);
+        assert_eq!(arr.dim(), (3, 3));
+        assert_eq!(arr.row(2).to_vec(), vec![6, -1, -1]);
+    }
+}
@@ -0,0 +1,118 @@
+use arrayvec::{ArrayVec, CapacityError};
+
+use crate::{Chunk, Chunks};
+
+impl<I: Iterator> Chunk<'_, I> {
+    /// Collects the rest of the chunk into an [`ArrayVec`] with capacity
+    /// `CAP`.
+    ///
+    /// If the chunk has more than `CAP` elements left, the first element that
+    /// does not fit is returned in the error, and the elements after it are
+    /// left to the next chunk.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut chunks = (0..10).chunks(4);
+    /// let chunk = chunks.next().unwrap().collect_arrayvec::<4>().unwrap();
+    /// assert_eq!(chunk.as_slice(), &[0, 1, 2, 3]);
+    /// let err = chunks.next().unwrap().collect_arrayvec::<2>().unwrap_err();
+    /// assert_eq!(err.element(), 6);
+    /// ```
+    pub fn collect_arrayvec<const CAP: usize>(
+        mut self,
+    ) -> Result<ArrayVec<I::Item, CAP>, CapacityError<I::Item>> {
+        let mut v = ArrayVec::new();
+        for item in &mut self {
+            v.try_push(item)?;
+        }
+        Ok(v)
+    }
+}
+
+impl<I: Iterator> Chunks<I> {
+    /// Converts into an [`Iterator`] that yields every chunk as an
+    /// [`ArrayVec`].
+    ///
+    /// Returns an error if the chunk size exceeds `CAP`, so that every chunk
+    /// is guaranteed to fit.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let chunks = (0..10).chunks(4).into_arrayvecs::<4>().unwrap();
+    /// let sums = chunks.map(|c| c.iter().sum::<i32>()).collect::<Vec<_>>();
+    /// assert_eq!(sums, vec![6, 22, 17]);
+    ///
+    /// assert!((0..10).chunks(4).into_arrayvecs::<3>().is_err());
+    /// ```
+    // The error gives `self` back, so it's as large as `Chunks`.
+    #[allow(clippy::result_large_err)]
+    pub fn into_arrayvecs<const CAP: usize>(
+        self,
+    ) -> Result<IntoArrayVecs<I, CAP>, CapacityError<Self>> {
+        if self.n > CAP {
+            return Err(CapacityError::new(self));
+        }
+        Ok(IntoArrayVecs { chunks: self })
+    }
+}
+
+/// An iterator that yields every chunk as an [`ArrayVec`].
+///
+/// This `struct` is created by [`Chunks::into_arrayvecs`]. See its
+/// documentation for more.
+pub struct IntoArrayVecs<I: Iterator, const CAP: usize> {
+    chunks: Chunks<I>,
+}
+
+impl<I: Iterator, const CAP: usize> IntoArrayVecs<I, CAP> {
+    /// Consumes the adaptor and returns the underlying [`Chunks`].
+    pub fn into_inner(self) -> Chunks<I> {
+        self.chunks
+    }
+}
+
+impl<I: Iterator, const CAP: usize> Iterator for IntoArrayVecs<I, CAP> {
+    type Item = ArrayVec<I::Item, CAP>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // The chunk size is checked against `CAP` on construction.
+        self.chunks.next().map(|chunk| chunk.collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IterChunks;
+
+    #[test]
+    fn test_collect_arrayvec() {
+        let mut chunks = (0..8).chunks(3);
+        let mut chunk = chunks.next().unwrap();
+        chunk.next();
+        assert_eq!(chunk.collect_arrayvec::<2>().unwrap().as_slice(), &[1, 2]);
+
+        let err = chunks.next().unwrap().collect_arrayvec::<1>().unwrap_err();
+        assert_eq!(err.element(), 4);
+        // The element after the overflowing one is left to the next chunk.
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![5, 6, 7]);
+    }
+
+    #[test]
+    fn test_into_arrayvecs() {
+        let res = (0..7)
+            .chunks(3)
+            .into_arrayvecs::<4>()
+            .unwrap()
+            .map(|v| v.to_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(res, vec![vec![0, 1, 2], vec![3, 4, 5], vec![6]]);
+
+        let Err(err) = (0..7).chunks(3).into_arrayvecs::<2>() else {
+            panic!("chunk size exceeds capacity");
+        };
+        let mut chunks = err.element();
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![0, 1, 2]);
+    }
+}
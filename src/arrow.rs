@@ -0,0 +1,202 @@
+use arrow_array::builder::{make_builder, ArrayBuilder};
+use arrow_array::{ArrayRef, RecordBatch};
+use arrow_schema::{ArrowError, SchemaRef};
+
+use crate::Chunks;
+
+impl<I: Iterator> Chunks<I> {
+    /// Converts into an [`Iterator`] that appends every chunk to `builder`,
+    /// and yields it as an [`ArrayRef`] of at most `n` rows.
+    ///
+    /// The builder is reused, since [`ArrayBuilder::finish`] resets it.
+    ///
+    /// ```
+    /// use arrow_array::builder::Int32Builder;
+    /// use arrow_array::Array;
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let rows = [Some(1), None, Some(3)];
+    /// let arrays = rows
+    ///     .into_iter()
+    ///     .chunks(2)
+    ///     .into_arrow_arrays(Int32Builder::new())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(arrays.len(), 2);
+    /// assert_eq!(arrays[0].len(), 2);
+    /// assert_eq!(arrays[0].null_count(), 1);
+    /// ```
+    pub fn into_arrow_arrays<B>(self, builder: B) -> ArrowArrays<I, B>
+    where
+        B: ArrayBuilder + Extend<I::Item>,
+    {
+        ArrowArrays {
+            chunks: self,
+            builder,
+        }
+    }
+
+    /// Converts into an [`Iterator`] that yields every chunk as a
+    /// [`RecordBatch`] of at most `n` rows with `schema`.
+    ///
+    /// A builder is made for every field of `schema`, and `append` is called
+    /// with the builders (in field order) and every element to append one
+    /// row. An error is yielded if the columns don't match `schema`.
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    ///
+    /// use arrow_array::builder::{Int64Builder, StringBuilder};
+    /// use arrow_schema::{DataType, Field, Schema};
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let schema = Arc::new(Schema::new(vec![
+    ///     Field::new("id", DataType::Int64, false),
+    ///     Field::new("name", DataType::Utf8, false),
+    /// ]));
+    /// let rows = [(1, "a"), (2, "b"), (3, "c")];
+    /// let batches = rows
+    ///     .into_iter()
+    ///     .chunks(2)
+    ///     .into_record_batches(schema, |builders, (id, name)| {
+    ///         let ids = builders[0].as_any_mut().downcast_mut::<Int64Builder>();
+    ///         ids.unwrap().append_value(id);
+    ///         let names = builders[1].as_any_mut().downcast_mut::<StringBuilder>();
+    ///         names.unwrap().append_value(name);
+    ///     })
+    ///     .collect::<Result<Vec<_>, _>>()
+    ///     .unwrap();
+    /// assert_eq!(batches.len(), 2);
+    /// assert_eq!(batches[0].num_rows(), 2);
+    /// assert_eq!(batches[1].num_rows(), 1);
+    /// ```
+    pub fn into_record_batches<F>(self, schema: SchemaRef, append: F) -> RecordBatches<I, F>
+    where
+        F: FnMut(&mut [Box<dyn ArrayBuilder>], I::Item),
+    {
+        let builders = schema
+            .fields()
+            .iter()
+            .map(|field| make_builder(field.data_type(), self.n))
+            .collect();
+        RecordBatches {
+            chunks: self,
+            schema,
+            builders,
+            append,
+        }
+    }
+}
+
+/// An iterator that yields every chunk as an [`ArrayRef`].
+///
+/// This `struct` is created by [`Chunks::into_arrow_arrays`]. See its
+/// documentation for more.
+pub struct ArrowArrays<I: Iterator, B> {
+    chunks: Chunks<I>,
+    builder: B,
+}
+
+impl<I: Iterator, B> ArrowArrays<I, B> {
+    /// Consumes the adaptor and returns the underlying [`Chunks`].
+    pub fn into_inner(self) -> Chunks<I> {
+        self.chunks
+    }
+}
+
+impl<I, B> Iterator for ArrowArrays<I, B>
+where
+    I: Iterator,
+    B: ArrayBuilder + Extend<I::Item>,
+{
+    type Item = ArrayRef;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.builder.extend(self.chunks.next()?);
+        Some(self.builder.finish())
+    }
+}
+
+/// An iterator that yields every chunk as a [`RecordBatch`].
+///
+/// This `struct` is created by [`Chunks::into_record_batches`]. See its
+/// documentation for more.
+pub struct RecordBatches<I: Iterator, F> {
+    chunks: Chunks<I>,
+    schema: SchemaRef,
+    builders: Vec<Box<dyn ArrayBuilder>>,
+    append: F,
+}
+
+impl<I: Iterator, F> RecordBatches<I, F> {
+    /// Consumes the adaptor and returns the underlying [`Chunks`].
+    pub fn into_inner(self) -> Chunks<I> {
+        self.chunks
+    }
+}
+
+impl<I, F> Iterator for RecordBatches<I, F>
+where
+    I: Iterator,
+    F: FnMut(&mut [Box<dyn ArrayBuilder>], I::Item),
+{
+    type Item = Result<RecordBatch, ArrowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for v in self.chunks.next()? {
+            (self.append)(&mut self.builders, v);
+        }
+        let columns = self.builders.iter_mut().map(|b| b.finish()).collect();
+        Some(RecordBatch::try_new(self.schema.clone(), columns))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_array::builder::{Float64Builder, StringBuilder};
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Float64Type;
+    use arrow_array::Array;
+    use arrow_schema::{DataType, Field, Schema};
+
+    use crate::IterChunks;
+
+    #[test]
+    fn test_into_arrow_arrays() {
+        let names = ["a", "b", "c", "d", "e"].map(Some);
+        let arrays = names
+            .into_iter()
+            .chunks(2)
+            .into_arrow_arrays(StringBuilder::new())
+            .collect::<Vec<_>>();
+        let lens = arrays.iter().map(|a| a.len()).collect::<Vec<_>>();
+        assert_eq!(lens, [2, 2, 1]);
+        assert_eq!(arrays[2].as_string::<i32>().value(0), "e");
+    }
+
+    #[test]
+    fn test_into_record_batches() {
+        let schema = Arc::new(Schema::new(vec![Field::new("x", DataType::Float64, true)]));
+        let batches = (0..5)
+            .chunks(3)
+            .into_record_batches(schema.clone(), |builders, v| {
+                let b = builders[0].as_any_mut().downcast_mut::<Float64Builder>();
+                b.unwrap().append_value(v as f64);
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[1].schema(), schema);
+        let col = batches[1].column(0).as_primitive::<Float64Type>();
+        assert_eq!(col.values().to_vec(), vec![3.0, 4.0]);
+
+        // A null in a non-nullable column.
+        let schema = Arc::new(Schema::new(vec![Field::new("x", DataType::Float64, false)]));
+        let mut batches = (0..2).chunks(2).into_record_batches(schema, |builders, _| {
+            let b = builders[0].as_any_mut().downcast_mut::<Float64Builder>();
+            b.unwrap().append_null();
+        });
+        assert!(batches.next().unwrap().is_err());
+    }
+}
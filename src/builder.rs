@@ -0,0 +1,327 @@
+use crate::{Chunk, Chunks};
+
+// The padding element, with the `Clone::clone` of its type, so that only
+// `pad_with` requires `Clone`.
+type Pad<T> = (T, fn(&T) -> T);
+
+/// What happens to the rest of a chunk that was not fully consumed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LeftoverPolicy {
+    /// The rest is yielded first by the next chunk, and counts against its
+    /// size, so a chunk starts right after the last consumed element. This is
+    /// how [`Chunks`] behaves.
+    #[default]
+    CarryOver,
+    /// The rest is skipped, so that every chunk starts at a multiple of the
+    /// chunk size.
+    Skip,
+}
+
+/// A builder that composes the policies of chunking in one place.
+///
+/// It's created by [`Chunks::builder`]. Every policy is off by default, so
+/// `Chunks::builder(n).build(iter)` yields the same chunks as
+/// `iter.chunks(n)`.
+///
+/// ```
+/// use iter_chunks::Chunks;
+///
+/// let mut chunks = Chunks::builder(3)
+///     .pad_with(0)
+///     .min_last(2)
+///     .fused(true)
+///     .auto_align(true)
+///     .build([1, 2, 3, 4, 5].into_iter());
+/// let mut res = vec![];
+/// chunks.for_each(|chunk| res.push(chunk.collect::<Vec<_>>()));
+/// assert_eq!(res, vec![vec![1, 2, 3], vec![4, 5, 0]]);
+/// ```
+pub struct ChunksBuilder<T> {
+    n: usize,
+    pad: Option<Pad<T>>,
+    min_last: usize,
+    fused: bool,
+    leftover: LeftoverPolicy,
+}
+
+impl<T> ChunksBuilder<T> {
+    /// Creates a builder for chunks of `n` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    pub fn new(n: usize) -> Self {
+        assert_ne!(n, 0);
+        Self {
+            n,
+            pad: None,
+            min_last: 0,
+            fused: false,
+            leftover: LeftoverPolicy::CarryOver,
+        }
+    }
+
+    /// Pads a short final chunk with clones of `pad` up to the chunk size.
+    ///
+    /// The padding counts towards [`Chunks::elements_consumed`].
+    pub fn pad_with(mut self, pad: T) -> Self
+    where
+        T: Clone,
+    {
+        self.pad = Some((pad, T::clone));
+        self
+    }
+
+    /// Drops a final chunk with fewer than `min` elements instead of yielding
+    /// it.
+    ///
+    /// This is checked before padding, so a dropped chunk is never padded.
+    pub fn min_last(mut self, min: usize) -> Self {
+        self.min_last = min;
+        self
+    }
+
+    /// Makes the first `None` from the underlying iterator terminal, see
+    /// [`Chunks::non_resumable`].
+    pub fn fused(mut self, fused: bool) -> Self {
+        self.fused = fused;
+        self
+    }
+
+    /// Sets what happens to the rest of a chunk that was not fully consumed.
+    ///
+    /// The default is [`LeftoverPolicy::CarryOver`]: stream parsers that
+    /// consume a variable number of elements per record can leave the rest
+    /// of a record for the next chunk. [`LeftoverPolicy::Skip`] keeps strict
+    /// fixed boundaries instead.
+    ///
+    /// ```
+    /// use iter_chunks::{Chunks, LeftoverPolicy};
+    ///
+    /// let mut chunks = Chunks::builder(3)
+    ///     .leftover(LeftoverPolicy::CarryOver)
+    ///     .build(0..6);
+    /// assert_eq!(chunks.next().unwrap().take(2).collect::<Vec<_>>(), vec![0, 1]);
+    /// // The leftover 2 starts the next chunk, and counts against its size.
+    /// assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![2, 3, 4]);
+    /// assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![5]);
+    /// ```
+    pub fn leftover(mut self, policy: LeftoverPolicy) -> Self {
+        self.leftover = policy;
+        self
+    }
+
+    /// Skips the rest of a chunk that was not fully consumed, so that every
+    /// chunk starts at a multiple of the chunk size.
+    ///
+    /// This is a shorthand of [`ChunksBuilder::leftover`] with
+    /// [`LeftoverPolicy::Skip`] if `auto_align` is `true`, or
+    /// [`LeftoverPolicy::CarryOver`] otherwise.
+    pub fn auto_align(mut self, auto_align: bool) -> Self {
+        self.leftover = if auto_align {
+            LeftoverPolicy::Skip
+        } else {
+            LeftoverPolicy::CarryOver
+        };
+        self
+    }
+
+    /// Creates a [`ConfiguredChunks`] over `iter` with the configured policies.
+    pub fn build<I>(self, iter: I) -> ConfiguredChunks<I>
+    where
+        I: Iterator<Item = T>,
+    {
+        let mut chunks = Chunks::new(iter, self.n);
+        if self.fused {
+            chunks = chunks.non_resumable();
+        }
+        ConfiguredChunks {
+            chunks,
+            pad: self.pad,
+            min_last: self.min_last,
+            leftover: self.leftover,
+            next_start: 0,
+            buf: Vec::new(),
+        }
+    }
+}
+
+// The source type is fixed only so that `Chunks::builder(n)` needs no
+// annotation, the builder works for any iterator.
+impl<T> Chunks<core::iter::Empty<T>> {
+    /// Creates a [`ChunksBuilder`] for chunks of `n` elements, same as
+    /// [`ChunksBuilder::new`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    pub fn builder(n: usize) -> ChunksBuilder<T> {
+        ChunksBuilder::new(n)
+    }
+}
+
+/// An iterator-like struct that yields chunks with the policies of a
+/// [`ChunksBuilder`].
+///
+/// This `struct` is created by [`ChunksBuilder::build`]. See its documentation
+/// for more.
+pub struct ConfiguredChunks<I: Iterator> {
+    chunks: Chunks<I>,
+    pad: Option<Pad<I::Item>>,
+    min_last: usize,
+    leftover: LeftoverPolicy,
+    // The consumed count at which the next chunk starts with
+    // `LeftoverPolicy::Skip`.
+    next_start: usize,
+    buf: Vec<I::Item>,
+}
+
+impl<I: Iterator> ConfiguredChunks<I> {
+    /// Returns the configured chunk size `n`.
+    pub fn chunk_size(&self) -> usize {
+        self.chunks.chunk_size()
+    }
+
+    /// Similar to [`Iterator::next`], but not implements [`Iterator`] due to
+    /// lifetime.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<Chunk<'_, I>> {
+        let consumed = self.chunks.consumed();
+        if self.leftover == LeftoverPolicy::Skip && consumed < self.next_start {
+            #[cfg(feature = "tracing")]
+            self.chunks.finish_span();
+            let k = self.next_start - consumed;
+            if self.chunks.skip_elements(k) < k {
+                // This `None` reports the end.
+                self.chunks.end_flag = false;
+                self.next_start = self.chunks.consumed();
+                return None;
+            }
+        }
+        let n = self.chunks.chunk_size();
+        let start = self.chunks.consumed();
+        if self.pad.is_some() || self.min_last > 1 {
+            // The chunk is buffered to know whether it's a short final one.
+            if !self.chunks.next_buffered(&mut self.buf) {
+                return None;
+            }
+            let got = self.buf.len();
+            if got < self.min_last {
+                self.buf.clear();
+                // This `None` takes the place of the one of the short chunk.
+                self.chunks.end_flag = false;
+                self.next_start = self.chunks.consumed();
+                return None;
+            }
+            if let Some((pad, clone)) = &self.pad {
+                self.buf.extend((got..n).map(|_| clone(pad)));
+                self.chunks.pulled += n.saturating_sub(got);
+            }
+            self.chunks.unread(&mut self.buf);
+        }
+        self.next_start = start.saturating_add(n);
+        self.chunks.next()
+    }
+
+    /// Similar to [`Iterator::for_each`].
+    pub fn for_each(&mut self, mut f: impl FnMut(Chunk<'_, I>)) {
+        while let Some(item) = self.next() {
+            f(item)
+        }
+    }
+
+    /// Consumes the adaptor and returns the underlying [`Chunks`].
+    pub fn into_inner(self) -> Chunks<I> {
+        self.chunks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ChunksBuilder, LeftoverPolicy};
+
+    fn collect<I: Iterator>(mut chunks: super::ConfiguredChunks<I>) -> Vec<Vec<I::Item>> {
+        let mut res = vec![];
+        chunks.for_each(|chunk| res.push(chunk.collect()));
+        res
+    }
+
+    #[test]
+    fn test_chunks_builder() {
+        let res = collect(ChunksBuilder::new(3).build(0..7));
+        assert_eq!(res, vec![vec![0, 1, 2], vec![3, 4, 5], vec![6]]);
+
+        let res = collect(ChunksBuilder::new(3).pad_with(-1).build(0..7));
+        assert_eq!(res, vec![vec![0, 1, 2], vec![3, 4, 5], vec![6, -1, -1]]);
+
+        let res = collect(ChunksBuilder::new(3).min_last(2).build(0..7));
+        assert_eq!(res, vec![vec![0, 1, 2], vec![3, 4, 5]]);
+
+        let res = collect(ChunksBuilder::new(3).min_last(2).build(0..8));
+        assert_eq!(res, vec![vec![0, 1, 2], vec![3, 4, 5], vec![6, 7]]);
+
+        let res = collect(ChunksBuilder::new(3).pad_with(-1).build(0..6));
+        assert_eq!(res, vec![vec![0, 1, 2], vec![3, 4, 5]]);
+    }
+
+    #[test]
+    fn test_auto_align() {
+        let mut chunks = ChunksBuilder::new(3).auto_align(true).build(0..8);
+        assert_eq!(chunks.next().unwrap().next(), Some(0));
+        assert_eq!(
+            chunks.next().unwrap().take(2).collect::<Vec<_>>(),
+            vec![3, 4]
+        );
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![6, 7]);
+        assert!(chunks.next().is_none());
+
+        let mut chunks = ChunksBuilder::new(3)
+            .auto_align(true)
+            .pad_with(-1)
+            .build(0..4);
+        assert_eq!(chunks.next().unwrap().next(), Some(0));
+        assert_eq!(chunks.next().unwrap().next(), Some(3));
+        assert!(chunks.next().is_none());
+        assert_eq!(chunks.into_inner().elements_consumed(), 6);
+    }
+
+    #[test]
+    fn test_leftover() {
+        // Every chunk consumes one record, whose length is its first element.
+        let parse = |policy| {
+            let mut chunks = ChunksBuilder::new(4)
+                .leftover(policy)
+                .build([2, 0, 1, 0, 3, 0, 0, 1, 0].into_iter());
+            let mut res = vec![];
+            while let Some(mut chunk) = chunks.next() {
+                let len = chunk.next().unwrap();
+                res.push(chunk.take(len).collect::<Vec<_>>());
+            }
+            res
+        };
+        assert_eq!(
+            parse(LeftoverPolicy::CarryOver),
+            vec![vec![0, 1], vec![], vec![0, 0, 1], vec![]]
+        );
+        assert_eq!(
+            parse(LeftoverPolicy::Skip),
+            vec![vec![0, 1], vec![0, 0, 1], vec![]]
+        );
+    }
+
+    #[test]
+    fn test_fused() {
+        let mut i = 0;
+        let resumable = std::iter::from_fn(move || {
+            i += 1;
+            (i != 3).then_some(i)
+        });
+        let mut chunks = ChunksBuilder::new(2)
+            .min_last(2)
+            .fused(true)
+            .build(resumable);
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![1, 2]);
+        assert!(chunks.next().is_none());
+        assert!(chunks.next().is_none());
+    }
+}
@@ -0,0 +1,115 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::{Chunk, Chunks};
+
+/// A panic caught by [`Chunks::for_each_catch`].
+#[derive(Debug)]
+pub struct ChunkPanic {
+    /// The index of the chunk that panicked, counting from the first chunk
+    /// passed to [`Chunks::for_each_catch`].
+    pub index: usize,
+    /// The payload of the panic, see [`std::panic::catch_unwind`].
+    pub payload: Box<dyn Any + Send>,
+}
+
+impl ChunkPanic {
+    /// Returns the panic message, if the payload is a string as made by
+    /// [`panic!`].
+    pub fn message(&self) -> Option<&str> {
+        if let Some(s) = self.payload.downcast_ref::<&str>() {
+            Some(s)
+        } else {
+            self.payload.downcast_ref::<String>().map(String::as_str)
+        }
+    }
+}
+
+impl<I: Iterator> Chunks<I> {
+    /// Similar to [`Chunks::for_each`], but a panic in `f` is caught and
+    /// recorded, and the iteration continues with the next chunk. Returns the
+    /// panics in chunk order.
+    ///
+    /// The rest of a chunk that panicked is skipped, so the next chunk starts
+    /// at its usual boundary. The panic hook still runs for every panic, so
+    /// the messages are printed by default.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut sums = vec![];
+    /// let panics = [1, 2, 0, 4, 5, 6].into_iter().chunks(2).for_each_catch(|chunk| {
+    ///     sums.push(chunk.map(|v| 12 / v).sum::<i32>());
+    /// });
+    /// assert_eq!(sums, [18, 4]);
+    /// assert_eq!(panics.len(), 1);
+    /// assert_eq!(panics[0].index, 1);
+    /// assert_eq!(panics[0].message(), Some("attempt to divide by zero"));
+    /// ```
+    pub fn for_each_catch(&mut self, mut f: impl FnMut(Chunk<'_, I>)) -> Vec<ChunkPanic> {
+        let mut panics = vec![];
+        let mut index = 0;
+        while let Some(chunk) = self.next() {
+            let res = panic::catch_unwind(AssertUnwindSafe(|| Self::in_span(chunk, &mut f)));
+            if let Err(payload) = res {
+                panics.push(ChunkPanic { index, payload });
+                self.align_to_next_boundary();
+            }
+            index += 1;
+        }
+        panics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IterChunks;
+
+    #[test]
+    fn test_for_each_catch() {
+        let mut res = vec![];
+        let panics = (0..10).chunks(3).for_each_catch(|mut chunk| {
+            let first = chunk.next().unwrap();
+            if first % 2 == 1 {
+                panic!("odd chunk {first}");
+            }
+            res.push((first, chunk.collect::<Vec<_>>()));
+        });
+        assert_eq!(res, [(0, vec![1, 2]), (6, vec![7, 8])]);
+        let panics = panics
+            .iter()
+            .map(|p| (p.index, p.message().unwrap()))
+            .collect::<Vec<_>>();
+        assert_eq!(panics, [(1, "odd chunk 3"), (3, "odd chunk 9")]);
+
+        // Panics at the end of the underlying iterator.
+        let mut chunks = (0..4).chunks(3);
+        let panics = chunks.for_each_catch(|chunk| {
+            assert_eq!(chunk.count(), 3);
+        });
+        assert_eq!(panics.len(), 1);
+        assert_eq!(panics[0].index, 1);
+        assert!(chunks.next().is_none());
+        assert_eq!(chunks.elements_consumed(), 4);
+    }
+
+    #[test]
+    fn test_for_each_catch_prepended() {
+        // Prepended chunks shorter and longer than `n` panic after their
+        // first element, and only their own rest is skipped.
+        for prepended in [vec![100], vec![100, 101, 102, 103, 104]] {
+            let mut chunks = (0..5).chunks(3);
+            chunks.prepend_chunk(prepended);
+            let mut res = vec![];
+            let panics = chunks.for_each_catch(|mut chunk| {
+                if chunk.next() == Some(100) {
+                    panic!("prepended");
+                }
+                res.push(chunk.collect::<Vec<_>>());
+            });
+            assert_eq!(panics.len(), 1);
+            assert_eq!(panics[0].index, 0);
+            assert_eq!(res, [vec![1, 2], vec![4]]);
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{Chunks, EitherChunk};
+
+/// An iterator-like struct that yields the chunks of one chunked stream, then
+/// the chunks of another.
+///
+/// This `struct` is created by [`Chunks::chain`]. See its documentation for
+/// more.
+pub struct ChainChunks<A: Iterator, B: Iterator<Item = A::Item>> {
+    a: Chunks<A>,
+    b: Chunks<B>,
+    a_done: bool,
+}
+
+impl<A, B> ChainChunks<A, B>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+{
+    pub(crate) fn new(a: Chunks<A>, b: Chunks<B>) -> Self {
+        Self {
+            a,
+            b,
+            a_done: false,
+        }
+    }
+
+    /// Similar to [`Iterator::next`], but not implements [`Iterator`] due to
+    /// lifetime.
+    ///
+    /// Once the first stream returns `None`, it's never polled again. The
+    /// second stream is resumable as in [`Chunks::next`].
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<EitherChunk<'_, A, B>> {
+        if !self.a_done {
+            match self.a.next_first() {
+                Some(first) => return Some(EitherChunk::Left(self.a.chunk_with_first(first))),
+                None => self.a_done = true,
+            }
+        }
+        self.b.next().map(EitherChunk::Right)
+    }
+
+    /// Similar to [`Iterator::for_each`].
+    pub fn for_each(&mut self, mut f: impl FnMut(EitherChunk<'_, A, B>)) {
+        while let Some(item) = self.next() {
+            f(item)
+        }
+    }
+
+    /// Consumes the adaptor and returns the two underlying chunked streams.
+    pub fn into_inner(self) -> (Chunks<A>, Chunks<B>) {
+        (self.a, self.b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IterChunks;
+
+    #[test]
+    fn test_chain() {
+        let a = (0..5).chunks(2);
+        let b = (5..10).chunks(3);
+        let mut chain = a.chain(b);
+        let mut res = vec![];
+        while let Some(chunk) = chain.next() {
+            res.push((chunk.chunk_size(), chunk.collect::<Vec<_>>()));
+        }
+        // The short chunk of the first stream is not merged with the second.
+        assert_eq!(
+            res,
+            vec![
+                (2, vec![0, 1]),
+                (2, vec![2, 3]),
+                (2, vec![4]),
+                (3, vec![5, 6, 7]),
+                (3, vec![8, 9]),
+            ]
+        );
+
+        let a = std::iter::empty().chunks(2);
+        let b = [1].into_iter().chunks(2);
+        let mut chain = a.chain(b);
+        assert!(chain.next().unwrap().is_right());
+        assert!(chain.next().is_none());
+    }
+}
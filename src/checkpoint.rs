@@ -0,0 +1,103 @@
+use crate::Chunks;
+
+/// An iterator-like struct that buffers the current chunk, so that it can be
+/// yielded again after a failure.
+///
+/// This `struct` is created by [`Chunks::checkpointed`]. See its
+/// documentation for more.
+pub struct CheckpointedChunks<I: Iterator> {
+    chunks: Chunks<I>,
+    buf: Vec<I::Item>,
+    replay: bool,
+}
+
+impl<I: Iterator> CheckpointedChunks<I> {
+    pub(crate) fn new(chunks: Chunks<I>) -> Self {
+        let buf = Vec::with_capacity(chunks.chunk_size());
+        Self {
+            chunks,
+            buf,
+            replay: false,
+        }
+    }
+
+    /// Similar to [`Iterator::next`], but yields the chunk as a slice of the
+    /// internal buffer.
+    ///
+    /// If [`CheckpointedChunks::replay`] was called since the last call, the
+    /// same chunk is yielded again instead of pulling a new one.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<&[I::Item]> {
+        if std::mem::take(&mut self.replay) && !self.buf.is_empty() {
+            return Some(&self.buf);
+        }
+        if self.chunks.next_buffered(&mut self.buf) {
+            Some(&self.buf)
+        } else {
+            None
+        }
+    }
+
+    /// Marks the last yielded chunk to be yielded again on the next call to
+    /// [`CheckpointedChunks::next`].
+    ///
+    /// Does nothing if no chunk has been yielded yet, or if the last call to
+    /// [`CheckpointedChunks::next`] returned `None`.
+    pub fn replay(&mut self) {
+        self.replay = true;
+    }
+
+    /// Returns the last yielded chunk, if any.
+    pub fn current(&self) -> Option<&[I::Item]> {
+        if self.buf.is_empty() {
+            None
+        } else {
+            Some(&self.buf)
+        }
+    }
+
+    /// Consumes the adaptor and returns the underlying [`Chunks`].
+    ///
+    /// A chunk marked for replay is given back to the returned [`Chunks`], so
+    /// that it's not lost.
+    pub fn into_inner(mut self) -> Chunks<I> {
+        if self.replay {
+            self.chunks.unread(&mut self.buf);
+        }
+        self.chunks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IterChunks;
+
+    #[test]
+    fn test_checkpointed() {
+        let mut chunks = (0..5).chunks(2).checkpointed();
+        let mut failures = 1;
+        let mut res = vec![];
+        while let Some(chunk) = chunks.next() {
+            if chunk[0] == 2 && failures > 0 {
+                failures -= 1;
+                chunks.replay();
+                continue;
+            }
+            res.push(chunk.to_vec());
+        }
+        assert_eq!(res, vec![vec![0, 1], vec![2, 3], vec![4]]);
+        assert_eq!(chunks.current(), None);
+    }
+
+    #[test]
+    fn test_checkpointed_into_inner() {
+        let mut chunks = (0..5).chunks(2).checkpointed();
+        assert_eq!(chunks.next(), Some(&[0, 1][..]));
+        assert_eq!(chunks.current(), Some(&[0, 1][..]));
+        chunks.replay();
+
+        let mut chunks = chunks.into_inner();
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![2, 3]);
+    }
+}
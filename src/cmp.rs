@@ -0,0 +1,116 @@
+use std::cmp::Ordering;
+
+use crate::Chunks;
+
+impl<I: Iterator> Chunks<I> {
+    /// Determines if the chunks are equal to `expected`, chunk by chunk and
+    /// element by element.
+    ///
+    /// Returns `false` at the first difference, including a different chunk
+    /// boundary, without pulling the rest of the underlying iterator.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// assert!((0..5).chunks(2).eq_nested([vec![0, 1], vec![2, 3], vec![4]]));
+    /// assert!(!(0..5).chunks(2).eq_nested([vec![0, 1, 2], vec![3, 4]]));
+    /// assert!(!(0..5).chunks(2).eq_nested([vec![0, 1], vec![2, 3]]));
+    /// ```
+    pub fn eq_nested<E>(mut self, expected: E) -> bool
+    where
+        E: IntoIterator,
+        E::Item: IntoIterator,
+        I::Item: PartialEq<<E::Item as IntoIterator>::Item>,
+    {
+        let mut expected = expected.into_iter();
+        loop {
+            match (self.next(), expected.next()) {
+                (None, None) => return true,
+                (Some(chunk), Some(other)) => {
+                    if !chunk.eq(other) {
+                        return false;
+                    }
+                }
+                _ => return false,
+            }
+        }
+    }
+
+    /// [Lexicographically](Ord#lexicographical-comparison) compares the chunks
+    /// with `other`, where every chunk is also compared lexicographically.
+    ///
+    /// Returns at the first difference, without pulling the rest of the
+    /// underlying iterator.
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    ///
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let chunks = || (0..5).chunks(2);
+    /// assert_eq!(chunks().cmp_nested([vec![0, 1], vec![2, 3], vec![4]]), Ordering::Equal);
+    /// assert_eq!(chunks().cmp_nested([vec![0, 1], vec![2]]), Ordering::Greater);
+    /// assert_eq!(chunks().cmp_nested([vec![0, 1], vec![2, 4]]), Ordering::Less);
+    /// ```
+    pub fn cmp_nested<E>(mut self, other: E) -> Ordering
+    where
+        E: IntoIterator,
+        E::Item: IntoIterator<Item = I::Item>,
+        I::Item: Ord,
+    {
+        let mut other = other.into_iter();
+        loop {
+            match (self.next(), other.next()) {
+                (None, None) => return Ordering::Equal,
+                (None, Some(_)) => return Ordering::Less,
+                (Some(_), None) => return Ordering::Greater,
+                (Some(chunk), Some(other)) => match chunk.cmp(other) {
+                    Ordering::Equal => {}
+                    non_eq => return non_eq,
+                },
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use crate::IterChunks;
+
+    #[test]
+    fn test_eq_nested() {
+        assert!((0..4).chunks(2).eq_nested([[0, 1], [2, 3]]));
+        assert!(std::iter::empty::<i32>()
+            .chunks(2)
+            .eq_nested(Vec::<Vec<i32>>::new()));
+        assert!(!(0..4).chunks(2).eq_nested([vec![0, 1], vec![2, 3], vec![]]));
+
+        // Short-circuits at the first different chunk.
+        let mut pulled = 0;
+        let source = (0..).inspect(|_| pulled += 1);
+        assert!(!source.chunks(2).eq_nested([[0, 1], [2, 4], [4, 5]]));
+        assert_eq!(pulled, 4);
+
+        // Compares elements of different types.
+        let words = ["a", "b", "c"].map(String::from);
+        assert!(words
+            .into_iter()
+            .chunks(2)
+            .eq_nested([vec!["a", "b"], vec!["c"]]));
+    }
+
+    #[test]
+    fn test_cmp_nested() {
+        let cmp = |other: Vec<Vec<i32>>| (0..5).chunks(2).cmp_nested(other);
+        assert_eq!(cmp(vec![vec![0, 1], vec![2, 3], vec![4]]), Ordering::Equal);
+        assert_eq!(
+            cmp(vec![vec![0, 1], vec![2, 3], vec![4, 0]]),
+            Ordering::Less
+        );
+        assert_eq!(cmp(vec![vec![0, 1], vec![2, 3]]), Ordering::Greater);
+        assert_eq!(cmp(vec![vec![0, 1], vec![1, 9]]), Ordering::Greater);
+        assert_eq!(cmp(vec![]), Ordering::Greater);
+    }
+}
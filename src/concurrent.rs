@@ -0,0 +1,98 @@
+use std::future::Future;
+
+use futures_util::stream::{FuturesUnordered, StreamExt};
+
+use crate::Chunks;
+
+impl<I: Iterator> Chunks<I> {
+    /// Processes chunks with the async closure `f`, keeping up to `limit`
+    /// chunks in flight at the same time.
+    ///
+    /// Every chunk is collected into a `Vec` and passed to `f`. A new chunk is
+    /// pulled from the source as soon as one of the in-flight futures
+    /// completes, and the returned future completes when the source is
+    /// exhausted and all the futures complete.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is 0.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut sums = vec![];
+    /// futures::executor::block_on((0..10).chunks(3).for_each_concurrent(2, |chunk| {
+    ///     sums.push(chunk.iter().sum::<i32>());
+    ///     async {}
+    /// }));
+    /// sums.sort();
+    /// assert_eq!(sums, vec![3, 9, 12, 21]);
+    /// ```
+    pub async fn for_each_concurrent<F, Fut>(&mut self, limit: usize, mut f: F)
+    where
+        F: FnMut(Vec<I::Item>) -> Fut,
+        Fut: Future<Output = ()>,
+    {
+        assert_ne!(limit, 0);
+        let mut in_flight = FuturesUnordered::new();
+        let mut source_done = false;
+        loop {
+            while !source_done && in_flight.len() < limit {
+                let mut chunk = Vec::with_capacity(self.n);
+                if self.next_buffered(&mut chunk) {
+                    in_flight.push(f(chunk));
+                } else {
+                    source_done = true;
+                }
+            }
+            if in_flight.next().await.is_none() {
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::task::Poll;
+
+    use futures::executor::block_on;
+    use futures::future::poll_fn;
+
+    use crate::IterChunks;
+
+    #[test]
+    fn test_for_each_concurrent() {
+        let in_flight = Cell::new(0);
+        let max_in_flight = Cell::new(0);
+        let mut started = vec![];
+
+        let mut chunks = (0..9).chunks(2);
+        block_on(chunks.for_each_concurrent(2, |chunk| {
+            started.push(chunk);
+            in_flight.set(in_flight.get() + 1);
+            max_in_flight.set(max_in_flight.get().max(in_flight.get()));
+            let in_flight = &in_flight;
+            let mut yielded = false;
+            // Yield once before completing, so that chunks overlap.
+            poll_fn(move |cx| {
+                if yielded {
+                    in_flight.set(in_flight.get() - 1);
+                    Poll::Ready(())
+                } else {
+                    yielded = true;
+                    cx.waker().wake_by_ref();
+                    Poll::Pending
+                }
+            })
+        }));
+        assert_eq!(
+            started,
+            vec![vec![0, 1], vec![2, 3], vec![4, 5], vec![6, 7], vec![8]]
+        );
+        assert_eq!(in_flight.get(), 0);
+        assert_eq!(max_in_flight.get(), 2);
+        assert!(chunks.next().is_none());
+    }
+}
@@ -0,0 +1,104 @@
+use crossbeam_channel::{Receiver, TryRecvError};
+
+use crate::std_channel::impl_blocking_recv_chunks;
+
+impl_blocking_recv_chunks!(Receiver<T>);
+
+/// A trait that extends crossbeam receivers with non-blocking batch
+/// receiving.
+pub trait TryRecvChunk<T> {
+    /// Receives up to `n` messages that are available right away, without
+    /// blocking.
+    ///
+    /// Returns [`TryRecvError::Empty`] if no message is available, and
+    /// [`TryRecvError::Disconnected`] if the channel is disconnected and
+    /// empty. This suits a receiver that [`select!`] reported as ready.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    ///
+    /// ```
+    /// use crossbeam_channel::{select, unbounded, TryRecvError};
+    /// use iter_chunks::TryRecvChunk;
+    ///
+    /// let (tx, rx) = unbounded();
+    /// let (_tick_tx, ticks) = unbounded::<()>();
+    /// for i in 0..5 {
+    ///     tx.send(i).unwrap();
+    /// }
+    ///
+    /// select! {
+    ///     recv(ticks) -> _ => unreachable!(),
+    ///     default => assert_eq!(rx.try_recv_chunk(3), Ok(vec![0, 1, 2])),
+    /// }
+    /// assert_eq!(rx.try_recv_chunk(3), Ok(vec![3, 4]));
+    /// assert_eq!(rx.try_recv_chunk(3), Err(TryRecvError::Empty));
+    /// drop(tx);
+    /// assert_eq!(rx.try_recv_chunk(3), Err(TryRecvError::Disconnected));
+    /// ```
+    ///
+    /// [`select!`]: crossbeam_channel::select
+    fn try_recv_chunk(&self, n: usize) -> Result<Vec<T>, TryRecvError>;
+}
+
+impl<T> TryRecvChunk<T> for Receiver<T> {
+    fn try_recv_chunk(&self, n: usize) -> Result<Vec<T>, TryRecvError> {
+        assert_ne!(n, 0);
+        let mut buf = Vec::with_capacity(n.min(self.len().max(1)));
+        buf.push(self.try_recv()?);
+        buf.extend(self.try_iter().take(n - 1));
+        Ok(buf)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use std::time::Duration;
+
+    use crossbeam_channel::{bounded, select, unbounded, TryRecvError};
+
+    use super::TryRecvChunk;
+    use crate::BlockingRecvChunks;
+
+    #[test]
+    fn test_recv_chunks() {
+        let (tx, rx) = bounded(0);
+        let handle = thread::spawn(move || {
+            for i in 0..3 {
+                tx.send(i).unwrap();
+            }
+            thread::sleep(Duration::from_millis(500));
+            tx.send(3).unwrap();
+        });
+
+        let idle = Some(Duration::from_millis(100));
+        assert_eq!(rx.recv_chunks(10, idle), Some(vec![0, 1, 2]));
+        assert_eq!(rx.recv_chunks(10, None), Some(vec![3]));
+        assert_eq!(rx.recv_chunks(10, idle), None);
+        handle.join().unwrap();
+    }
+
+    #[test]
+    fn test_try_recv_chunk() {
+        let (tx, rx) = unbounded();
+        assert_eq!(rx.try_recv_chunk(2), Err(TryRecvError::Empty));
+        for i in 0..3 {
+            tx.send(i).unwrap();
+        }
+        let mut batches = vec![];
+        while batches.len() < 2 {
+            select! {
+                recv(rx) -> first => {
+                    let mut batch = vec![first.unwrap()];
+                    batch.extend(rx.try_recv_chunk(1).unwrap_or_default());
+                    batches.push(batch);
+                }
+            }
+        }
+        assert_eq!(batches, vec![vec![0, 1], vec![2]]);
+        drop(tx);
+        assert_eq!(rx.try_recv_chunk(2), Err(TryRecvError::Disconnected));
+    }
+}
@@ -0,0 +1,95 @@
+use std::iter::Take;
+
+use crate::{Chunks, IterChunks};
+
+/// A random-access view over the chunks of a cloneable, exact-size iterator.
+///
+/// This `struct` is created by [`chunks_cursor`] method on [`IterChunks`].
+/// See its documentation for more.
+///
+/// [`chunks_cursor`]: IterChunks::chunks_cursor
+#[derive(Debug, Clone)]
+pub struct ChunksCursor<I> {
+    source: I,
+    n: usize,
+}
+
+impl<I> ChunksCursor<I>
+where
+    I: Clone + ExactSizeIterator,
+{
+    pub(crate) fn new(source: I, n: usize) -> Self {
+        assert_ne!(n, 0);
+        Self { source, n }
+    }
+
+    /// Returns the configured chunk size `n`.
+    pub fn chunk_size(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the number of chunks.
+    pub fn len(&self) -> usize {
+        self.source.len().div_ceil(self.n)
+    }
+
+    /// Returns `true` if there is no chunk.
+    pub fn is_empty(&self) -> bool {
+        self.source.len() == 0
+    }
+
+    /// Returns an iterator over the `i`-th chunk, or `None` if `i` is out of
+    /// bounds.
+    ///
+    /// The source is cloned, and the elements before the chunk are skipped
+    /// with [`Iterator::nth`], which is O(1) for sources such as ranges and
+    /// slice iterators.
+    pub fn get(&self, i: usize) -> Option<Take<I>> {
+        if i >= self.len() {
+            return None;
+        }
+        Some(self.skip_to(i).take(self.n))
+    }
+
+    /// Returns a [`Chunks`] that starts at the `i`-th chunk.
+    ///
+    /// If `i` is out of bounds, the returned [`Chunks`] yields nothing.
+    pub fn seek(&self, i: usize) -> Chunks<I> {
+        self.skip_to(i).chunks(self.n)
+    }
+
+    fn skip_to(&self, i: usize) -> I {
+        let mut iter = self.source.clone();
+        let skip = i.saturating_mul(self.n);
+        if skip > 0 {
+            iter.nth(skip - 1);
+        }
+        iter
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IterChunks;
+
+    #[test]
+    fn test_chunks_cursor() {
+        let cursor = (0..10).chunks_cursor(3);
+        assert_eq!(cursor.len(), 4);
+        assert!(!cursor.is_empty());
+        assert_eq!(cursor.get(0).unwrap().collect::<Vec<_>>(), vec![0, 1, 2]);
+        assert_eq!(cursor.get(3).unwrap().collect::<Vec<_>>(), vec![9]);
+        assert_eq!(cursor.get(2).unwrap().len(), 3);
+        assert!(cursor.get(4).is_none());
+
+        let mut chunks = cursor.seek(2);
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![6, 7, 8]);
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![9]);
+        assert!(chunks.next().is_none());
+        assert!(cursor.seek(usize::MAX).next().is_none());
+
+        let cursor = [0; 0].iter().chunks_cursor(3);
+        assert!(cursor.is_empty());
+        assert!(cursor.get(0).is_none());
+    }
+}
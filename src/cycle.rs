@@ -0,0 +1,114 @@
+use crate::{Chunk, Chunks};
+
+/// An iterator-like struct that restarts a cloneable source after it ends,
+/// and yields its chunks indefinitely.
+///
+/// This `struct` is created by [`Chunks::cycle_chunks`]. See its
+/// documentation for more.
+pub struct CycleChunks<I: Iterator> {
+    chunks: Chunks<I>,
+    source: I,
+    cycle: usize,
+}
+
+impl<I: Iterator + Clone> CycleChunks<I> {
+    pub(crate) fn new(chunks: Chunks<I>) -> Self {
+        let source = chunks.inner.clone();
+        Self {
+            chunks,
+            source,
+            cycle: 0,
+        }
+    }
+
+    /// Similar to [`Iterator::next`], but not implements [`Iterator`] due to
+    /// lifetime.
+    ///
+    /// Returns `None` only if the source yields no element in a whole cycle.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<Chunk<'_, I>> {
+        let first = match self.chunks.next_first() {
+            Some(v) => v,
+            None => {
+                self.restart();
+                self.chunks.next_first()?
+            }
+        };
+        Some(self.chunks.chunk_with_first(first))
+    }
+
+    /// Returns the index of the current cycle, starting from 0.
+    ///
+    /// It's incremented when the source is restarted, i.e. before the first
+    /// chunk of the new cycle is yielded.
+    pub fn cycle(&self) -> usize {
+        self.cycle
+    }
+
+    /// Similar to [`Iterator::for_each`].
+    ///
+    /// This only returns if the source is empty.
+    pub fn for_each(&mut self, mut f: impl FnMut(Chunk<'_, I>)) {
+        while let Some(item) = self.next() {
+            f(item)
+        }
+    }
+
+    /// Consumes the adaptor and returns the underlying [`Chunks`] over the
+    /// current cycle.
+    pub fn into_inner(self) -> Chunks<I> {
+        self.chunks
+    }
+
+    fn restart(&mut self) {
+        self.cycle += 1;
+        self.chunks.inner = self.source.clone();
+        self.chunks.end_flag = false;
+        self.chunks.exhausted = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IterChunks;
+
+    #[test]
+    fn test_cycle_chunks() {
+        let mut chunks = (0..5).chunks(2).cycle_chunks();
+        let mut res = vec![];
+        for _ in 0..7 {
+            let chunk = chunks.next().unwrap();
+            res.push(chunk.collect::<Vec<_>>());
+        }
+        assert_eq!(
+            res,
+            vec![
+                vec![0, 1],
+                vec![2, 3],
+                vec![4],
+                vec![0, 1],
+                vec![2, 3],
+                vec![4],
+                vec![0, 1]
+            ]
+        );
+        assert_eq!(chunks.cycle(), 2);
+        assert_eq!(chunks.into_inner().elements_consumed(), 12);
+
+        let mut chunks = std::iter::empty::<i32>().chunks(2).cycle_chunks();
+        assert!(chunks.next().is_none());
+        assert!(chunks.next().is_none());
+    }
+
+    #[test]
+    fn test_cycle_chunks_non_resumable() {
+        let mut chunks = [1, 2, 3]
+            .into_iter()
+            .chunks(3)
+            .non_resumable()
+            .cycle_chunks();
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(chunks.cycle(), 1);
+    }
+}
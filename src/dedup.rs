@@ -0,0 +1,81 @@
+use crate::Chunks;
+
+/// An iterator-like struct that skips chunks equal to the previous one.
+///
+/// This `struct` is created by [`Chunks::dedup_chunks`]. See its
+/// documentation for more.
+pub struct DedupChunks<I: Iterator> {
+    chunks: Chunks<I>,
+    // The last yielded chunk, empty before the first one.
+    prev: Vec<I::Item>,
+    buf: Vec<I::Item>,
+    skipped: usize,
+}
+
+impl<I> DedupChunks<I>
+where
+    I: Iterator,
+    I::Item: PartialEq,
+{
+    pub(crate) fn new(chunks: Chunks<I>) -> Self {
+        Self {
+            chunks,
+            prev: Vec::new(),
+            buf: Vec::new(),
+            skipped: 0,
+        }
+    }
+
+    /// Similar to [`Iterator::next`], but yields the chunk as a slice of the
+    /// internal buffer.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<&[I::Item]> {
+        loop {
+            if !self.chunks.next_buffered(&mut self.buf) {
+                return None;
+            }
+            if self.buf != self.prev {
+                std::mem::swap(&mut self.prev, &mut self.buf);
+                return Some(&self.prev);
+            }
+            self.skipped += 1;
+        }
+    }
+
+    /// Similar to [`Iterator::for_each`].
+    pub fn for_each(&mut self, mut f: impl FnMut(&[I::Item])) {
+        while let Some(chunk) = self.next() {
+            f(chunk)
+        }
+    }
+
+    /// Returns the number of chunks skipped so far.
+    pub fn skipped(&self) -> usize {
+        self.skipped
+    }
+
+    /// Consumes the adaptor and returns the underlying [`Chunks`].
+    pub fn into_inner(self) -> Chunks<I> {
+        self.chunks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IterChunks;
+
+    #[test]
+    fn test_dedup_chunks() {
+        let snapshots = [1, 2, 1, 2, 1, 2, 3, 4, 1, 2, 1, 2, 1];
+        let mut chunks = snapshots.into_iter().chunks(2).dedup_chunks();
+        let mut res = vec![];
+        chunks.for_each(|chunk| res.push(chunk.to_vec()));
+        assert_eq!(res, vec![vec![1, 2], vec![3, 4], vec![1, 2], vec![1]]);
+        assert_eq!(chunks.skipped(), 3);
+        assert!(chunks.next().is_none());
+
+        let mut chunks = std::iter::empty::<i32>().chunks(2).dedup_chunks();
+        assert!(chunks.next().is_none());
+        assert_eq!(chunks.skipped(), 0);
+    }
+}
@@ -0,0 +1,106 @@
+use std::iter::FusedIterator;
+
+/// An iterator that yields chunks of `n` elements from both ends of an
+/// exact-size iterator.
+///
+/// This `struct` is created by [`double_ended_chunks`] method on
+/// [`IterChunks`]. See its documentation for more.
+///
+/// [`double_ended_chunks`]: crate::IterChunks::double_ended_chunks
+/// [`IterChunks`]: crate::IterChunks
+#[derive(Debug, Clone)]
+pub struct DoubleEndedChunks<I> {
+    inner: I,
+    n: usize,
+    // The number of elements left between the front and the back.
+    len: usize,
+}
+
+impl<I: ExactSizeIterator> DoubleEndedChunks<I> {
+    pub(crate) fn new(inner: I, n: usize) -> Self {
+        assert_ne!(n, 0);
+        Self {
+            len: inner.len(),
+            inner,
+            n,
+        }
+    }
+
+    /// Returns the configured chunk size `n`.
+    pub fn chunk_size(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the number of elements not yielded from either end yet.
+    pub fn remaining(&self) -> usize {
+        self.len
+    }
+}
+
+impl<I: DoubleEndedIterator + ExactSizeIterator> Iterator for DoubleEndedChunks<I> {
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let n = self.n.min(self.len);
+        self.len -= n;
+        Some(self.inner.by_ref().take(n).collect())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len.div_ceil(self.n);
+        (len, Some(len))
+    }
+}
+
+impl<I: DoubleEndedIterator + ExactSizeIterator> DoubleEndedIterator for DoubleEndedChunks<I> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        // The last chunk is the short one, so it's aligned from the front.
+        let rem = self.len % self.n;
+        let n = if rem == 0 { self.n } else { rem };
+        self.len -= n;
+        let mut chunk = self.inner.by_ref().rev().take(n).collect::<Vec<_>>();
+        chunk.reverse();
+        Some(chunk)
+    }
+}
+
+impl<I: DoubleEndedIterator + ExactSizeIterator> ExactSizeIterator for DoubleEndedChunks<I> {}
+
+impl<I: DoubleEndedIterator + ExactSizeIterator> FusedIterator for DoubleEndedChunks<I> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{chunk_ranges, IterChunks};
+
+    #[test]
+    fn test_double_ended_chunks() {
+        // Any mix of both ends gives the boundaries of `chunks`.
+        for len in 0..12 {
+            for n in 1..5 {
+                for pattern in 0..16u32 {
+                    let mut chunks = (0..len).double_ended_chunks(n);
+                    let mut ranges = chunk_ranges(len, n);
+                    for i in 0.. {
+                        let (chunk, range) = if pattern >> (i % 4) & 1 == 0 {
+                            (chunks.next(), ranges.next())
+                        } else {
+                            (chunks.next_back(), ranges.next_back())
+                        };
+                        assert_eq!(chunk, range.map(|r| r.collect::<Vec<_>>()));
+                        assert_eq!(chunks.len(), ranges.len());
+                        if chunk.is_none() {
+                            break;
+                        }
+                    }
+                    assert_eq!(chunks.remaining(), 0);
+                }
+            }
+        }
+    }
+}
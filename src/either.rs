@@ -0,0 +1,61 @@
+use crate::Chunk;
+
+/// A chunk from either of two chunked streams with the same item type.
+///
+/// This `enum` is yielded by adaptors that combine two [`Chunks`], such as
+/// [`Chunks::interleave_chunks`]. It implements `Iterator` like [`Chunk`].
+///
+/// [`Chunks`]: crate::Chunks
+/// [`Chunks::interleave_chunks`]: crate::Chunks::interleave_chunks
+pub enum EitherChunk<'a, A: Iterator, B: Iterator<Item = A::Item>> {
+    /// A chunk from the first stream.
+    Left(Chunk<'a, A>),
+    /// A chunk from the second stream.
+    Right(Chunk<'a, B>),
+}
+
+impl<'a, A, B> EitherChunk<'a, A, B>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+{
+    /// Returns `true` if the chunk comes from the first stream.
+    pub fn is_left(&self) -> bool {
+        matches!(self, EitherChunk::Left(_))
+    }
+
+    /// Returns `true` if the chunk comes from the second stream.
+    pub fn is_right(&self) -> bool {
+        matches!(self, EitherChunk::Right(_))
+    }
+
+    /// Returns the configured chunk size of the stream the chunk comes from.
+    pub fn chunk_size(&self) -> usize {
+        match self {
+            EitherChunk::Left(chunk) => chunk.chunk_size(),
+            EitherChunk::Right(chunk) => chunk.chunk_size(),
+        }
+    }
+}
+
+impl<'a, A, B> Iterator for EitherChunk<'a, A, B>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+{
+    type Item = A::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            EitherChunk::Left(chunk) => chunk.next(),
+            EitherChunk::Right(chunk) => chunk.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            EitherChunk::Left(chunk) => chunk.size_hint(),
+            EitherChunk::Right(chunk) => chunk.size_hint(),
+        }
+    }
+}
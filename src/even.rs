@@ -0,0 +1,74 @@
+use std::iter::FusedIterator;
+
+/// An iterator that divides an exact-size iterator into a fixed number of
+/// chunks of nearly equal sizes.
+///
+/// This `struct` is created by [`parts`] method on [`IterChunks`]. See its
+/// documentation for more.
+///
+/// [`parts`]: crate::IterChunks::parts
+/// [`IterChunks`]: crate::IterChunks
+#[derive(Debug, Clone)]
+pub struct EvenParts<I> {
+    inner: I,
+    // The number of parts and elements left.
+    k: usize,
+    len: usize,
+}
+
+impl<I: ExactSizeIterator> EvenParts<I> {
+    pub(crate) fn new(inner: I, k: usize) -> Self {
+        assert_ne!(k, 0);
+        Self {
+            len: inner.len(),
+            inner,
+            k,
+        }
+    }
+}
+
+impl<I: ExactSizeIterator> Iterator for EvenParts<I> {
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.k == 0 {
+            return None;
+        }
+        // The longer parts come first.
+        let n = self.len.div_ceil(self.k);
+        self.k -= 1;
+        self.len -= n;
+        Some(self.inner.by_ref().take(n).collect())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.k, Some(self.k))
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for EvenParts<I> {}
+
+impl<I: ExactSizeIterator> FusedIterator for EvenParts<I> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::IterChunks;
+
+    #[test]
+    fn test_parts() {
+        for len in 0..20 {
+            for k in 1..8 {
+                let parts = (0..len).parts(k);
+                assert_eq!(parts.len(), k);
+                let parts = parts.collect::<Vec<_>>();
+                assert_eq!(parts.len(), k);
+                let sizes = parts.iter().map(Vec::len).collect::<Vec<_>>();
+                let max = *sizes.iter().max().unwrap();
+                let min = *sizes.iter().min().unwrap();
+                assert!(max - min <= 1, "{sizes:?}");
+                assert!(sizes.windows(2).all(|w| w[0] >= w[1]), "{sizes:?}");
+                assert_eq!(parts.concat(), (0..len).collect::<Vec<_>>());
+            }
+        }
+    }
+}
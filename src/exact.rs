@@ -0,0 +1,150 @@
+use std::{error::Error, fmt};
+
+use crate::{Chunk, Chunks};
+
+/// The error returned by [`ExactChunks`] for a final chunk shorter than the
+/// chunk size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IncompleteChunk {
+    /// The configured chunk size.
+    pub expected: usize,
+    /// The number of elements left in the final chunk.
+    pub got: usize,
+}
+
+impl fmt::Display for IncompleteChunk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "incomplete chunk: expected {} elements, got {}",
+            self.expected, self.got
+        )
+    }
+}
+
+impl Error for IncompleteChunk {}
+
+/// An iterator-like struct that only yields chunks of exactly `n` elements.
+///
+/// This `struct` is created by [`chunks_strict_exact`] method on
+/// [`IterChunks`]. See its documentation for more.
+///
+/// [`chunks_strict_exact`]: crate::IterChunks::chunks_strict_exact
+/// [`IterChunks`]: crate::IterChunks
+pub struct ExactChunks<I: Iterator> {
+    chunks: Chunks<I>,
+    buf: Vec<I::Item>,
+    done: bool,
+}
+
+impl<I: Iterator> ExactChunks<I> {
+    pub(crate) fn new(chunks: Chunks<I>) -> Self {
+        Self {
+            chunks,
+            buf: Vec::new(),
+            done: false,
+        }
+    }
+
+    /// Similar to [`Iterator::next`], but not implements [`Iterator`] due to
+    /// lifetime.
+    ///
+    /// A final chunk shorter than the chunk size is reported as
+    /// [`IncompleteChunk`], after which this always returns `None`.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<Result<Chunk<'_, I>, IncompleteChunk>> {
+        if self.done || !self.chunks.next_buffered(&mut self.buf) {
+            return None;
+        }
+        let got = self.buf.len();
+        // The elements are given back either way: to be yielded as a chunk, or
+        // to be left in the underlying `Chunks`.
+        self.chunks.unread(&mut self.buf);
+        let expected = self.chunks.chunk_size();
+        if got < expected {
+            self.done = true;
+            return Some(Err(IncompleteChunk { expected, got }));
+        }
+        self.chunks.next().map(Ok)
+    }
+
+    /// Similar to [`Iterator::for_each`].
+    pub fn for_each(&mut self, mut f: impl FnMut(Result<Chunk<'_, I>, IncompleteChunk>)) {
+        while let Some(item) = self.next() {
+            f(item)
+        }
+    }
+
+    /// Consumes the adaptor and returns the underlying [`Chunks`].
+    ///
+    /// After an [`IncompleteChunk`], the [`Chunks`] still holds the elements
+    /// of the incomplete chunk.
+    pub fn into_inner(self) -> Chunks<I> {
+        self.chunks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::IncompleteChunk;
+    use crate::IterChunks;
+
+    #[test]
+    fn test_chunks_strict_exact() {
+        let mut chunks = (0..7).chunks_strict_exact(3);
+        let mut res = vec![];
+        while let Some(chunk) = chunks.next() {
+            res.push(chunk.map(|c| c.collect::<Vec<_>>()));
+        }
+        assert_eq!(
+            res,
+            vec![
+                Ok(vec![0, 1, 2]),
+                Ok(vec![3, 4, 5]),
+                Err(IncompleteChunk {
+                    expected: 3,
+                    got: 1
+                }),
+            ]
+        );
+        assert!(chunks.next().is_none());
+        let mut rest = chunks.into_inner();
+        assert_eq!(rest.next().unwrap().collect::<Vec<_>>(), vec![6]);
+    }
+
+    #[test]
+    fn test_chunks_strict_exact_complete() {
+        let mut chunks = (0..6).chunks_strict_exact(2);
+        let mut res = vec![];
+        chunks.for_each(|chunk| res.push(chunk.unwrap().collect::<Vec<_>>()));
+        assert_eq!(res, vec![vec![0, 1], vec![2, 3], vec![4, 5]]);
+    }
+
+    #[test]
+    fn test_chunks_strict_exact_partial() {
+        // A partially consumed chunk leaves its rest to the next chunk.
+        let mut chunks = (0..5).chunks_strict_exact(2);
+        assert_eq!(chunks.next().unwrap().unwrap().next(), Some(0));
+        assert_eq!(
+            chunks.next().unwrap().unwrap().collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        assert_eq!(
+            chunks.next().unwrap().unwrap().collect::<Vec<_>>(),
+            vec![3, 4]
+        );
+        assert!(chunks.next().is_none());
+    }
+
+    #[test]
+    fn test_incomplete_chunk_display() {
+        let err = IncompleteChunk {
+            expected: 8,
+            got: 3,
+        };
+        assert_eq!(
+            err.to_string(),
+            "incomplete chunk: expected 8 elements, got 3"
+        );
+    }
+}
@@ -0,0 +1,106 @@
+use crate::{Chunk, Chunks};
+
+impl<I: Iterator> Chunks<I> {
+    /// Converts into an [`Iterator`] that maps every chunk with `f`, and
+    /// flattens the results.
+    ///
+    /// `f` consumes the chunk directly, so no `Vec` is allocated per chunk
+    /// unless `f` collects one. The rest of a chunk not consumed by `f` is
+    /// yielded by the next chunk, as with [`Chunks::next`].
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// // Every batch of readings yields its minimum and maximum.
+    /// let readings = [3, 1, 4, 1, 5, 9, 2];
+    /// let stats = readings
+    ///     .into_iter()
+    ///     .chunks(3)
+    ///     .flat_map_chunks(|chunk| {
+    ///         let (min, max) = chunk.fold((i32::MAX, i32::MIN), |(lo, hi), v| (lo.min(v), hi.max(v)));
+    ///         [min, max]
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(stats, [1, 4, 1, 9, 2, 2]);
+    /// ```
+    pub fn flat_map_chunks<U, F>(self, f: F) -> FlatMapChunks<I, U, F>
+    where
+        U: IntoIterator,
+        F: FnMut(Chunk<'_, I>) -> U,
+    {
+        FlatMapChunks {
+            chunks: self,
+            f,
+            front: None,
+        }
+    }
+}
+
+/// An iterator that maps every chunk to an iterator, and flattens the
+/// results.
+///
+/// This `struct` is created by [`Chunks::flat_map_chunks`]. See its
+/// documentation for more.
+pub struct FlatMapChunks<I: Iterator, U: IntoIterator, F> {
+    chunks: Chunks<I>,
+    f: F,
+    // The results of the current chunk.
+    front: Option<U::IntoIter>,
+}
+
+impl<I: Iterator, U: IntoIterator, F> FlatMapChunks<I, U, F> {
+    /// Consumes the adaptor and returns the underlying [`Chunks`].
+    ///
+    /// The results of the current chunk that are not yielded yet are lost.
+    pub fn into_inner(self) -> Chunks<I> {
+        self.chunks
+    }
+}
+
+impl<I, U, F> Iterator for FlatMapChunks<I, U, F>
+where
+    I: Iterator,
+    U: IntoIterator,
+    F: FnMut(Chunk<'_, I>) -> U,
+{
+    type Item = U::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(v) = self.front.as_mut().and_then(Iterator::next) {
+                return Some(v);
+            }
+            let chunk = self.chunks.next()?;
+            self.front = Some((self.f)(chunk).into_iter());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IterChunks;
+
+    #[test]
+    fn test_flat_map_chunks() {
+        // Chunks may expand to any number of results, including none.
+        let res = (0..7)
+            .chunks(3)
+            .flat_map_chunks(|chunk| {
+                chunk
+                    .filter(|v| v % 2 == 0)
+                    .map(|v| v * 10)
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(res, [0, 20, 40, 60]);
+
+        let mut iter = (0..6).chunks(2).flat_map_chunks(|mut chunk| chunk.next());
+        assert_eq!(iter.next(), Some(0));
+        // The rest of every chunk goes to the next chunk.
+        assert_eq!(iter.collect::<Vec<_>>(), [1, 2, 3, 4, 5]);
+
+        let mut iter = (0..6).chunks(2).flat_map_chunks(|chunk| chunk.max());
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.into_inner().elements_consumed(), 2);
+    }
+}
@@ -0,0 +1,110 @@
+use crate::EmptyChunks;
+
+/// An iterator that batches `Some` elements, and ends every batch at a `None`
+/// flush marker.
+///
+/// This `struct` is created by [`flush_chunks`] method on [`IterChunks`]. See
+/// its documentation for more.
+///
+/// [`flush_chunks`]: crate::IterChunks::flush_chunks
+/// [`IterChunks`]: crate::IterChunks
+#[derive(Debug, Clone)]
+pub struct FlushChunks<I> {
+    inner: I,
+    empty: EmptyChunks,
+    // Whether the last element pulled was a flush marker.
+    after_flush: bool,
+}
+
+impl<I> FlushChunks<I> {
+    pub(crate) fn new(inner: I) -> Self {
+        Self {
+            inner,
+            empty: EmptyChunks::Skip,
+            after_flush: false,
+        }
+    }
+
+    /// Sets how flush markers with nothing to flush, such as two consecutive
+    /// `None`s, are handled. Defaults to [`EmptyChunks::Skip`].
+    ///
+    /// ```
+    /// use iter_chunks::{EmptyChunks, IterChunks};
+    ///
+    /// let events = [Some(1), None, None, Some(2)];
+    /// let batches = events
+    ///     .into_iter()
+    ///     .flush_chunks()
+    ///     .empty_chunks(EmptyChunks::Yield)
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(batches, vec![vec![1], vec![], vec![2]]);
+    /// ```
+    pub fn empty_chunks(mut self, empty: EmptyChunks) -> Self {
+        self.empty = empty;
+        self
+    }
+}
+
+impl<I, T> Iterator for FlushChunks<I>
+where
+    I: Iterator<Item = Option<T>>,
+{
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut batch = Vec::new();
+        loop {
+            match self.inner.next() {
+                Some(Some(v)) => {
+                    self.after_flush = false;
+                    batch.push(v);
+                }
+                Some(None) => {
+                    let after_flush = std::mem::replace(&mut self.after_flush, true);
+                    if !batch.is_empty() || self.empty.keep(after_flush) {
+                        return Some(batch);
+                    }
+                }
+                // The elements after the last flush marker.
+                None => return (!batch.is_empty()).then_some(batch),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{EmptyChunks, IterChunks};
+
+    #[test]
+    fn test_flush_chunks() {
+        let events = [None, Some(1), Some(2), None, None, Some(3), None, Some(4)];
+        let batches = events.into_iter().flush_chunks().collect::<Vec<_>>();
+        assert_eq!(batches, vec![vec![1, 2], vec![3], vec![4]]);
+
+        let batches = events
+            .into_iter()
+            .flush_chunks()
+            .empty_chunks(EmptyChunks::Yield)
+            .collect::<Vec<_>>();
+        assert_eq!(batches, vec![vec![], vec![1, 2], vec![], vec![3], vec![4]]);
+
+        let batches = events
+            .into_iter()
+            .flush_chunks()
+            .empty_chunks(EmptyChunks::Coalesce)
+            .collect::<Vec<_>>();
+        assert_eq!(batches, vec![vec![], vec![1, 2], vec![3], vec![4]]);
+
+        let batches = [Some(1), None]
+            .into_iter()
+            .flush_chunks()
+            .collect::<Vec<_>>();
+        assert_eq!(batches, vec![vec![1]]);
+        assert_eq!(std::iter::empty::<Option<i32>>().flush_chunks().count(), 0);
+    }
+}
@@ -0,0 +1,128 @@
+use std::cell::Cell;
+use std::fmt::{self, Display};
+
+use crate::{Chunk, Chunks};
+
+impl<'a, I> Chunk<'a, I>
+where
+    I: Iterator,
+    I::Item: Display,
+{
+    /// Formats the elements of the chunk separated by `sep`, without
+    /// allocating.
+    ///
+    /// The returned value drains the chunk when formatted, so it can only be
+    /// formatted once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if formatted more than once.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut chunks = [1, 2, 3, 4, 5].into_iter().chunks(2);
+    /// let mut sql = String::from("VALUES ");
+    /// while let Some(chunk) = chunks.next() {
+    ///     sql += &format!("({}), ", chunk.format(", "));
+    /// }
+    /// assert_eq!(sql, "VALUES (1, 2), (3, 4), (5), ");
+    /// ```
+    pub fn format(self, sep: &str) -> ChunkFormat<'_, 'a, I> {
+        ChunkFormat {
+            sep,
+            chunk: Cell::new(Some(self)),
+        }
+    }
+}
+
+/// A lazy [`Display`] of the elements of a chunk separated by a separator.
+///
+/// This `struct` is created by [`Chunk::format`]. See its documentation for
+/// more.
+pub struct ChunkFormat<'s, 'a, I: Iterator> {
+    sep: &'s str,
+    chunk: Cell<Option<Chunk<'a, I>>>,
+}
+
+impl<I> Display for ChunkFormat<'_, '_, I>
+where
+    I: Iterator,
+    I::Item: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut chunk = self
+            .chunk
+            .take()
+            .expect("ChunkFormat was already formatted once");
+        if let Some(v) = chunk.next() {
+            v.fmt(f)?;
+            for v in chunk {
+                f.write_str(self.sep)?;
+                v.fmt(f)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An iterator that yields the elements of every chunk joined into a
+/// `String`.
+///
+/// This `struct` is created by [`Chunks::format_chunks`]. See its
+/// documentation for more.
+pub struct FormatChunks<I: Iterator> {
+    chunks: Chunks<I>,
+    sep: String,
+}
+
+impl<I: Iterator> FormatChunks<I> {
+    pub(crate) fn new(chunks: Chunks<I>, sep: String) -> Self {
+        Self { chunks, sep }
+    }
+}
+
+impl<I> Iterator for FormatChunks<I>
+where
+    I: Iterator,
+    I::Item: Display,
+{
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let chunk = self.chunks.next()?;
+        Some(chunk.format(&self.sep).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IterChunks;
+
+    #[test]
+    fn test_format_chunks() {
+        let lines = (1..=7).chunks(3).format_chunks(",").collect::<Vec<_>>();
+        assert_eq!(lines, ["1,2,3", "4,5,6", "7"]);
+        assert_eq!(
+            std::iter::empty::<i32>()
+                .chunks(3)
+                .format_chunks(",")
+                .count(),
+            0
+        );
+
+        let mut chunks = ["a", "b", "c"].into_iter().chunks(3);
+        let mut chunk = chunks.next().unwrap();
+        chunk.next();
+        assert_eq!(format!("[{:>2}]", chunk.format(" | ")), "[ b |  c]");
+    }
+
+    #[test]
+    #[should_panic(expected = "already formatted")]
+    fn test_format_twice() {
+        let mut chunks = [1, 2].into_iter().chunks(2);
+        let format = chunks.next().unwrap().format(",");
+        let _ = format.to_string();
+        let _ = format.to_string();
+    }
+}
@@ -0,0 +1,211 @@
+use std::io::{self, Write};
+
+use crate::{Chunk, Chunks};
+
+/// The encoding of the length prefix of a frame written by
+/// [`Chunk::write_frame_to`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthPrefix {
+    /// A big-endian `u16`.
+    U16Be,
+    /// A little-endian `u16`.
+    U16Le,
+    /// A big-endian `u32`.
+    U32Be,
+    /// A little-endian `u32`.
+    U32Le,
+    /// An unsigned LEB128 varint, 7 bits per byte with the least significant
+    /// group first.
+    Varint,
+}
+
+impl LengthPrefix {
+    /// Writes the prefix of a frame of `len` bytes into `writer`, and returns
+    /// the number of bytes written.
+    ///
+    /// An [`io::ErrorKind::InvalidInput`] error is returned if `len` doesn't
+    /// fit in the prefix.
+    pub fn write_len(self, len: usize, mut writer: impl Write) -> io::Result<usize> {
+        let too_long = || {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("frame of {len} bytes is too long for {self:?}"),
+            )
+        };
+        let mut buf = [0u8; 10];
+        let prefix: &[u8] = match self {
+            LengthPrefix::U16Be | LengthPrefix::U16Le => {
+                let len = u16::try_from(len).map_err(|_| too_long())?;
+                buf[..2].copy_from_slice(&if self == LengthPrefix::U16Be {
+                    len.to_be_bytes()
+                } else {
+                    len.to_le_bytes()
+                });
+                &buf[..2]
+            }
+            LengthPrefix::U32Be | LengthPrefix::U32Le => {
+                let len = u32::try_from(len).map_err(|_| too_long())?;
+                buf[..4].copy_from_slice(&if self == LengthPrefix::U32Be {
+                    len.to_be_bytes()
+                } else {
+                    len.to_le_bytes()
+                });
+                &buf[..4]
+            }
+            LengthPrefix::Varint => {
+                let mut len = len as u64;
+                let mut i = 0;
+                loop {
+                    let byte = (len & 0x7f) as u8;
+                    len >>= 7;
+                    if len == 0 {
+                        buf[i] = byte;
+                        break &buf[..=i];
+                    }
+                    buf[i] = byte | 0x80;
+                    i += 1;
+                }
+            }
+        };
+        writer.write_all(prefix)?;
+        Ok(prefix.len())
+    }
+}
+
+impl<I: Iterator<Item = u8>> Chunk<'_, I> {
+    /// Drains the chunk into `writer` as one frame: the length in bytes
+    /// encoded as `prefix`, then the bytes. Returns the number of bytes
+    /// written, including the prefix.
+    ///
+    /// The chunk is collected first to know its length. An
+    /// [`io::ErrorKind::InvalidInput`] error is returned, and nothing is
+    /// written, if the length doesn't fit in the prefix.
+    ///
+    /// ```
+    /// use iter_chunks::{IterChunks, LengthPrefix};
+    ///
+    /// let mut chunks = b"hello".iter().copied().chunks(3);
+    /// let mut out = vec![];
+    /// let mut chunk = chunks.next().unwrap();
+    /// assert_eq!(chunk.write_frame_to(&mut out, LengthPrefix::U16Be).unwrap(), 5);
+    /// assert_eq!(out, b"\0\x03hel");
+    /// ```
+    pub fn write_frame_to(
+        &mut self,
+        writer: impl Write,
+        prefix: LengthPrefix,
+    ) -> io::Result<usize> {
+        let buf = self.collect::<Vec<_>>();
+        write_frame(&buf, writer, prefix)
+    }
+}
+
+impl<I: Iterator<Item = u8>> Chunks<I> {
+    /// Writes every chunk into `writer` as a length-prefixed frame, see
+    /// [`Chunk::write_frame_to`]. Returns the number of bytes written.
+    ///
+    /// A single buffer is reused for all chunks.
+    ///
+    /// ```
+    /// use iter_chunks::{IterChunks, LengthPrefix};
+    ///
+    /// let mut out = vec![];
+    /// let written = b"hello"
+    ///     .iter()
+    ///     .copied()
+    ///     .chunks(3)
+    ///     .write_frames_to(&mut out, LengthPrefix::Varint)
+    ///     .unwrap();
+    /// assert_eq!(written, 7);
+    /// assert_eq!(out, b"\x03hel\x02lo");
+    /// ```
+    pub fn write_frames_to(
+        &mut self,
+        mut writer: impl Write,
+        prefix: LengthPrefix,
+    ) -> io::Result<usize> {
+        let mut buf = Vec::with_capacity(self.n);
+        let mut written = 0;
+        while self.next_buffered(&mut buf) {
+            written += write_frame(&buf, &mut writer, prefix)?;
+        }
+        Ok(written)
+    }
+}
+
+fn write_frame(buf: &[u8], mut writer: impl Write, prefix: LengthPrefix) -> io::Result<usize> {
+    let len = prefix.write_len(buf.len(), &mut writer)?;
+    writer.write_all(buf)?;
+    Ok(len + buf.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use super::LengthPrefix;
+    use crate::IterChunks;
+
+    #[test]
+    fn test_write_len() {
+        let cases: [(LengthPrefix, usize, &[u8]); 7] = [
+            (LengthPrefix::U16Be, 0x0102, b"\x01\x02"),
+            (LengthPrefix::U16Le, 0x0102, b"\x02\x01"),
+            (LengthPrefix::U32Be, 0x0102, b"\0\0\x01\x02"),
+            (LengthPrefix::U32Le, 0x0102, b"\x02\x01\0\0"),
+            (LengthPrefix::Varint, 0, b"\0"),
+            (LengthPrefix::Varint, 127, b"\x7f"),
+            (LengthPrefix::Varint, 300, b"\xac\x02"),
+        ];
+        for (prefix, len, expected) in cases {
+            let mut out = vec![];
+            assert_eq!(prefix.write_len(len, &mut out).unwrap(), expected.len());
+            assert_eq!(out, expected, "{prefix:?} {len}");
+        }
+
+        let mut out = vec![];
+        let err = LengthPrefix::U16Le
+            .write_len(1 << 16, &mut out)
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn test_write_frames_to() {
+        let data = (0..=255u8).cycle().take(1000).collect::<Vec<_>>();
+        let mut out = vec![];
+        let written = data
+            .iter()
+            .copied()
+            .chunks(300)
+            .write_frames_to(&mut out, LengthPrefix::U32Le)
+            .unwrap();
+        assert_eq!(written, out.len());
+
+        // Read the frames back.
+        let mut frames = vec![];
+        let mut rest = &out[..];
+        while !rest.is_empty() {
+            let (len, tail) = rest.split_at(4);
+            let len = u32::from_le_bytes(len.try_into().unwrap()) as usize;
+            let (frame, tail) = tail.split_at(len);
+            frames.push(frame);
+            rest = tail;
+        }
+        assert_eq!(
+            frames.iter().map(|f| f.len()).collect::<Vec<_>>(),
+            [300, 300, 300, 100]
+        );
+        assert_eq!(frames.concat(), data);
+
+        let mut out = vec![];
+        let err = (0..=255u8)
+            .cycle()
+            .chunks(1 << 16)
+            .write_frames_to(&mut out, LengthPrefix::U16Be)
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert!(out.is_empty());
+    }
+}
@@ -0,0 +1,108 @@
+use crate::{Chunks, EitherChunk};
+
+/// An iterator-like struct that alternates chunks from two chunked streams.
+///
+/// This `struct` is created by [`Chunks::interleave_chunks`]. See its
+/// documentation for more.
+pub struct InterleaveChunks<A: Iterator, B: Iterator<Item = A::Item>> {
+    a: Chunks<A>,
+    b: Chunks<B>,
+    a_done: bool,
+    b_done: bool,
+    next_is_b: bool,
+}
+
+impl<A, B> InterleaveChunks<A, B>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+{
+    pub(crate) fn new(a: Chunks<A>, b: Chunks<B>) -> Self {
+        Self {
+            a,
+            b,
+            a_done: false,
+            b_done: false,
+            next_is_b: false,
+        }
+    }
+
+    /// Similar to [`Iterator::next`], but not implements [`Iterator`] due to
+    /// lifetime.
+    ///
+    /// Once a stream returns `None`, it's never polled again, and the other
+    /// stream's chunks are yielded one after another.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<EitherChunk<'_, A, B>> {
+        for _ in 0..2 {
+            let take_b = self.next_is_b;
+            self.next_is_b = !self.next_is_b;
+            if take_b {
+                if self.b_done {
+                    continue;
+                }
+                match self.b.next_first() {
+                    Some(first) => return Some(EitherChunk::Right(self.b.chunk_with_first(first))),
+                    None => self.b_done = true,
+                }
+            } else {
+                if self.a_done {
+                    continue;
+                }
+                match self.a.next_first() {
+                    Some(first) => return Some(EitherChunk::Left(self.a.chunk_with_first(first))),
+                    None => self.a_done = true,
+                }
+            }
+        }
+        None
+    }
+
+    /// Similar to [`Iterator::for_each`].
+    pub fn for_each(&mut self, mut f: impl FnMut(EitherChunk<'_, A, B>)) {
+        while let Some(item) = self.next() {
+            f(item)
+        }
+    }
+
+    /// Consumes the adaptor and returns the two underlying chunked streams.
+    pub fn into_inner(self) -> (Chunks<A>, Chunks<B>) {
+        (self.a, self.b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IterChunks;
+
+    #[test]
+    fn test_interleave_chunks() {
+        let a = [1, 2, 3, 4, 5].into_iter().chunks(2);
+        let b = (10..13).chunks(1);
+        let mut iter = a.interleave_chunks(b);
+        let mut res = vec![];
+        while let Some(chunk) = iter.next() {
+            let left = chunk.is_left();
+            res.push((left, chunk.collect::<Vec<_>>()));
+        }
+        assert_eq!(
+            res,
+            vec![
+                (true, vec![1, 2]),
+                (false, vec![10]),
+                (true, vec![3, 4]),
+                (false, vec![11]),
+                (true, vec![5]),
+                (false, vec![12]),
+            ]
+        );
+        assert!(iter.next().is_none());
+
+        let a = (0..1).chunks(3);
+        let b = (1..8).chunks(3);
+        let mut res = vec![];
+        a.interleave_chunks(b)
+            .for_each(|chunk| res.push(chunk.collect::<Vec<_>>()));
+        assert_eq!(res, vec![vec![0], vec![1, 2, 3], vec![4, 5, 6], vec![7]]);
+    }
+}
@@ -0,0 +1,118 @@
+use std::io::{self, Write};
+
+use crate::Chunk;
+
+impl<I: Iterator<Item = u8>> Chunk<'_, I> {
+    /// Drains the chunk into `writer`, and returns the number of bytes
+    /// written.
+    ///
+    /// Bytes are staged in a stack buffer, so that `writer` gets a few large
+    /// writes instead of one write per byte.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut chunks = b"hello world".iter().copied().chunks(6);
+    /// let mut out = vec![];
+    /// let written = chunks.next().unwrap().write_to(&mut out).unwrap();
+    /// assert_eq!(written, 6);
+    /// assert_eq!(out, b"hello ");
+    /// ```
+    pub fn write_to(&mut self, mut writer: impl Write) -> io::Result<usize> {
+        let mut buf = [0u8; 4096];
+        let mut written = 0;
+        loop {
+            let mut len = 0;
+            for (slot, v) in buf.iter_mut().zip(&mut *self) {
+                *slot = v;
+                len += 1;
+            }
+            if len == 0 {
+                return Ok(written);
+            }
+            writer.write_all(&buf[..len])?;
+            written += len;
+        }
+    }
+}
+
+impl<I> Chunk<'_, I>
+where
+    I: Iterator,
+    I::Item: AsRef<[u8]>,
+{
+    /// Drains the chunk into `writer`, writing every element as a byte slice,
+    /// and returns the number of bytes written.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let lines = ["a\n", "bc\n", "def\n"];
+    /// let mut chunks = lines.into_iter().chunks(2);
+    /// let mut out = vec![];
+    /// while let Some(mut chunk) = chunks.next() {
+    ///     chunk.write_slices_to(&mut out).unwrap();
+    ///     out.extend_from_slice(b"--\n");
+    /// }
+    /// assert_eq!(out, b"a\nbc\n--\ndef\n--\n");
+    /// ```
+    pub fn write_slices_to(&mut self, mut writer: impl Write) -> io::Result<usize> {
+        let mut written = 0;
+        for v in self {
+            let bytes = v.as_ref();
+            writer.write_all(bytes)?;
+            written += bytes.len();
+        }
+        Ok(written)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, Write};
+
+    use crate::IterChunks;
+
+    #[test]
+    fn test_write_to() {
+        let data = (0..10000).map(|v| v as u8).collect::<Vec<_>>();
+        let mut chunks = data.iter().copied().chunks(4500);
+        let mut out = vec![];
+        let mut lens = vec![];
+        while let Some(mut chunk) = chunks.next() {
+            lens.push(chunk.write_to(&mut out).unwrap());
+        }
+        assert_eq!(lens, [4500, 4500, 1000]);
+        assert_eq!(out, data);
+    }
+
+    #[test]
+    fn test_write_slices_to() {
+        let items = vec![vec![1u8, 2], vec![], vec![3]];
+        let mut chunks = items.into_iter().chunks(2);
+        let mut out = vec![];
+        assert_eq!(chunks.next().unwrap().write_slices_to(&mut out).unwrap(), 2);
+        assert_eq!(chunks.next().unwrap().write_slices_to(&mut out).unwrap(), 1);
+        assert_eq!(out, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_write_to_error() {
+        struct Full;
+
+        impl Write for Full {
+            fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+                Err(io::ErrorKind::WriteZero.into())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let mut chunks = [1u8, 2, 3].into_iter().chunks(2);
+        let err = chunks.next().unwrap().write_to(Full).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), [3]);
+    }
+}
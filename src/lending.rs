@@ -0,0 +1,228 @@
+/// An iterator whose items may borrow from the iterator itself, such as a
+/// line reader that reuses its buffer.
+///
+/// Unlike [`Iterator`], an item must be dropped before the next one is pulled.
+pub trait LendingIterator {
+    /// The type of the elements, which may borrow from the iterator.
+    type Item<'a>
+    where
+        Self: 'a;
+
+    /// Advances the iterator and returns the next item.
+    fn next(&mut self) -> Option<Self::Item<'_>>;
+
+    /// Create an iterator-liked struct that yields the items by chunk every n
+    /// items, or fewer if the underlying lending iterator ends sooner.
+    ///
+    /// Every item borrows the [`LendingChunk`] it comes from, so the items are
+    /// never copied. To know whether a chunk exists, an item would have to be
+    /// pulled ahead, which is impossible while it borrows the source. So the
+    /// last chunk is empty if the source ends exactly at a chunk boundary.
+    ///
+    /// ```
+    /// use std::io::BufRead;
+    ///
+    /// use iter_chunks::LendingIterator;
+    ///
+    /// /// Lends every line from a reused buffer.
+    /// struct Lines<R> {
+    ///     reader: R,
+    ///     line: String,
+    /// }
+    ///
+    /// impl<R: BufRead> LendingIterator for Lines<R> {
+    ///     type Item<'a> = &'a str where Self: 'a;
+    ///
+    ///     fn next(&mut self) -> Option<&str> {
+    ///         self.line.clear();
+    ///         match self.reader.read_line(&mut self.line) {
+    ///             Ok(0) | Err(_) => None,
+    ///             Ok(_) => Some(self.line.trim_end()),
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let lines = Lines { reader: &b"a,1\nb,2\nc,3\n"[..], line: String::new() };
+    /// let mut chunks = lines.lending_chunks(2);
+    /// let mut lens = vec![];
+    /// while let Some(mut chunk) = chunks.next() {
+    ///     let mut len = 0;
+    ///     while let Some(line) = chunk.next() {
+    ///         len += line.len();
+    ///     }
+    ///     lens.push(len);
+    /// }
+    /// assert_eq!(lens, [6, 3]);
+    /// ```
+    fn lending_chunks(self, n: usize) -> LendingChunks<Self>
+    where
+        Self: Sized,
+    {
+        LendingChunks::new(self, n)
+    }
+}
+
+/// An iterator-like struct that yields chunks of a [`LendingIterator`].
+///
+/// This `struct` is created by [`lending_chunks`] method on
+/// [`LendingIterator`]. See its documentation for more.
+///
+/// [`lending_chunks`]: LendingIterator::lending_chunks
+pub struct LendingChunks<L> {
+    inner: L,
+    n: usize,
+    end_flag: bool,
+}
+
+impl<L: LendingIterator> LendingChunks<L> {
+    pub(crate) fn new(inner: L, n: usize) -> Self {
+        assert_ne!(n, 0);
+        Self {
+            inner,
+            n,
+            end_flag: false,
+        }
+    }
+
+    /// Returns the configured chunk size `n`.
+    pub fn chunk_size(&self) -> usize {
+        self.n
+    }
+
+    /// Similar to [`Iterator::next`], but not implements [`Iterator`] due to
+    /// lifetime.
+    ///
+    /// Returns `None` once after a chunk observed the end of the underlying
+    /// lending iterator, which may be polled again after that.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<LendingChunk<'_, L>> {
+        if core::mem::take(&mut self.end_flag) {
+            return None;
+        }
+        let n = self.n;
+        Some(LendingChunk { parent: self, n })
+    }
+
+    /// Consumes the struct and returns the underlying lending iterator.
+    pub fn into_inner(self) -> L {
+        self.inner
+    }
+}
+
+/// A lending iterator over a chunk of items.
+///
+/// This `struct` is created by [`LendingChunks::next`].
+pub struct LendingChunk<'a, L> {
+    parent: &'a mut LendingChunks<L>,
+    n: usize,
+}
+
+impl<L: LendingIterator> LendingChunk<'_, L> {
+    /// Returns the next item of the chunk, which borrows the chunk.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<L::Item<'_>> {
+        if self.n == 0 {
+            return None;
+        }
+        self.n -= 1;
+        let parent = &mut *self.parent;
+        let v = parent.inner.next();
+        if v.is_none() {
+            // The parent chunks should output None once.
+            parent.end_flag = true;
+            self.n = 0;
+        }
+        v
+    }
+
+    /// Similar to [`Iterator::for_each`], but every item borrows the chunk.
+    pub fn for_each(mut self, mut f: impl FnMut(L::Item<'_>)) {
+        while let Some(item) = self.next() {
+            f(item)
+        }
+    }
+}
+
+impl<L: LendingIterator> LendingIterator for LendingChunk<'_, L> {
+    type Item<'b>
+        = L::Item<'b>
+    where
+        Self: 'b;
+
+    fn next(&mut self) -> Option<Self::Item<'_>> {
+        LendingChunk::next(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LendingIterator;
+
+    /// Lends windows of a buffer that is overwritten for every item.
+    struct Counter {
+        buf: [u32; 2],
+        i: u32,
+        end: u32,
+    }
+
+    impl LendingIterator for Counter {
+        type Item<'a> = &'a [u32];
+
+        fn next(&mut self) -> Option<&[u32]> {
+            if self.i == self.end {
+                return None;
+            }
+            self.buf = [self.i, self.i * 10];
+            self.i += 1;
+            Some(&self.buf)
+        }
+    }
+
+    fn collect(counter: Counter, n: usize) -> Vec<Vec<Vec<u32>>> {
+        let mut chunks = counter.lending_chunks(n);
+        let mut res = vec![];
+        while let Some(chunk) = chunks.next() {
+            let mut items = vec![];
+            chunk.for_each(|item| items.push(item.to_vec()));
+            res.push(items);
+        }
+        res
+    }
+
+    #[test]
+    fn test_lending_chunks() {
+        let counter = |end| Counter {
+            buf: [0; 2],
+            i: 0,
+            end,
+        };
+        assert_eq!(
+            collect(counter(3), 2),
+            vec![vec![vec![0, 0], vec![1, 10]], vec![vec![2, 20]]]
+        );
+        // An empty chunk at the boundary.
+        assert_eq!(
+            collect(counter(2), 2),
+            vec![vec![vec![0, 0], vec![1, 10]], vec![]]
+        );
+        assert_eq!(collect(counter(0), 2), vec![Vec::<Vec<u32>>::new()]);
+    }
+
+    #[test]
+    fn test_lending_chunks_partial() {
+        let counter = Counter {
+            buf: [0; 2],
+            i: 0,
+            end: 5,
+        };
+        let mut chunks = counter.lending_chunks(3);
+        assert_eq!(chunks.next().unwrap().next(), Some(&[0, 0][..]));
+        // The rest of a dropped chunk goes to the next chunk.
+        let mut chunk = chunks.next().unwrap();
+        assert_eq!(chunk.next(), Some(&[1, 10][..]));
+        assert_eq!(chunk.next(), Some(&[2, 20][..]));
+        assert_eq!(chunk.next(), Some(&[3, 30][..]));
+        assert_eq!(chunk.next(), None);
+        assert_eq!(chunks.into_inner().next(), Some(&[4, 40][..]));
+    }
+}
@@ -1,4 +1,262 @@
-use std::iter::Iterator;
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::iter::{Flatten, Iterator, Skip, Take};
+#[cfg(feature = "std")]
+use core::time::Duration;
+
+#[cfg(feature = "std")]
+mod adaptive;
+mod array;
+#[cfg(feature = "ndarray")]
+mod array2;
+#[cfg(feature = "arrayvec")]
+mod array_vec;
+#[cfg(feature = "arrow")]
+mod arrow;
+#[cfg(feature = "std")]
+mod builder;
+#[cfg(feature = "std")]
+mod catch;
+#[cfg(feature = "std")]
+mod chain;
+#[cfg(feature = "std")]
+mod checkpoint;
+#[cfg(feature = "std")]
+mod cmp;
+#[cfg(feature = "futures")]
+mod concurrent;
+#[cfg(feature = "crossbeam")]
+mod crossbeam;
+#[cfg(feature = "std")]
+mod cursor;
+#[cfg(feature = "std")]
+mod cycle;
+#[cfg(feature = "std")]
+mod dedup;
+#[cfg(feature = "std")]
+mod double_ended;
+#[cfg(feature = "std")]
+mod either;
+#[cfg(feature = "std")]
+mod even;
+#[cfg(feature = "std")]
+mod exact;
+mod flat_map;
+#[cfg(feature = "std")]
+mod flush;
+#[cfg(feature = "std")]
+mod format;
+#[cfg(feature = "std")]
+mod frame;
+#[cfg(feature = "std")]
+mod interleave;
+#[cfg(feature = "std")]
+mod io;
+mod lending;
+#[cfg(feature = "std")]
+mod merge;
+#[cfg(feature = "metrics")]
+mod metric;
+#[cfg(feature = "std")]
+mod par_map;
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "std")]
+mod partition;
+mod parts;
+mod peek;
+#[cfg(feature = "std")]
+mod progress;
+#[cfg(feature = "indicatif")]
+mod progress_bar;
+#[cfg(feature = "std")]
+mod ranges;
+#[cfg(feature = "std")]
+mod rechunk;
+#[cfg(feature = "std")]
+mod retry;
+#[cfg(feature = "std")]
+mod runs;
+#[cfg(feature = "rand")]
+mod sample;
+#[cfg(feature = "std")]
+mod sample_every;
+#[cfg(feature = "std")]
+mod shared;
+#[cfg(feature = "rand")]
+mod shuffle;
+#[cfg(feature = "futures")]
+mod sink;
+#[cfg(feature = "std")]
+mod skip_while;
+#[cfg(feature = "smallvec")]
+mod small_vec;
+#[cfg(feature = "std")]
+mod sort;
+#[cfg(feature = "tokio")]
+mod spawn;
+#[cfg(feature = "std")]
+mod split;
+#[cfg(feature = "std")]
+mod std_channel;
+#[cfg(feature = "std")]
+mod step_by;
+#[cfg(feature = "std")]
+mod strategy;
+#[cfg(feature = "futures-core")]
+mod stream;
+#[cfg(feature = "std")]
+mod super_chunks;
+#[cfg(feature = "std")]
+mod take_while;
+#[cfg(feature = "std")]
+mod tee;
+#[cfg(feature = "std")]
+mod text;
+#[cfg(feature = "std")]
+mod throttle;
+#[cfg(feature = "tokio")]
+mod tokio_channel;
+#[cfg(feature = "tokio-util")]
+mod tokio_codec;
+#[cfg(feature = "tokio")]
+mod tokio_io;
+#[cfg(feature = "std")]
+mod tuple;
+#[cfg(feature = "std")]
+mod uninit;
+#[cfg(feature = "std")]
+mod unzip;
+#[cfg(feature = "std")]
+mod weight;
+#[cfg(feature = "std")]
+mod window;
+#[cfg(feature = "std")]
+mod zip;
+
+#[cfg(feature = "std")]
+pub use adaptive::AdaptiveChunks;
+pub use array::ArrayWindows;
+#[cfg(feature = "arrayvec")]
+pub use array_vec::IntoArrayVecs;
+#[cfg(feature = "arrow")]
+pub use arrow::{ArrowArrays, RecordBatches};
+#[cfg(feature = "std")]
+pub use builder::{ChunksBuilder, ConfiguredChunks, LeftoverPolicy};
+#[cfg(feature = "std")]
+pub use catch::ChunkPanic;
+#[cfg(feature = "std")]
+pub use chain::ChainChunks;
+#[cfg(feature = "std")]
+pub use checkpoint::CheckpointedChunks;
+#[cfg(feature = "crossbeam")]
+pub use crossbeam::TryRecvChunk;
+#[cfg(feature = "std")]
+pub use cursor::ChunksCursor;
+#[cfg(feature = "std")]
+pub use cycle::CycleChunks;
+#[cfg(feature = "std")]
+pub use dedup::DedupChunks;
+#[cfg(feature = "std")]
+pub use double_ended::DoubleEndedChunks;
+#[cfg(feature = "std")]
+pub use either::EitherChunk;
+#[cfg(feature = "std")]
+pub use even::EvenParts;
+#[cfg(feature = "std")]
+pub use exact::{ExactChunks, IncompleteChunk};
+pub use flat_map::FlatMapChunks;
+#[cfg(feature = "std")]
+pub use flush::FlushChunks;
+#[cfg(feature = "std")]
+pub use format::{ChunkFormat, FormatChunks};
+#[cfg(feature = "std")]
+pub use frame::LengthPrefix;
+#[cfg(feature = "std")]
+pub use interleave::InterleaveChunks;
+pub use lending::{LendingChunk, LendingChunks, LendingIterator};
+#[cfg(feature = "std")]
+pub use merge::{merge_sorted, merge_sorted_by, MergeSorted};
+#[cfg(feature = "metrics")]
+pub use metric::MetricsChunks;
+#[cfg(feature = "std")]
+pub use par_map::ParMapOrdered;
+#[cfg(feature = "std")]
+pub use partition::{PartitionResultChunks, PartitionedChunks};
+pub use parts::ChunksParts;
+pub use peek::MultiPeek;
+#[cfg(feature = "std")]
+pub use progress::ProgressChunks;
+#[cfg(feature = "indicatif")]
+pub use progress_bar::{chunk_progress_style, element_progress_style, ProgressBarChunks};
+#[cfg(feature = "std")]
+pub use ranges::{chunk_ranges, ChunkRanges};
+#[cfg(feature = "std")]
+pub use rechunk::RechunkBytes;
+#[cfg(feature = "std")]
+pub use retry::RetryPolicy;
+#[cfg(feature = "std")]
+pub use runs::{ChunkRuns, RleChunks};
+#[cfg(feature = "rand")]
+pub use sample::{SampleChunks, SampleFraction};
+#[cfg(feature = "std")]
+pub use sample_every::SampleEvery;
+#[cfg(feature = "std")]
+pub use shared::{SharedChunk, SharedChunks};
+#[cfg(feature = "rand")]
+pub use shuffle::ShuffledChunks;
+#[cfg(feature = "futures")]
+pub use sink::ChunkedSink;
+#[cfg(feature = "std")]
+pub use skip_while::SkipWhileChunks;
+#[cfg(feature = "smallvec")]
+pub use small_vec::IntoSmallVecs;
+#[cfg(feature = "std")]
+pub use sort::SortedChunks;
+#[cfg(feature = "tokio")]
+pub use spawn::SpawnChunks;
+#[cfg(feature = "std")]
+pub use split::{EmptyChunks, SplitChunks};
+#[cfg(feature = "std")]
+pub use std_channel::BlockingRecvChunks;
+#[cfg(feature = "std")]
+pub use step_by::StepByChunks;
+#[cfg(feature = "std")]
+pub use strategy::{ByteStrategy, ChunkStrategy, ChunkWith, ChunksWith, CountStrategy};
+#[cfg(feature = "futures-core")]
+pub use stream::{ChunkStream, ChunksStream, StreamChunks, WeightedChunksStream};
+#[cfg(feature = "std")]
+pub use super_chunks::{SuperChunk, SuperChunks};
+#[cfg(feature = "std")]
+pub use take_while::TakeWhileChunks;
+#[cfg(feature = "std")]
+pub use tee::Tee;
+#[cfg(feature = "unicode-segmentation")]
+pub use text::{grapheme_chunks, GraphemeChunks};
+#[cfg(feature = "std")]
+pub use text::{str_chunks, StrChunks};
+#[cfg(feature = "std")]
+pub use throttle::ThrottledChunks;
+#[cfg(feature = "tokio")]
+pub use tokio_channel::RecvChunks;
+#[cfg(feature = "tokio-util")]
+pub use tokio_codec::ChunkDecoder;
+#[cfg(feature = "tokio")]
+pub use tokio_io::{AsyncBufReadChunks, AsyncReadChunks, LineChunks, ReadChunks};
+#[cfg(feature = "std")]
+pub use tuple::{TupleChunk, TupleChunks};
+#[cfg(feature = "std")]
+pub use unzip::UnzipChunks;
+#[cfg(feature = "std")]
+pub use weight::{OversizedItem, OversizedPolicy, WeightChunks};
+#[cfg(feature = "std")]
+pub use window::{SessionWindow, TumblingWindow};
+#[cfg(feature = "std")]
+pub use zip::{EitherOrBothChunk, ZipLongestChunks};
 
 /// A trait that extends [`Iterator`] with `chunks` method.
 pub trait IterChunks: Sized + Iterator {
@@ -22,6 +280,642 @@ pub trait IterChunks: Sized + Iterator {
     /// }
     /// ```
     fn chunks(self, n: usize) -> Chunks<Self>;
+
+    /// Advances the iterator and returns an array of the next `N` elements,
+    /// or `None` if fewer than `N` elements are left.
+    ///
+    /// This is a stable polyfill of the nightly `Iterator::next_chunk`. The
+    /// elements of a short tail are consumed and dropped; use
+    /// [`next_array_partial`] to keep them.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut bytes = [0xca, 0xfe, 0xba, 0xbe, 0x01].into_iter();
+    /// let magic = bytes.next_array::<4>().map(u32::from_be_bytes);
+    /// assert_eq!(magic, Some(0xcafebabe));
+    /// assert_eq!(bytes.next_array::<4>(), None);
+    /// ```
+    ///
+    /// [`next_array_partial`]: IterChunks::next_array_partial
+    fn next_array<const N: usize>(&mut self) -> Option<[Self::Item; N]>;
+
+    /// Similar to [`next_array`], but returns the short tail as `Err` if fewer
+    /// than `N` elements are left.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut iter = 0..5;
+    /// assert_eq!(iter.next_array_partial::<3>(), Ok([0, 1, 2]));
+    /// assert_eq!(iter.next_array_partial::<3>(), Err(vec![3, 4]));
+    /// ```
+    ///
+    /// [`next_array`]: IterChunks::next_array
+    fn next_array_partial<const N: usize>(&mut self) -> Result<[Self::Item; N], Vec<Self::Item>>;
+
+    /// Create an iterator over the overlapping windows of `N` consecutive
+    /// elements, as arrays, like [`slice::windows`] on a stream.
+    ///
+    /// The last `N` elements are kept in a ring buffer, and every window is
+    /// cloned out of it, so the windows can be destructured.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let prices = [10, 12, 11, 15];
+    /// let deltas = prices
+    ///     .into_iter()
+    ///     .array_windows()
+    ///     .map(|[a, b]| b - a)
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(deltas, [2, -1, 4]);
+    /// ```
+    fn array_windows<const N: usize>(self) -> ArrayWindows<Self, N>
+    where
+        Self::Item: Clone;
+
+    /// Create an iterator that yields elements as tuples of 2 to 4 elements.
+    ///
+    /// Unlike [`chunks`], the returned adaptor is a real [`Iterator`], so the
+    /// tuples can be destructured in a `for` loop. If the underlying iterator
+    /// ends in the middle of a tuple, the remaining elements can be retrieved
+    /// with [`TupleChunks::leftover`].
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut pairs = [1, 2, 3, 4, 5].into_iter().tuple_chunks();
+    /// for (a, b) in pairs.by_ref() {
+    ///     assert_eq!(a + 1, b);
+    /// }
+    /// assert_eq!(pairs.leftover(), [5]);
+    /// ```
+    ///
+    /// [`chunks`]: IterChunks::chunks
+    #[cfg(feature = "std")]
+    fn tuple_chunks<T>(self) -> TupleChunks<Self, T>
+    where
+        T: TupleChunk<Item = Self::Item>;
+
+    /// Create an iterator-liked struct that yields chunks whose sizes are
+    /// chosen by a feedback closure.
+    ///
+    /// The first chunk has `initial` elements. Before every following chunk,
+    /// `f` is called with the number of elements consumed from the previous
+    /// chunk and the time elapsed since it was yielded (roughly its processing
+    /// time), and returns the size of the next chunk.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// // Grow the batch while processing is fast.
+    /// let mut chunks = (0..7).chunks_adaptive(1, |last_len, elapsed| {
+    ///     if elapsed.as_secs() < 1 {
+    ///         last_len * 2
+    ///     } else {
+    ///         last_len.div_ceil(2)
+    ///     }
+    /// });
+    /// let mut lens = vec![];
+    /// chunks.for_each(|chunk| lens.push(chunk.count()));
+    /// assert_eq!(lens, vec![1, 2, 4]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn chunks_adaptive<F>(self, initial: usize, f: F) -> AdaptiveChunks<Self, F>
+    where
+        F: FnMut(usize, Duration) -> usize;
+
+    /// Create an iterator that regroups byte buffers into chunks of exactly
+    /// `n` bytes, or fewer for the last chunk.
+    ///
+    /// The input buffers are treated as one concatenated byte stream, so
+    /// partial data is carried across them regardless of how the input is
+    /// framed. Empty input buffers are skipped.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let reads: [&[u8]; 3] = [b"hel", b"lo wor", b"ld"];
+    /// let chunks = reads.into_iter().rechunk_bytes(4).collect::<Vec<_>>();
+    /// assert_eq!(chunks, [&b"hell"[..], b"o wo", b"rld"]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn rechunk_bytes(self, n: usize) -> RechunkBytes<Self>
+    where
+        Self::Item: AsRef<[u8]>;
+
+    /// Flattens an iterator of iterables and chunks the flattened elements
+    /// every n elements, in one pass.
+    ///
+    /// This is a shorthand of `self.flatten().chunks(n)`, which re-batches
+    /// data that arrives in wrong-sized batches without collecting them.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let polls = vec![vec![1, 2, 3], vec![], vec![4], vec![5, 6]];
+    /// let mut res = vec![];
+    /// polls
+    ///     .into_iter()
+    ///     .rechunk(4)
+    ///     .for_each(|chunk| res.push(chunk.collect::<Vec<_>>()));
+    /// assert_eq!(res, vec![vec![1, 2, 3, 4], vec![5, 6]]);
+    /// ```
+    fn rechunk(self, n: usize) -> Chunks<Flatten<Self>>
+    where
+        Self::Item: IntoIterator;
+
+    /// Create a cursor that gives random access to the chunks of every n
+    /// elements.
+    ///
+    /// Any chunk can be materialized by index without iterating the previous
+    /// chunks through the lending API, which suits paged views and samplers.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let rows = (0..100).collect::<Vec<_>>();
+    /// let pages = rows.iter().chunks_cursor(10);
+    /// assert_eq!(pages.len(), 10);
+    /// assert_eq!(pages.get(7).unwrap().next(), Some(&70));
+    /// ```
+    #[cfg(feature = "std")]
+    fn chunks_cursor(self, n: usize) -> ChunksCursor<Self>
+    where
+        Self: Clone + ExactSizeIterator;
+
+    /// Create an iterator that divides the elements into exactly `k` chunks,
+    /// whose sizes differ by at most one, e.g. to split work evenly across `k`
+    /// workers.
+    ///
+    /// The longer chunks come first. If there are fewer than `k` elements,
+    /// the last chunks are empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is 0.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let parts = (0..10).parts(4).collect::<Vec<_>>();
+    /// assert_eq!(parts, vec![vec![0, 1, 2], vec![3, 4, 5], vec![6, 7], vec![8, 9]]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn parts(self, k: usize) -> EvenParts<Self>
+    where
+        Self: ExactSizeIterator;
+
+    /// Create an iterator that yields chunks of `n` elements from either end,
+    /// as `Vec`s in the original order, e.g. to match the oldest records
+    /// against the newest ones.
+    ///
+    /// [`Iterator::next`] takes a chunk from the front, and
+    /// [`DoubleEndedIterator::next_back`] from the back. The chunks meet in
+    /// the middle without overlap, and keep the boundaries of [`chunks`]
+    /// whatever the order of the calls, so only the last chunk may be short.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut chunks = (0..7).double_ended_chunks(2);
+    /// assert_eq!(chunks.next(), Some(vec![0, 1]));
+    /// assert_eq!(chunks.next_back(), Some(vec![6]));
+    /// assert_eq!(chunks.next_back(), Some(vec![4, 5]));
+    /// assert_eq!(chunks.next(), Some(vec![2, 3]));
+    /// assert_eq!(chunks.next(), None);
+    /// ```
+    ///
+    /// [`chunks`]: IterChunks::chunks
+    #[cfg(feature = "std")]
+    fn double_ended_chunks(self, n: usize) -> DoubleEndedChunks<Self>
+    where
+        Self: DoubleEndedIterator + ExactSizeIterator;
+
+    /// Create an iterator-like struct that yields chunks of exactly n elements.
+    ///
+    /// Instead of a short final chunk, an [`IncompleteChunk`] error with the
+    /// number of elements left is yielded. The elements of the incomplete
+    /// chunk stay in the [`Chunks`] returned by [`ExactChunks::into_inner`].
+    ///
+    /// ```
+    /// use iter_chunks::{IncompleteChunk, IterChunks};
+    ///
+    /// let record = [1u8, 2, 3, 4, 5];
+    /// let mut chunks = record.into_iter().chunks_strict_exact(2);
+    /// chunks.next().unwrap().unwrap().for_each(drop);
+    /// chunks.next().unwrap().unwrap().for_each(drop);
+    /// assert_eq!(
+    ///     chunks.next().unwrap().err(),
+    ///     Some(IncompleteChunk { expected: 2, got: 1 }),
+    /// );
+    /// assert!(chunks.next().is_none());
+    /// ```
+    #[cfg(feature = "std")]
+    fn chunks_strict_exact(self, n: usize) -> ExactChunks<Self>;
+
+    /// Create an [`Iterator`] that yields every chunk of n elements as an
+    /// [`Iterator`] sharing the underlying iterator, like itertools'
+    /// `IntoChunks`.
+    ///
+    /// Unlike [`Chunks`], the chunks can be held at the same time, so generic
+    /// code that requires an `Iterator` of iterators can be used. The elements
+    /// are still pulled lazily: consuming a chunk before the previous ones
+    /// buffers their remaining elements, and dropping a chunk skips its
+    /// elements. [`SharedChunks`] and [`SharedChunk`] are `Send` as long as the
+    /// underlying iterator and its items are, so chunks can be moved to other
+    /// threads.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let sums = (1..=7)
+    ///     .shared_chunks(3)
+    ///     .map(|chunk| chunk.sum::<i32>())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(sums, vec![6, 15, 7]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn shared_chunks(self, n: usize) -> SharedChunks<Self>;
+
+    /// Create an iterator that yields every chunk of `n` results as a pair of
+    /// the `Ok` values and the `Err`s, or fewer results for the last chunk.
+    ///
+    /// Unlike collecting into a `Result`, an `Err` does not short-circuit the
+    /// chunk, so every error of a batch is reported at once. Both `Ok` and
+    /// `Err` results count towards the chunk size.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let rows = ["1", "x", "3", "y", "5"];
+    /// let mut chunks = rows
+    ///     .into_iter()
+    ///     .map(|s| s.parse::<i32>().map_err(|_| s))
+    ///     .partition_result_chunks(4);
+    /// assert_eq!(chunks.next(), Some((vec![1, 3], vec!["x", "y"])));
+    /// assert_eq!(chunks.next(), Some((vec![5], vec![])));
+    /// assert_eq!(chunks.next(), None);
+    /// ```
+    #[cfg(feature = "std")]
+    fn partition_result_chunks<T, E>(self, n: usize) -> PartitionResultChunks<Self>
+    where
+        Self: Iterator<Item = Result<T, E>>;
+
+    /// Create an iterator that yields every chunk of `n` pairs unzipped into
+    /// two vectors, or fewer pairs for the last chunk.
+    ///
+    /// This suits columnar sinks that take a vector per column for every
+    /// batch, without collecting the pairs first.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let rows = [(1, 'a'), (2, 'b'), (3, 'c')];
+    /// let mut chunks = rows.into_iter().unzip_chunks(2);
+    /// assert_eq!(chunks.next(), Some((vec![1, 2], vec!['a', 'b'])));
+    /// assert_eq!(chunks.next(), Some((vec![3], vec!['c'])));
+    /// assert_eq!(chunks.next(), None);
+    /// ```
+    #[cfg(feature = "std")]
+    fn unzip_chunks<A, B>(self, n: usize) -> UnzipChunks<Self>
+    where
+        Self: Iterator<Item = (A, B)>;
+
+    /// Create an iterator that yields every chunk of `n` elements as a sorted
+    /// `Vec`, or fewer elements for the last chunk.
+    ///
+    /// This is the run generation phase of an external merge sort: every run
+    /// fits in memory, and can be spooled before the runs are merged with
+    /// [`merge_sorted`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut runs = [5, 3, 9, 1, 4].into_iter().sorted_chunks(3);
+    /// assert_eq!(runs.next(), Some(vec![3, 5, 9]));
+    /// assert_eq!(runs.next(), Some(vec![1, 4]));
+    /// assert_eq!(runs.next(), None);
+    /// ```
+    #[cfg(feature = "std")]
+    #[allow(clippy::type_complexity)]
+    fn sorted_chunks(
+        self,
+        n: usize,
+    ) -> SortedChunks<Self, fn(&Self::Item, &Self::Item) -> std::cmp::Ordering>
+    where
+        Self::Item: Ord;
+
+    /// Similar to [`IterChunks::sorted_chunks`], but sorts every chunk with
+    /// the `compare` function, e.g. by a key.
+    ///
+    /// The sort is stable, so equal elements keep their order in the chunk.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let rows = [("b", 2), ("a", 1), ("c", 1)];
+    /// let mut runs = rows.into_iter().sorted_chunks_by(3, |a, b| a.1.cmp(&b.1));
+    /// assert_eq!(runs.next(), Some(vec![("a", 1), ("c", 1), ("b", 2)]));
+    /// ```
+    #[cfg(feature = "std")]
+    fn sorted_chunks_by<F>(self, n: usize, compare: F) -> SortedChunks<Self, F>
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> std::cmp::Ordering;
+
+    /// Create an iterator that groups consecutive elements with equal keys,
+    /// and yields every group with its key.
+    ///
+    /// A group never exceeds `max_n` elements: longer runs are split into
+    /// several chunks of the same key, the last of them possibly short.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_n` is 0.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let rows = ["a1", "a2", "a3", "b1", "a4"];
+    /// let batches = rows
+    ///     .into_iter()
+    ///     .chunk_runs(2, |row| row.as_bytes()[0])
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     batches,
+    ///     vec![
+    ///         (b'a', vec!["a1", "a2"]),
+    ///         (b'a', vec!["a3"]),
+    ///         (b'b', vec!["b1"]),
+    ///         (b'a', vec!["a4"]),
+    ///     ]
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    fn chunk_runs<K, F>(self, max_n: usize, key_fn: F) -> ChunkRuns<Self, K, F>
+    where
+        K: PartialEq,
+        F: FnMut(&Self::Item) -> K;
+
+    /// Create an iterator that compresses runs of equal consecutive elements
+    /// into `(item, run_length)` entries, e.g. to shrink a repetitive event
+    /// stream before batching it.
+    ///
+    /// The first element of every run is kept. If `max_run` is `Some`, longer
+    /// runs are split into several entries of at most `max_run` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_run` is `Some(0)`.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let runs = "aaaabcc".chars().rle_chunks(Some(3)).collect::<Vec<_>>();
+    /// assert_eq!(runs, vec![('a', 3), ('a', 1), ('b', 1), ('c', 2)]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn rle_chunks(self, max_run: Option<usize>) -> RleChunks<Self>
+    where
+        Self::Item: PartialEq;
+
+    /// Create an iterator that batches the `Some` elements, and ends every
+    /// batch at a `None`, which acts as an explicit flush marker.
+    ///
+    /// The elements after the last flush marker are yielded as a final batch.
+    /// Flush markers with nothing to flush are skipped by default, see
+    /// [`FlushChunks::empty_chunks`].
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let events = [Some(1), Some(2), None, Some(3), None, None, Some(4)];
+    /// let batches = events.into_iter().flush_chunks().collect::<Vec<_>>();
+    /// assert_eq!(batches, vec![vec![1, 2], vec![3], vec![4]]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn flush_chunks<T>(self) -> FlushChunks<Self>
+    where
+        Self: Iterator<Item = Option<T>>;
+
+    /// Create an iterator that splits elements into chunks at the delimiters
+    /// matching `predicate`, which are not included in any chunk.
+    ///
+    /// Every delimiter terminates a chunk, like [`str::split_terminator`]. By
+    /// default, consecutive delimiters yield empty chunks, see
+    /// [`SplitChunks::empty_chunks`].
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let lines = b"ab\ncd\n\ne".iter().split_chunks(|&&b| b == b'\n');
+    /// assert_eq!(lines.map(|line| line.len()).collect::<Vec<_>>(), [2, 2, 0, 1]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn split_chunks<P>(self, predicate: P) -> SplitChunks<Self, P>
+    where
+        P: FnMut(&Self::Item) -> bool;
+
+    /// Create an iterator-liked struct that yields chunks with boundaries
+    /// chosen by `strategy`.
+    ///
+    /// This is the extension point for weight limits, token budgets or
+    /// protocol-specific framing. [`CountStrategy`] gives the same boundaries
+    /// as [`chunks`]. See [`ChunkStrategy`] for how the strategy is called.
+    ///
+    /// ```
+    /// use iter_chunks::{CountStrategy, IterChunks};
+    ///
+    /// let mut chunks = (0..5).chunks_with(CountStrategy::new(2));
+    /// let mut res = vec![];
+    /// chunks.for_each(|chunk| res.push(chunk.collect::<Vec<_>>()));
+    /// assert_eq!(res, vec![vec![0, 1], vec![2, 3], vec![4]]);
+    /// ```
+    ///
+    /// [`chunks`]: IterChunks::chunks
+    #[cfg(feature = "std")]
+    fn chunks_with<S>(self, strategy: S) -> ChunksWith<Self, S>
+    where
+        S: ChunkStrategy<Self::Item>;
+
+    /// Create an iterator-liked struct that yields chunks whose total byte
+    /// length stays within `max_bytes`, such as the bodies of bulk requests
+    /// under a size cap.
+    ///
+    /// A chunk is closed before the element that would take it over the
+    /// budget, and [`ChunkWith::bytes`] returns its running size. An element
+    /// longer than `max_bytes` gets its own chunk; see [`chunks_by_weight`]
+    /// for other ways to handle it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_bytes` is 0.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let docs = [r#"{"id":1}"#, r#"{"id":2}"#, r#"{"id":3}"#];
+    /// let mut chunks = docs.into_iter().chunks_by_bytes(20);
+    /// let mut bodies = vec![];
+    /// while let Some(mut chunk) = chunks.next() {
+    ///     let body = chunk.by_ref().collect::<Vec<_>>().join("\n");
+    ///     assert!(chunk.bytes() <= 20);
+    ///     bodies.push(body);
+    /// }
+    /// assert_eq!(bodies, ["{\"id\":1}\n{\"id\":2}", "{\"id\":3}"]);
+    /// ```
+    ///
+    /// [`chunks_by_weight`]: IterChunks::chunks_by_weight
+    #[cfg(feature = "std")]
+    fn chunks_by_bytes(self, max_bytes: usize) -> ChunksWith<Self, ByteStrategy>
+    where
+        Self::Item: AsRef<[u8]>;
+
+    /// Create an iterator-liked struct that groups elements into tumbling
+    /// windows of `window` wide, by the timestamp returned by `ts_fn`.
+    ///
+    /// Windows are aligned to multiples of `window`, and every chunk holds a
+    /// run of consecutive elements in the same window, so empty windows yield
+    /// no chunk. Elements are expected in timestamp order: an out-of-order
+    /// element starts a new chunk.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is 0.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// // (timestamp in seconds, value)
+    /// let metrics = [(0, 1), (42, 2), (61, 3), (185, 4)];
+    /// let mut chunks = metrics.into_iter().chunk_by_window(|&(ts, _)| ts, 60);
+    /// let mut sums = vec![];
+    /// chunks.for_each(|chunk| sums.push(chunk.map(|(_, v)| v).sum::<i32>()));
+    /// assert_eq!(sums, vec![3, 3, 4]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn chunk_by_window<F>(self, ts_fn: F, window: u64) -> ChunksWith<Self, TumblingWindow<F>>
+    where
+        F: FnMut(&Self::Item) -> u64;
+
+    /// Create an iterator-liked struct that splits elements into sessions,
+    /// closing a chunk whenever the timestamp returned by `ts_fn` grows by
+    /// more than `gap` between two consecutive elements.
+    ///
+    /// A timestamp smaller than the previous one counts as a gap of 0.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// // (timestamp in seconds, page)
+    /// let clicks = [(0, "a"), (20, "b"), (400, "c"), (410, "d")];
+    /// let mut sessions = clicks.into_iter().sessionize(300, |&(ts, _)| ts);
+    /// let mut res = vec![];
+    /// sessions.for_each(|chunk| res.push(chunk.map(|(_, page)| page).collect::<Vec<_>>()));
+    /// assert_eq!(res, vec![vec!["a", "b"], vec!["c", "d"]]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn sessionize<F>(self, gap: u64, ts_fn: F) -> ChunksWith<Self, SessionWindow<F>>
+    where
+        F: FnMut(&Self::Item) -> u64;
+
+    /// Create an iterator that yields chunks whose total weight, as returned
+    /// by `weight_fn`, doesn't exceed `budget`.
+    ///
+    /// A chunk is closed before the element that would take it over the
+    /// budget. A single element heavier than the budget is handled by
+    /// `policy`: it can get its own chunk, be yielded as an
+    /// [`OversizedItem`] error, or be split into lighter pieces.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `budget` is 0.
+    ///
+    /// ```
+    /// use iter_chunks::{IterChunks, OversizedPolicy};
+    ///
+    /// let words = ["aaa", "bb", "c", "dddddddd", "ee"];
+    /// let mut chunks = words
+    ///     .into_iter()
+    ///     .chunks_by_weight(5, |s| s.len(), OversizedPolicy::Error);
+    /// assert_eq!(chunks.next(), Some(Ok(vec!["aaa", "bb"])));
+    /// assert_eq!(chunks.next(), Some(Ok(vec!["c"])));
+    /// assert_eq!(chunks.next().unwrap().unwrap_err().item, "dddddddd");
+    /// assert_eq!(chunks.next(), Some(Ok(vec!["ee"])));
+    /// assert_eq!(chunks.next(), None);
+    /// ```
+    #[cfg(feature = "std")]
+    fn chunks_by_weight<F>(
+        self,
+        budget: usize,
+        weight_fn: F,
+        policy: OversizedPolicy<Self::Item>,
+    ) -> WeightChunks<Self, F>
+    where
+        F: FnMut(&Self::Item) -> usize;
+
+    /// Create an iterator that yields up to `k` uniformly sampled elements
+    /// from every chunk of `n` elements.
+    ///
+    /// Sampling uses reservoir sampling within the chunk, so the chunk is never
+    /// collected as a whole. The sampled elements are not guaranteed to keep
+    /// their original order.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    /// use rand::rngs::SmallRng;
+    /// use rand::SeedableRng;
+    ///
+    /// let rng = SmallRng::seed_from_u64(0);
+    /// for sample in (0..100).sample_chunks(10, 2, rng) {
+    ///     assert_eq!(sample.len(), 2);
+    /// }
+    /// ```
+    #[cfg(feature = "rand")]
+    fn sample_chunks<R: rand::Rng>(self, n: usize, k: usize, rng: R) -> SampleChunks<Self, R>;
+
+    /// Create an iterator that yields randomized batches of `n` elements,
+    /// shuffling through a buffer of `buffer_size` elements.
+    ///
+    /// This matches the `shuffle(buffer_size).batch(n)` idiom of common data
+    /// loaders: every output element is drawn uniformly from the buffer, and
+    /// its slot is refilled from the underlying iterator. A buffer at least as
+    /// large as the input gives a uniform shuffle.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    /// use rand::rngs::SmallRng;
+    /// use rand::SeedableRng;
+    ///
+    /// let rng = SmallRng::seed_from_u64(0);
+    /// let mut all = (0..10).shuffled_chunks(4, 3, rng).flatten().collect::<Vec<_>>();
+    /// all.sort();
+    /// assert_eq!(all, (0..10).collect::<Vec<_>>());
+    /// ```
+    #[cfg(feature = "rand")]
+    fn shuffled_chunks<R: rand::Rng>(
+        self,
+        buffer_size: usize,
+        n: usize,
+        rng: R,
+    ) -> ShuffledChunks<Self, R>;
 }
 
 impl<I> IterChunks for I
@@ -29,58 +923,976 @@ where
     I: Iterator,
 {
     fn chunks(self, n: usize) -> Chunks<Self> {
-        assert_ne!(n, 0);
-        Chunks {
-            inner: self,
-            n,
-            end_flag: false,
-        }
+        Chunks::new(self, n)
     }
-}
 
-/// An iterator-like struct that yields chunks.
-///
-/// This `struct` is created by [`chunks`] method on [`IterChunks`]. See its
-/// documentation for more.
-///
-/// [`chunks`]: IterChunks::chunks
-pub struct Chunks<I: Iterator> {
-    inner: I,
-    n: usize,
-    end_flag: bool,
-}
+    fn next_array<const N: usize>(&mut self) -> Option<[Self::Item; N]> {
+        array::next_array_partial(self).ok()
+    }
 
-impl<I: Iterator> Chunks<I> {
-    /// Similar to [`Iterator::next`], but not implements [`Iterator`] due to
-    /// lifetime.
-    ///
-    /// The underlying iterator implementations may choose to resume iteration
-    /// after finished, so calling `Chunks::next` may also return `Some(Chunk)`
-    /// after returning `None`.
-    #[allow(clippy::should_implement_trait)]
-    pub fn next(&mut self) -> Option<Chunk<'_, I>> {
-        if self.end_flag {
-            // The inner iterator may be resumable.
-            self.end_flag = false;
-            None
-        } else {
-            match self.inner.next() {
-                Some(v) => {
-                    let n = self.n;
-                    Some(Chunk {
-                        first: Some(v),
-                        parent: self,
-                        n: n - 1,
-                    })
-                }
-                None => None,
-            }
-        }
+    fn next_array_partial<const N: usize>(&mut self) -> Result<[Self::Item; N], Vec<Self::Item>> {
+        array::next_array_partial(self)
     }
 
-    /// Similar to [`Iterator::for_each`].
-    ///
-    /// ```
+    fn array_windows<const N: usize>(self) -> ArrayWindows<Self, N>
+    where
+        Self::Item: Clone,
+    {
+        ArrayWindows::new(self)
+    }
+
+    #[cfg(feature = "std")]
+    fn tuple_chunks<T>(self) -> TupleChunks<Self, T>
+    where
+        T: TupleChunk<Item = Self::Item>,
+    {
+        TupleChunks::new(self)
+    }
+
+    #[cfg(feature = "std")]
+    fn chunks_adaptive<F>(self, initial: usize, f: F) -> AdaptiveChunks<Self, F>
+    where
+        F: FnMut(usize, Duration) -> usize,
+    {
+        AdaptiveChunks::new(self, initial, f)
+    }
+
+    #[cfg(feature = "std")]
+    fn rechunk_bytes(self, n: usize) -> RechunkBytes<Self>
+    where
+        Self::Item: AsRef<[u8]>,
+    {
+        RechunkBytes::new(self, n)
+    }
+
+    fn rechunk(self, n: usize) -> Chunks<Flatten<Self>>
+    where
+        Self::Item: IntoIterator,
+    {
+        self.flatten().chunks(n)
+    }
+
+    #[cfg(feature = "std")]
+    fn chunks_cursor(self, n: usize) -> ChunksCursor<Self>
+    where
+        Self: Clone + ExactSizeIterator,
+    {
+        ChunksCursor::new(self, n)
+    }
+
+    #[cfg(feature = "std")]
+    fn parts(self, k: usize) -> EvenParts<Self>
+    where
+        Self: ExactSizeIterator,
+    {
+        EvenParts::new(self, k)
+    }
+
+    #[cfg(feature = "std")]
+    fn double_ended_chunks(self, n: usize) -> DoubleEndedChunks<Self>
+    where
+        Self: DoubleEndedIterator + ExactSizeIterator,
+    {
+        DoubleEndedChunks::new(self, n)
+    }
+
+    #[cfg(feature = "std")]
+    fn chunks_strict_exact(self, n: usize) -> ExactChunks<Self> {
+        ExactChunks::new(Chunks::new(self, n))
+    }
+
+    #[cfg(feature = "std")]
+    fn shared_chunks(self, n: usize) -> SharedChunks<Self> {
+        SharedChunks::new(self, n)
+    }
+
+    #[cfg(feature = "std")]
+    fn partition_result_chunks<T, E>(self, n: usize) -> PartitionResultChunks<Self>
+    where
+        Self: Iterator<Item = Result<T, E>>,
+    {
+        PartitionResultChunks::new(self, n)
+    }
+
+    #[cfg(feature = "std")]
+    fn unzip_chunks<A, B>(self, n: usize) -> UnzipChunks<Self>
+    where
+        Self: Iterator<Item = (A, B)>,
+    {
+        UnzipChunks::new(self, n)
+    }
+
+    #[cfg(feature = "std")]
+    fn sorted_chunks(
+        self,
+        n: usize,
+    ) -> SortedChunks<Self, fn(&Self::Item, &Self::Item) -> std::cmp::Ordering>
+    where
+        Self::Item: Ord,
+    {
+        SortedChunks::new(self, n, Ord::cmp)
+    }
+
+    #[cfg(feature = "std")]
+    fn sorted_chunks_by<F>(self, n: usize, compare: F) -> SortedChunks<Self, F>
+    where
+        F: FnMut(&Self::Item, &Self::Item) -> std::cmp::Ordering,
+    {
+        SortedChunks::new(self, n, compare)
+    }
+
+    #[cfg(feature = "std")]
+    fn chunk_runs<K, F>(self, max_n: usize, key_fn: F) -> ChunkRuns<Self, K, F>
+    where
+        K: PartialEq,
+        F: FnMut(&Self::Item) -> K,
+    {
+        ChunkRuns::new(self, max_n, key_fn)
+    }
+
+    #[cfg(feature = "std")]
+    fn rle_chunks(self, max_run: Option<usize>) -> RleChunks<Self>
+    where
+        Self::Item: PartialEq,
+    {
+        RleChunks::new(self, max_run)
+    }
+
+    #[cfg(feature = "std")]
+    fn flush_chunks<T>(self) -> FlushChunks<Self>
+    where
+        Self: Iterator<Item = Option<T>>,
+    {
+        FlushChunks::new(self)
+    }
+
+    #[cfg(feature = "std")]
+    fn split_chunks<P>(self, predicate: P) -> SplitChunks<Self, P>
+    where
+        P: FnMut(&Self::Item) -> bool,
+    {
+        SplitChunks::new(self, predicate)
+    }
+
+    #[cfg(feature = "std")]
+    fn chunks_with<S>(self, strategy: S) -> ChunksWith<Self, S>
+    where
+        S: ChunkStrategy<Self::Item>,
+    {
+        ChunksWith::new(self, strategy)
+    }
+
+    #[cfg(feature = "std")]
+    fn chunks_by_bytes(self, max_bytes: usize) -> ChunksWith<Self, ByteStrategy>
+    where
+        Self::Item: AsRef<[u8]>,
+    {
+        ChunksWith::new(self, ByteStrategy::new(max_bytes))
+    }
+
+    #[cfg(feature = "std")]
+    fn chunk_by_window<F>(self, ts_fn: F, window: u64) -> ChunksWith<Self, TumblingWindow<F>>
+    where
+        F: FnMut(&Self::Item) -> u64,
+    {
+        ChunksWith::new(self, TumblingWindow::new(ts_fn, window))
+    }
+
+    #[cfg(feature = "std")]
+    fn sessionize<F>(self, gap: u64, ts_fn: F) -> ChunksWith<Self, SessionWindow<F>>
+    where
+        F: FnMut(&Self::Item) -> u64,
+    {
+        ChunksWith::new(self, SessionWindow::new(gap, ts_fn))
+    }
+
+    #[cfg(feature = "std")]
+    fn chunks_by_weight<F>(
+        self,
+        budget: usize,
+        weight_fn: F,
+        policy: OversizedPolicy<Self::Item>,
+    ) -> WeightChunks<Self, F>
+    where
+        F: FnMut(&Self::Item) -> usize,
+    {
+        WeightChunks::new(self, budget, weight_fn, policy)
+    }
+
+    #[cfg(feature = "rand")]
+    fn sample_chunks<R: rand::Rng>(self, n: usize, k: usize, rng: R) -> SampleChunks<Self, R> {
+        SampleChunks::new(self, n, k, rng)
+    }
+
+    #[cfg(feature = "rand")]
+    fn shuffled_chunks<R: rand::Rng>(
+        self,
+        buffer_size: usize,
+        n: usize,
+        rng: R,
+    ) -> ShuffledChunks<Self, R> {
+        ShuffledChunks::new(self, buffer_size, n, rng)
+    }
+}
+
+/// An iterator-like struct that yields chunks.
+///
+/// This `struct` is created by [`chunks`] method on [`IterChunks`]. See its
+/// documentation for more.
+///
+/// With the `tracing` feature, every chunk gets a `chunk` span with its index
+/// and length, which is entered around the closure of [`Chunks::for_each`] and
+/// [`Chunks::fold`].
+/// The exhaustion of the underlying iterator and a short final chunk are
+/// reported as events.
+///
+/// [`chunks`]: IterChunks::chunks
+pub struct Chunks<I: Iterator> {
+    inner: I,
+    n: usize,
+    end_flag: bool,
+    // Whether the inner iterator may be polled again after returning `None`.
+    resumable: bool,
+    // Set once a non-resumable inner iterator returns `None`.
+    exhausted: bool,
+    // Elements pulled ahead by adaptors, yielded before the inner iterator.
+    buffered: VecDeque<I::Item>,
+    // The number of elements pulled from the inner iterator or prepended,
+    // including the buffered ones.
+    pulled: usize,
+    // The chunks injected by `prepend_chunk` as the index of their first
+    // element, see `front`, and their length, the next one last.
+    prepended: Vec<(usize, usize)>,
+    // The consumed count at which the last yielded chunk ends.
+    chunk_end: usize,
+    // The furthest `front` reached, so that the elements put back are the
+    // ones before it.
+    put_back_mark: usize,
+    // The index of the next chunk, and the span of the current chunk with the
+    // consumed count when it started.
+    #[cfg(feature = "tracing")]
+    chunk_index: usize,
+    #[cfg(feature = "tracing")]
+    span: Option<(tracing::Span, usize)>,
+}
+
+impl<I: Iterator> Chunks<I> {
+    pub(crate) fn new(inner: I, n: usize) -> Self {
+        assert_ne!(n, 0);
+        Chunks {
+            inner,
+            n,
+            end_flag: false,
+            resumable: true,
+            exhausted: false,
+            buffered: VecDeque::new(),
+            pulled: 0,
+            prepended: Vec::new(),
+            chunk_end: 0,
+            put_back_mark: 0,
+            #[cfg(feature = "tracing")]
+            chunk_index: 0,
+            #[cfg(feature = "tracing")]
+            span: None,
+        }
+    }
+
+    /// Returns the configured chunk size `n`.
+    ///
+    /// Every chunk yields at most `chunk_size()` elements.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let chunks = [1, 2, 3].into_iter().chunks(2);
+    /// assert_eq!(chunks.chunk_size(), 2);
+    /// ```
+    pub fn chunk_size(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the number of source elements yielded by chunks so far.
+    ///
+    /// Elements pulled ahead from the underlying iterator but not yet yielded
+    /// are not counted, so the offset can be persisted and used to resume a
+    /// job from the first element that was not processed.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut chunks = (0..10).chunks(4);
+    /// chunks.next().unwrap().take(3).for_each(drop);
+    /// assert_eq!(chunks.elements_consumed(), 3);
+    /// chunks.next().unwrap().for_each(drop);
+    /// assert_eq!(chunks.elements_consumed(), 7);
+    /// ```
+    pub fn elements_consumed(&self) -> usize {
+        self.consumed()
+    }
+
+    /// Puts `item` back, so that it's yielded by the next chunk before any
+    /// other element.
+    ///
+    /// Put back elements count towards the chunk size like any other, and are
+    /// subtracted from [`Chunks::elements_consumed`]. If the next chunk was
+    /// injected by [`Chunks::prepend_chunk`], it grows to include them.
+    ///
+    /// At most `n` elements can be put back before they're yielded again:
+    /// beyond that, `item` is returned as an error. Elements pulled ahead,
+    /// e.g. by [`Chunks::peek_len`], don't count.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut chunks = (0..5).chunks(2);
+    /// let mut chunk = chunks.next().unwrap();
+    /// assert_eq!(chunk.next(), Some(0));
+    /// let over_read = chunk.next().unwrap();
+    /// chunks.put_back(over_read).unwrap();
+    /// assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![1, 2]);
+    /// ```
+    pub fn put_back(&mut self, item: I::Item) -> Result<(), I::Item> {
+        if self.put_back_len() >= self.n {
+            return Err(item);
+        }
+        self.extend_prepended(1);
+        self.buffered.push_front(item);
+        Ok(())
+    }
+
+    /// Puts `items` back in order, so that they're yielded by the next chunks
+    /// before any other element.
+    ///
+    /// Similar to [`Chunks::put_back`], but either all or none of the items
+    /// are put back.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut chunks = (3..5).chunks(2);
+    /// chunks.put_back_many([1, 2]).unwrap();
+    /// assert_eq!(chunks.put_back_many([0]), Err(vec![0]));
+    /// assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![1, 2]);
+    /// assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![3, 4]);
+    /// ```
+    pub fn put_back_many(
+        &mut self,
+        items: impl IntoIterator<Item = I::Item>,
+    ) -> Result<(), Vec<I::Item>> {
+        let mut items = items.into_iter().collect::<Vec<_>>();
+        if self.put_back_len() + items.len() > self.n {
+            return Err(items);
+        }
+        self.extend_prepended(items.len());
+        self.unread(&mut items);
+        Ok(())
+    }
+
+    /// Returns the length of the next chunk without yielding it, or 0 if
+    /// [`Chunks::next`] would return `None`.
+    ///
+    /// Up to `n` elements are pulled ahead from the underlying iterator and
+    /// buffered, so they're still yielded by the next chunk.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut chunks = b"hello".iter().copied().chunks(3);
+    /// let mut out = vec![];
+    /// loop {
+    ///     let len = chunks.peek_len();
+    ///     if len == 0 {
+    ///         break;
+    ///     }
+    ///     out.push(len as u8);
+    ///     out.extend(chunks.next().unwrap());
+    /// }
+    /// assert_eq!(out, b"\x03hel\x02lo");
+    /// ```
+    pub fn peek_len(&mut self) -> usize {
+        let len = self.chunk_len_at(self.front());
+        while self.buffered.len() < len && !self.end_flag {
+            match self.pull_inner() {
+                Some(v) => self.buffered.push_back(v),
+                // The next chunk ends here, or doesn't exist if nothing is
+                // buffered.
+                None => self.end_flag = true,
+            }
+        }
+        self.buffered.len().min(len)
+    }
+
+    /// Injects `chunk` at the front, so that it's yielded as a whole by the
+    /// next [`Chunks::next`], before any other element. The following chunks
+    /// keep their boundaries. An empty `chunk` is ignored.
+    ///
+    /// The injected elements count in [`Chunks::elements_consumed`] once
+    /// yielded. If the injected chunk is only partially consumed, its rest is
+    /// yielded by the next chunk, as with any other chunk.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// // Resume from a partial batch persisted before a restart.
+    /// let mut chunks = (3..8).chunks(2);
+    /// chunks.prepend_chunk(vec![0, 1, 2]);
+    /// let mut res = vec![];
+    /// chunks.for_each(|chunk| res.push(chunk.collect::<Vec<_>>()));
+    /// assert_eq!(res, vec![vec![0, 1, 2], vec![3, 4], vec![5, 6], vec![7]]);
+    /// ```
+    pub fn prepend_chunk(&mut self, mut chunk: Vec<I::Item>) {
+        if chunk.is_empty() {
+            return;
+        }
+        let front = self.front();
+        let len = chunk.len();
+        // The elements already buffered move behind the injected ones.
+        self.prepended
+            .retain(|&(start, _)| start.wrapping_sub(front) as isize >= 0);
+        for (start, _) in &mut self.prepended {
+            *start = start.wrapping_add(len);
+        }
+        self.prepended.push((front, len));
+        self.pulled += len;
+        self.unread(&mut chunk);
+    }
+
+    /// Replaces the underlying iterator with `inner`, and keeps the chunking
+    /// state: the buffered elements and the counters, e.g.
+    /// [`Chunks::elements_consumed`], carry over to the returned [`Chunks`].
+    ///
+    /// To let a chunk span the seam instead of ending short, swap before the
+    /// short chunk is yielded, e.g. once [`Chunks::peek_len`] is less than the
+    /// chunk size: the elements it pulled ahead start the next chunk.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut chunks = vec![1, 2, 3].into_iter().chunks(2);
+    /// assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![1, 2]);
+    /// assert_eq!(chunks.peek_len(), 1);
+    ///
+    /// let mut chunks = chunks.resume_with(vec![4, 5, 6].into_iter());
+    /// assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![3, 4]);
+    /// assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![5, 6]);
+    /// assert_eq!(chunks.elements_consumed(), 6);
+    /// ```
+    pub fn resume_with<J>(self, inner: J) -> Chunks<J>
+    where
+        J: Iterator<Item = I::Item>,
+    {
+        Chunks {
+            inner,
+            n: self.n,
+            // The new iterator hasn't ended yet.
+            end_flag: false,
+            resumable: self.resumable,
+            exhausted: false,
+            buffered: self.buffered,
+            pulled: self.pulled,
+            prepended: self.prepended,
+            chunk_end: self.chunk_end,
+            put_back_mark: self.put_back_mark,
+            #[cfg(feature = "tracing")]
+            chunk_index: self.chunk_index,
+            #[cfg(feature = "tracing")]
+            span: None,
+        }
+    }
+
+    /// Makes the first `None` from the underlying iterator terminal.
+    ///
+    /// By default, [`Chunks`] supports resumable iterators, and may yield
+    /// chunks again after returning `None`. In non-resumable mode, the
+    /// underlying iterator is never polled again once it returns `None`, and
+    /// [`Chunks::next`] keeps returning `None` after the remaining elements are
+    /// yielded.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut i = 0;
+    /// let resumable = std::iter::from_fn(move || {
+    ///     i += 1;
+    ///     (i != 3).then_some(i)
+    /// });
+    /// let mut chunks = resumable.chunks(4).non_resumable();
+    /// assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![1, 2]);
+    /// assert!(chunks.next().is_none());
+    /// assert!(chunks.next().is_none());
+    /// ```
+    pub fn non_resumable(mut self) -> Self {
+        self.resumable = false;
+        self
+    }
+
+    /// Buffers every chunk internally, so that a chunk whose processing
+    /// failed can be yielded again instead of being lost.
+    ///
+    /// Call [`CheckpointedChunks::replay`] after a failure, and the next call
+    /// to [`CheckpointedChunks::next`] yields the same chunk again. Only the
+    /// current chunk is buffered, so the underlying iterator doesn't need to be
+    /// cloned.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut chunks = (0..4).chunks(2).checkpointed();
+    /// let mut attempts = 0;
+    /// let mut written = vec![];
+    /// while let Some(chunk) = chunks.next() {
+    ///     attempts += 1;
+    ///     if attempts == 1 {
+    ///         // Simulate a transient failure.
+    ///         chunks.replay();
+    ///         continue;
+    ///     }
+    ///     written.push(chunk.to_vec());
+    /// }
+    /// assert_eq!(written, vec![vec![0, 1], vec![2, 3]]);
+    /// assert_eq!(attempts, 3);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn checkpointed(self) -> CheckpointedChunks<I> {
+        CheckpointedChunks::new(self)
+    }
+
+    /// Maps every chunk with `f` on a pool of `workers` threads, and yields
+    /// the results in the original chunk order.
+    ///
+    /// Chunks are collected into `Vec`s on the calling thread and sent to the
+    /// workers. At most `2 * workers` chunks are in flight, so a slow chunk
+    /// holds back the source instead of buffering unboundedly. A panic in `f`
+    /// is propagated to the caller when its result is due.
+    ///
+    /// The workers stop when the returned iterator is dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `workers` is 0.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let sums = (0..10)
+    ///     .chunks(3)
+    ///     .par_map_ordered(2, |chunk| chunk.iter().sum::<i32>())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(sums, vec![3, 12, 21, 9]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn par_map_ordered<R, F>(self, workers: usize, f: F) -> ParMapOrdered<I, R>
+    where
+        I::Item: Send + 'static,
+        R: Send + 'static,
+        F: Fn(Vec<I::Item>) -> R + Send + Sync + 'static,
+    {
+        ParMapOrdered::new(self, workers, f)
+    }
+
+    /// Joins the elements of every chunk with `sep`, and yields one `String`
+    /// per chunk.
+    ///
+    /// Use [`Chunk::format`] to format a chunk lazily without allocating.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let csv = [1, 2, 3, 4, 5]
+    ///     .into_iter()
+    ///     .chunks(2)
+    ///     .format_chunks(",")
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(csv, ["1,2", "3,4", "5"]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn format_chunks(self, sep: &str) -> FormatChunks<I>
+    where
+        I::Item: core::fmt::Display,
+    {
+        FormatChunks::new(self, sep.to_owned())
+    }
+
+    /// Similar to [`Iterator::next`], but not implements [`Iterator`] due to
+    /// lifetime.
+    ///
+    /// The underlying iterator implementations may choose to resume iteration
+    /// after finished, so calling `Chunks::next` may also return `Some(Chunk)`
+    /// after returning `None`. Use [`Chunks::non_resumable`] to opt out.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<Chunk<'_, I>> {
+        let first = self.next_first()?;
+        Some(self.chunk_with_first(first))
+    }
+
+    /// Similar to [`Chunks::next`], but the chunk spans the next `k` chunks,
+    /// i.e. up to `k * n` elements. The following chunks are sized as usual.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is 0.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut chunks = (0..10).chunks(2);
+    /// assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![0, 1]);
+    /// // Catch up with a bigger batch.
+    /// let merged = chunks.merge_next(3).unwrap();
+    /// assert_eq!(merged.collect::<Vec<_>>(), vec![2, 3, 4, 5, 6, 7]);
+    /// assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![8, 9]);
+    /// ```
+    pub fn merge_next(&mut self, k: usize) -> Option<Chunk<'_, I>> {
+        assert_ne!(k, 0);
+        let len = k.saturating_mul(self.n);
+        self.next_with_len(len)
+    }
+
+    /// Similar to [`Chunks::next`], but the chunk yields up to `k` elements
+    /// instead of `n`. The following chunks are sized as usual.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is 0.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// // A 2-byte header followed by 3-byte records.
+    /// let mut chunks = b"HDabcdef".iter().copied().chunks(3);
+    /// assert_eq!(chunks.next_n(2).unwrap().collect::<Vec<_>>(), b"HD");
+    /// assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), b"abc");
+    /// assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), b"def");
+    /// ```
+    pub fn next_n(&mut self, k: usize) -> Option<Chunk<'_, I>> {
+        assert_ne!(k, 0);
+        self.next_with_len(k)
+    }
+
+    /// Skips the next `k` chunks, i.e. up to `k * n` elements, or the length
+    /// of the chunks injected by [`Chunks::prepend_chunk`], and returns the
+    /// number of elements skipped.
+    ///
+    /// The underlying iterator is advanced in bulk with [`Iterator::nth`]
+    /// where possible, so skipping over a range or a slice is O(1). If it ends
+    /// before, the next call to [`Chunks::next`] returns `None`.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut chunks = (0..u64::MAX).chunks(1 << 20);
+    /// assert_eq!(chunks.skip_chunks(1 << 30), 1 << 50);
+    /// assert_eq!(chunks.next().unwrap().next(), Some(1 << 50));
+    ///
+    /// let mut chunks = (0..5).chunks(2);
+    /// assert_eq!(chunks.skip_chunks(3), 5);
+    /// assert!(chunks.next().is_none());
+    /// ```
+    pub fn skip_chunks(&mut self, k: usize) -> usize {
+        #[cfg(feature = "tracing")]
+        self.finish_span();
+        let len = self.chunks_len(k);
+        let skipped = self.skip_elements(len);
+        if skipped < len {
+            self.end_flag = true;
+        }
+        skipped
+    }
+
+    /// Skips the rest of the last yielded chunk that was not consumed, so that
+    /// the next chunk starts at its usual boundary, and returns the number of
+    /// elements skipped.
+    ///
+    /// By default, the rest of a chunk dropped early, e.g. by a `break`, is
+    /// yielded by the next chunk. Calling this after the early drop recovers
+    /// the boundaries for that chunk only, unlike
+    /// [`ChunksBuilder::auto_align`] which aligns every chunk. Elements put
+    /// back with [`Chunks::put_back`] count as unconsumed, while prepended
+    /// chunks are never skipped.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut chunks = (0..8).chunks(3);
+    /// let mut heads = vec![];
+    /// while let Some(mut chunk) = chunks.next() {
+    ///     // Only the head of every chunk is needed.
+    ///     heads.push(chunk.next().unwrap());
+    ///     chunks.align_to_next_boundary();
+    /// }
+    /// assert_eq!(heads, [0, 3, 6]);
+    /// ```
+    ///
+    /// [`ChunksBuilder::auto_align`]: crate::ChunksBuilder::auto_align
+    pub fn align_to_next_boundary(&mut self) -> usize {
+        #[cfg(feature = "tracing")]
+        self.finish_span();
+        let mut k = self.chunk_end.saturating_sub(self.consumed());
+        if self.end_flag {
+            // The inner iterator already ended, only the buffered elements are
+            // left.
+            k = k.min(self.buffered.len());
+        }
+        let skipped = self.skip_elements(k);
+        if skipped < k {
+            self.end_flag = true;
+        }
+        skipped
+    }
+
+    /// Similar to [`Chunks::next`], but the chunk yields up to `len` elements.
+    fn next_with_len(&mut self, len: usize) -> Option<Chunk<'_, I>> {
+        let first = self.next_first()?;
+        let mut chunk = self.chunk_with_first(first);
+        chunk.n = len - 1;
+        chunk.parent.chunk_end = chunk
+            .parent
+            .consumed()
+            .saturating_sub(1)
+            .saturating_add(len);
+        Some(chunk)
+    }
+
+    /// Pulls the first element of the next chunk.
+    ///
+    /// Split from [`Chunks::next`] so that adaptors can decide whether a chunk
+    /// exists without holding a borrow of `self`.
+    pub(crate) fn next_first(&mut self) -> Option<I::Item> {
+        #[cfg(feature = "tracing")]
+        self.finish_span();
+        if let Some(v) = self.buffered.pop_front() {
+            Some(v)
+        } else if self.end_flag {
+            // The inner iterator may be resumable.
+            self.end_flag = false;
+            // The ended chunk has nothing left to align.
+            self.chunk_end = 0;
+            None
+        } else {
+            self.pull_inner()
+        }
+    }
+
+    /// Pulls the next element of the current chunk, the buffered ones first.
+    ///
+    /// If the inner iterator ends, the chunk ends, and the next call to
+    /// [`Chunks::next_first`] returns `None` once. Shared with the adaptors
+    /// that choose their own boundaries.
+    #[cfg(feature = "std")]
+    pub(crate) fn next_in_chunk(&mut self) -> Option<I::Item> {
+        if let Some(v) = self.buffered.pop_front() {
+            return Some(v);
+        }
+        if self.end_flag {
+            // The inner iterator already ended while the elements were
+            // buffered.
+            return None;
+        }
+        let v = self.pull_inner();
+        if v.is_none() {
+            self.end_chunk();
+        }
+        v
+    }
+
+    /// Ends the current chunk after the inner iterator returned `None`.
+    fn end_chunk(&mut self) {
+        #[cfg(feature = "tracing")]
+        if let Some((span, start)) = &self.span {
+            tracing::debug!(
+                parent: span,
+                expected = self.n,
+                got = self.consumed() - start,
+                "short final chunk"
+            );
+        }
+
+        // The parent chunks iterator should output None once.
+        self.end_flag = true;
+    }
+
+    /// Pulls an element from the inner iterator, unless it's a non-resumable
+    /// one that already returned `None`.
+    fn pull_inner(&mut self) -> Option<I::Item> {
+        if self.exhausted {
+            return None;
+        }
+        let v = self.inner.next();
+        match v {
+            Some(_) => self.pulled += 1,
+            None => self.inner_ended(),
+        }
+        v
+    }
+
+    /// Records that the inner iterator returned `None`.
+    fn inner_ended(&mut self) {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            consumed = self.consumed(),
+            resumable = self.resumable,
+            "inner iterator exhausted"
+        );
+        if !self.resumable {
+            self.exhausted = true;
+        }
+    }
+
+    /// Skips up to `k` elements, the buffered ones first, and returns the
+    /// number skipped. Fewer are skipped if the inner iterator ends, or already
+    /// ended while the elements were buffered. Reporting the end is left to
+    /// the caller.
+    ///
+    /// The elements the inner iterator reports with the lower bound of its
+    /// size hint are skipped with a single `nth`, which is O(1) for e.g.
+    /// ranges and slices.
+    pub(crate) fn skip_elements(&mut self, k: usize) -> usize {
+        let mut skipped = k.min(self.buffered.len());
+        self.buffered.drain(..skipped);
+        if skipped == k || self.end_flag || self.exhausted {
+            return skipped;
+        }
+        let bulk = (k - skipped).min(self.inner.size_hint().0);
+        if bulk > 0 {
+            if self.inner.nth(bulk - 1).is_none() {
+                // The size hint was wrong, and the count is lost.
+                self.inner_ended();
+                return skipped;
+            }
+            self.pulled += bulk;
+            skipped += bulk;
+        }
+        while skipped < k && self.pull_inner().is_some() {
+            skipped += 1;
+        }
+        skipped
+    }
+
+    /// The number of elements yielded by chunks so far.
+    pub(crate) fn consumed(&self) -> usize {
+        // Elements that were never pulled may be put back.
+        self.pulled.saturating_sub(self.buffered.len())
+    }
+
+    /// The index of the next element, counted like `pulled`. It wraps if
+    /// elements that were never pulled are put back.
+    fn front(&self) -> usize {
+        self.pulled.wrapping_sub(self.buffered.len())
+    }
+
+    /// Returns the length of the chunk that starts at the element indexed
+    /// `start`, which may be injected or cut short by an injected chunk.
+    fn chunk_len_at(&self, start: usize) -> usize {
+        for &(s, len) in self.prepended.iter().rev() {
+            match s.wrapping_sub(start) as isize {
+                0 => return len,
+                offset if offset > 0 => return self.n.min(offset as usize),
+                // Passed by a previous chunk.
+                _ => {}
+            }
+        }
+        self.n
+    }
+
+    /// Returns the number of elements in the next `k` chunks.
+    fn chunks_len(&self, mut k: usize) -> usize {
+        let front = self.front();
+        let mut len = 0usize;
+        for &(start, n) in self.prepended.iter().rev() {
+            if k == 0 {
+                break;
+            }
+            let offset = start.wrapping_sub(front) as isize;
+            if offset < len as isize {
+                continue;
+            }
+            // The usual chunks before the injected one, the last of them cut.
+            let gap = offset as usize - len;
+            let before = gap.div_ceil(self.n);
+            if k <= before {
+                return len + gap.min(k * self.n);
+            }
+            len = offset as usize + n;
+            k -= before + 1;
+        }
+        len.saturating_add(k.saturating_mul(self.n))
+    }
+
+    /// Returns the number of elements put back and not yielded again.
+    fn put_back_len(&mut self) -> usize {
+        let front = self.front();
+        if front.wrapping_sub(self.put_back_mark) as isize > 0 {
+            self.put_back_mark = front;
+        }
+        self.put_back_mark.wrapping_sub(front)
+    }
+
+    /// Grows the injected chunk at the front by `k` elements, which are put
+    /// back in front of it.
+    fn extend_prepended(&mut self, k: usize) {
+        let front = self.front();
+        if let Some((start, len)) = self.prepended.last_mut() {
+            if *start == front {
+                *start = start.wrapping_sub(k);
+                *len += k;
+            }
+        }
+    }
+
+    /// Moves `items` to the front of the buffer, so that they're yielded
+    /// before any element already buffered or left in the inner iterator.
+    pub(crate) fn unread(&mut self, items: &mut Vec<I::Item>) {
+        for v in items.drain(..).rev() {
+            self.buffered.push_front(v);
+        }
+    }
+
+    /// Builds the chunk that starts with `first`, which must be pulled by
+    /// [`Chunks::next_first`].
+    pub(crate) fn chunk_with_first(&mut self, first: I::Item) -> Chunk<'_, I> {
+        let start = self.front().wrapping_sub(1);
+        let n = self.chunk_len_at(start);
+        // Forget the injected chunks passed or started by this one.
+        while let Some(&(s, _)) = self.prepended.last() {
+            if s.wrapping_sub(start) as isize >= n as isize {
+                break;
+            }
+            self.prepended.pop();
+        }
+        self.chunk_end = self.consumed().saturating_sub(1).saturating_add(n);
+        #[cfg(feature = "tracing")]
+        {
+            let index = self.chunk_index;
+            self.chunk_index += 1;
+            let span = tracing::debug_span!("chunk", index, len = tracing::field::Empty);
+            // The first element is already pulled.
+            self.span = Some((span, self.consumed().saturating_sub(1)));
+        }
+        Chunk {
+            first: Some(first),
+            parent: self,
+            n: n - 1,
+            position: 0,
+        }
+    }
+
+    /// Records the length of the current chunk on its span.
+    #[cfg(feature = "tracing")]
+    fn finish_span(&mut self) {
+        if let Some((span, start)) = self.span.take() {
+            span.record("len", self.consumed().saturating_sub(start));
+        }
+    }
+
+    /// Similar to [`Iterator::for_each`].
+    ///
+    /// Every chunk borrows the underlying iterator, so the chunks are pulled
+    /// one by one. Consuming a chunk by internal iteration, e.g. with
+    /// [`Iterator::sum`] or [`Iterator::for_each`], still drives the
+    /// underlying iterator with its `try_fold`. [`Chunks::for_each_buffered`]
+    /// drives the whole iteration with a single `try_fold`.
+    ///
+    /// ```
     /// use iter_chunks::IterChunks;
     ///
     /// let arr = [1, 4, 2, 3, 5];
@@ -90,21 +1902,758 @@ impl<I: Iterator> Chunks<I> {
     /// ```
     pub fn for_each(&mut self, mut f: impl FnMut(Chunk<'_, I>)) {
         while let Some(item) = self.next() {
-            f(item)
+            Self::in_span(item, &mut f)
+        }
+    }
+
+    /// Similar to [`Chunks::for_each`], but `f` is an async closure, whose
+    /// future is awaited before the next chunk is pulled.
+    ///
+    /// Only [`core::future`] is used, so this works with any executor.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// # futures::executor::block_on(async {
+    /// let mut sent = vec![];
+    /// (1..=5)
+    ///     .chunks(2)
+    ///     .for_each_async(async |chunk| {
+    ///         // e.g. `client.send(chunk).await`
+    ///         std::future::ready(()).await;
+    ///         sent.push(chunk.collect::<Vec<_>>());
+    ///     })
+    ///     .await;
+    /// assert_eq!(sent, [vec![1, 2], vec![3, 4], vec![5]]);
+    /// # });
+    /// ```
+    pub async fn for_each_async(&mut self, mut f: impl AsyncFnMut(Chunk<'_, I>)) {
+        while let Some(item) = self.next() {
+            f(item).await
+        }
+    }
+
+    /// Similar to [`Iterator::fold`], but every chunk is still a lending
+    /// [`Chunk`] inside the closure.
+    ///
+    /// Like [`Chunks::for_each`], the chunks are pulled one by one, since a
+    /// [`Chunk`] can't borrow the underlying iterator inside its `try_fold`.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let arr = [1, 4, 2, 3, 5];
+    /// let max_sum = arr
+    ///     .into_iter()
+    ///     .chunks(2)
+    ///     .fold(0, |acc, chunk| acc.max(chunk.sum::<i32>()));
+    /// assert_eq!(max_sum, 5);
+    /// ```
+    pub fn fold<B>(mut self, init: B, mut f: impl FnMut(B, Chunk<'_, I>) -> B) -> B {
+        let mut acc = init;
+        while let Some(item) = self.next() {
+            acc = Self::in_span(item, |item| f(acc, item));
+        }
+        acc
+    }
+
+    /// Calls `f` with `chunk`, inside the span of the chunk with the `tracing`
+    /// feature.
+    fn in_span<R>(chunk: Chunk<'_, I>, f: impl FnOnce(Chunk<'_, I>) -> R) -> R {
+        #[cfg(feature = "tracing")]
+        let span = chunk.parent.span.as_ref().map(|(span, _)| span.clone());
+        #[cfg(feature = "tracing")]
+        let _enter = span.as_ref().map(|span| span.enter());
+        f(chunk)
+    }
+
+    /// Fills `buf` with the elements of the next chunk.
+    ///
+    /// `buf` is cleared first, then the next chunk is drained into it. Returns
+    /// `false` (leaving `buf` empty) when [`Chunks::next`] would return `None`.
+    ///
+    /// Reusing the same buffer gives slice access to every chunk with only one
+    /// allocation for the whole iteration.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut chunks = [1, 2, 3].into_iter().chunks(2);
+    /// let mut buf = Vec::new();
+    ///
+    /// assert!(chunks.next_buffered(&mut buf));
+    /// assert_eq!(buf, [1, 2]);
+    /// assert!(chunks.next_buffered(&mut buf));
+    /// assert_eq!(buf, [3]);
+    /// assert!(!chunks.next_buffered(&mut buf));
+    /// assert!(buf.is_empty());
+    /// ```
+    pub fn next_buffered(&mut self, buf: &mut Vec<I::Item>) -> bool {
+        buf.clear();
+        match self.next() {
+            Some(chunk) => {
+                buf.extend(chunk);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Similar to [`Chunks::for_each`], but passes every chunk as a slice
+    /// backed by a single reused buffer.
+    ///
+    /// The elements are grouped into chunks inside a single `try_fold` of the
+    /// underlying iterator, which is much faster than pulling them one by one
+    /// for iterators like [`alloc::vec::IntoIter`] or [`core::iter::Chain`].
+    /// The chunks, including the ones injected by [`Chunks::prepend_chunk`],
+    /// and their `tracing` spans are the same as with [`Chunks::for_each`].
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let arr = [1, 4, 2, 3, 5];
+    /// let mut lens = vec![];
+    /// arr.into_iter().chunks(2).for_each_buffered(|chunk| {
+    ///     lens.push(chunk.len());
+    /// });
+    /// assert_eq!(lens, [2, 2, 1]);
+    /// ```
+    pub fn for_each_buffered(&mut self, mut f: impl FnMut(&mut [I::Item])) {
+        let n = self.n;
+        let mut buf = Vec::with_capacity(n);
+        // The injected chunks are sized differently.
+        while !self.prepended.is_empty() {
+            if !self.next_buffered(&mut buf) {
+                return;
+            }
+            #[cfg(feature = "tracing")]
+            let _enter = self.span.as_ref().map(|(span, _)| span.clone().entered());
+            f(&mut buf);
+        }
+        buf.clear();
+        #[cfg(feature = "tracing")]
+        self.finish_span();
+        #[cfg(feature = "tracing")]
+        let mut span = None;
+        #[cfg(feature = "tracing")]
+        let mut chunk_index = self.chunk_index;
+        // Pushes an element, or flushes the last chunk on `None`.
+        let mut push = |buf: &mut Vec<I::Item>, v: Option<I::Item>| {
+            if let Some(v) = v {
+                #[cfg(feature = "tracing")]
+                if buf.is_empty() {
+                    let index = chunk_index;
+                    chunk_index += 1;
+                    span = Some(tracing::debug_span!(
+                        "chunk",
+                        index,
+                        len = tracing::field::Empty
+                    ));
+                }
+                buf.push(v);
+                if buf.len() < n {
+                    return;
+                }
+            } else if buf.is_empty() {
+                return;
+            }
+            #[cfg(feature = "tracing")]
+            let span = span.take().unwrap_or_else(tracing::Span::none);
+            #[cfg(feature = "tracing")]
+            if buf.len() < n {
+                tracing::debug!(
+                    parent: &span,
+                    expected = n,
+                    got = buf.len(),
+                    "short final chunk"
+                );
+            }
+            #[cfg(feature = "tracing")]
+            span.in_scope(|| f(buf));
+            #[cfg(not(feature = "tracing"))]
+            f(buf);
+            #[cfg(feature = "tracing")]
+            span.record("len", buf.len());
+            buf.clear();
+        };
+        while let Some(v) = self.buffered.pop_front() {
+            push(&mut buf, Some(v));
+        }
+        if !self.end_flag && !self.exhausted {
+            // `for_each` on `&mut I` is driven by `I::try_fold`.
+            self.inner.by_ref().for_each(|v| {
+                self.pulled += 1;
+                push(&mut buf, Some(v));
+            });
+            self.inner_ended();
+        }
+        push(&mut buf, None);
+        #[cfg(feature = "tracing")]
+        {
+            self.chunk_index = chunk_index;
+        }
+        // Like `Chunks::next`, the end is reported once.
+        self.end_flag = false;
+    }
+
+    /// Similar to [`Chunks::for_each_buffered`], but the elements left in the
+    /// buffer by `f` are carried to the front of the next chunk.
+    ///
+    /// `f` removes the elements it processed, e.g. with [`Vec::drain`], and
+    /// leaves the rest, such as a partial record. Returns the elements still
+    /// carried when the underlying iterator ends.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// // Records are terminated by 0.
+    /// let data = [1, 2, 0, 3, 0, 4, 5, 6, 0, 7];
+    /// let mut records = vec![];
+    /// let rest = data.into_iter().chunks(3).for_each_with_carry(|buf| {
+    ///     while let Some(end) = buf.iter().position(|&v| v == 0) {
+    ///         records.push(buf.drain(..=end).collect::<Vec<_>>());
+    ///     }
+    /// });
+    /// assert_eq!(records, [vec![1, 2, 0], vec![3, 0], vec![4, 5, 6, 0]]);
+    /// assert_eq!(rest, [7]);
+    /// ```
+    pub fn for_each_with_carry(&mut self, mut f: impl FnMut(&mut Vec<I::Item>)) -> Vec<I::Item> {
+        let mut buf = Vec::with_capacity(self.n);
+        while let Some(chunk) = self.next() {
+            buf.extend(chunk);
+            f(&mut buf);
+        }
+        buf
+    }
+}
+
+impl<I: Iterator> Chunks<I> {
+    /// Alternates chunks from `self` and `other`: the first chunk of `self`,
+    /// the first chunk of `other`, the second chunk of `self`, and so on.
+    ///
+    /// Each stream keeps its own chunk size and boundaries. When one stream is
+    /// exhausted, the remaining chunks of the other are yielded in order, and
+    /// the adaptor ends when both are exhausted.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let a = [1, 2, 3].into_iter().chunks(2);
+    /// let b = [4, 5, 6].into_iter().chunks(1);
+    /// let mut res = vec![];
+    /// a.interleave_chunks(b)
+    ///     .for_each(|chunk| res.push(chunk.collect::<Vec<_>>()));
+    /// assert_eq!(res, vec![vec![1, 2], vec![4], vec![3], vec![5], vec![6]]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn interleave_chunks<J>(self, other: Chunks<J>) -> InterleaveChunks<I, J>
+    where
+        J: Iterator<Item = I::Item>,
+    {
+        InterleaveChunks::new(self, other)
+    }
+
+    /// Yields the chunks of `self` and `other` in lockstep, e.g. to diff two
+    /// exports batch by batch.
+    ///
+    /// Each stream keeps its own chunk size and boundaries, and the item
+    /// types may differ. Unlike zipping, the adaptor goes on when one stream
+    /// is exhausted, and yields the remaining chunks of the other alone, so
+    /// the unmatched tail is never dropped.
+    ///
+    /// ```
+    /// use iter_chunks::{EitherOrBothChunk, IterChunks};
+    ///
+    /// let old = [1, 2, 3].into_iter().chunks(2);
+    /// let new = [1, 2, 4, 5, 6].into_iter().chunks(2);
+    /// let mut res = vec![];
+    /// old.zip_longest_chunks(new).for_each(|pair| match pair {
+    ///     EitherOrBothChunk::Both(a, b) => {
+    ///         res.push(a.collect::<Vec<_>>() == b.collect::<Vec<_>>())
+    ///     }
+    ///     EitherOrBothChunk::Left(_) | EitherOrBothChunk::Right(_) => res.push(false),
+    /// });
+    /// assert_eq!(res, vec![true, false, false]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn zip_longest_chunks<J: Iterator>(self, other: Chunks<J>) -> ZipLongestChunks<I, J> {
+        ZipLongestChunks::new(self, other)
+    }
+
+    /// Yields all chunks of `self`, then all chunks of `other`.
+    ///
+    /// Unlike chunking the chained elements, each stream keeps its own chunk
+    /// size and boundaries, so the last chunk of `self` may be short.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let a = [1, 2, 3].into_iter().chunks(2);
+    /// let b = vec![4, 5].into_iter().chunks(2);
+    /// let mut res = vec![];
+    /// a.chain(b).for_each(|chunk| res.push(chunk.collect::<Vec<_>>()));
+    /// assert_eq!(res, vec![vec![1, 2], vec![3], vec![4, 5]]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn chain<J>(self, other: Chunks<J>) -> ChainChunks<I, J>
+    where
+        J: Iterator<Item = I::Item>,
+    {
+        ChainChunks::new(self, other)
+    }
+
+    /// Skips chunks while `predicate` returns `true`, then yields the rest,
+    /// starting from the first chunk for which `predicate` returns `false`.
+    ///
+    /// Every chunk is buffered to evaluate `predicate`, and the first failing
+    /// chunk is yielded as is, so no element is lost at the boundary. After
+    /// that, `predicate` is not called anymore.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let arr = [0, 0, 0, 0, 1, 0, 2];
+    /// let mut res = vec![];
+    /// arr.into_iter()
+    ///     .chunks(2)
+    ///     .skip_while_chunks(|chunk| chunk.iter().all(|&v| v == 0))
+    ///     .for_each(|chunk| res.push(chunk.collect::<Vec<_>>()));
+    /// assert_eq!(res, vec![vec![1, 0], vec![2]]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn skip_while_chunks<P>(self, predicate: P) -> SkipWhileChunks<I, P>
+    where
+        P: FnMut(&[I::Item]) -> bool,
+    {
+        SkipWhileChunks::new(self, predicate)
+    }
+
+    /// Yields chunks while `predicate` returns `true`, and stops at the first
+    /// chunk for which `predicate` returns `false`.
+    ///
+    /// Every chunk is buffered to evaluate `predicate`. The failing chunk is
+    /// not yielded but kept buffered, so the [`Chunks`] returned by
+    /// [`TakeWhileChunks::into_inner`] starts exactly at that chunk.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let pages = [1, 2, 3, 0, 0, 4];
+    /// let mut res = vec![];
+    /// let mut chunks = pages
+    ///     .into_iter()
+    ///     .chunks(2)
+    ///     .take_while_chunks(|chunk| !chunk.contains(&0));
+    /// chunks.for_each(|chunk| res.push(chunk.collect::<Vec<_>>()));
+    /// assert_eq!(res, vec![vec![1, 2]]);
+    ///
+    /// let mut rest = chunks.into_inner();
+    /// assert_eq!(rest.next().unwrap().collect::<Vec<_>>(), vec![3, 0]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn take_while_chunks<P>(self, predicate: P) -> TakeWhileChunks<I, P>
+    where
+        P: FnMut(&[I::Item]) -> bool,
+    {
+        TakeWhileChunks::new(self, predicate)
+    }
+
+    /// Yields the first chunk, then every `step`-th chunk after it, like
+    /// [`Iterator::step_by`].
+    ///
+    /// The chunks in between are skipped with [`Chunks::skip_chunks`], so
+    /// stepping over a range or a slice doesn't pull the skipped elements one
+    /// by one. The rest of a yielded chunk that was not consumed is skipped
+    /// with them.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is 0.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut res = vec![];
+    /// (0..10)
+    ///     .chunks(2)
+    ///     .step_by_chunks(2)
+    ///     .for_each(|chunk| res.push(chunk.collect::<Vec<_>>()));
+    /// assert_eq!(res, vec![vec![0, 1], vec![4, 5], vec![8, 9]]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn step_by_chunks(self, step: usize) -> StepByChunks<I> {
+        StepByChunks::new(self, step)
+    }
+
+    /// Skips every chunk equal to the previous yielded one, e.g. the
+    /// repeated batches of a periodic snapshot stream.
+    ///
+    /// Every chunk is buffered to be compared, and yielded as a slice. Only
+    /// consecutive chunks are compared, and [`DedupChunks::skipped`] counts
+    /// the skipped chunks.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let snapshots = [1, 2, 1, 2, 1, 2, 3, 4];
+    /// let mut chunks = snapshots.into_iter().chunks(2).dedup_chunks();
+    /// assert_eq!(chunks.next(), Some(&[1, 2][..]));
+    /// assert_eq!(chunks.next(), Some(&[3, 4][..]));
+    /// assert_eq!(chunks.next(), None);
+    /// assert_eq!(chunks.skipped(), 2);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn dedup_chunks(self) -> DedupChunks<I>
+    where
+        I::Item: PartialEq,
+    {
+        DedupChunks::new(self)
+    }
+
+    /// Groups every `m` consecutive chunks into a super-chunk, which lends
+    /// the chunks one by one like [`Chunks`].
+    ///
+    /// This is two-level batching without flattening and re-chunking, e.g.
+    /// `n` rows per insert and `m` inserts per transaction.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `m` is 0.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut txs = vec![];
+    /// (0..7).chunks(2).super_chunks(2).for_each(|mut tx| {
+    ///     let mut inserts = vec![];
+    ///     tx.for_each(|rows| inserts.push(rows.collect::<Vec<_>>()));
+    ///     txs.push(inserts);
+    /// });
+    /// assert_eq!(
+    ///     txs,
+    ///     vec![vec![vec![0, 1], vec![2, 3]], vec![vec![4, 5], vec![6]]]
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn super_chunks(self, m: usize) -> SuperChunks<I> {
+        SuperChunks::new(self, m)
+    }
+
+    /// Yields chunks no faster than one per `interval`, by sleeping before a
+    /// chunk that would be yielded too early.
+    ///
+    /// Use [`ThrottledChunks::busy_wait`] to spin instead of sleeping.
+    ///
+    /// ```
+    /// use std::time::{Duration, Instant};
+    ///
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let start = Instant::now();
+    /// let mut requests = 0;
+    /// (0..6)
+    ///     .chunks(2)
+    ///     .throttle(Duration::from_millis(10))
+    ///     .for_each(|batch| {
+    ///         batch.for_each(drop);
+    ///         requests += 1;
+    ///     });
+    /// assert_eq!(requests, 3);
+    /// assert!(start.elapsed() >= Duration::from_millis(20));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn throttle(self, interval: Duration) -> ThrottledChunks<I> {
+        ThrottledChunks::new(self, interval)
+    }
+}
+
+impl<I: Iterator + Clone> Chunks<I> {
+    /// Restarts the underlying iterator from a clone after it ends, and keeps
+    /// yielding chunks indefinitely.
+    ///
+    /// Every cycle is chunked from its start, so the chunk boundaries are the
+    /// same in every cycle and the last chunk of a cycle may be short. This
+    /// suits iterating a dataset in batches for multiple epochs.
+    ///
+    /// The source of every restart is a clone of the underlying iterator
+    /// taken when this is called. Elements already pulled ahead, e.g. by
+    /// [`Chunks::put_back`], are only yielded in the first cycle.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut batches = [1, 2, 3].into_iter().chunks(2).cycle_chunks();
+    /// let mut res = vec![];
+    /// while batches.cycle() < 2 {
+    ///     let batch = batches.next().unwrap().collect::<Vec<_>>();
+    ///     res.push((batches.cycle(), batch));
+    /// }
+    /// assert_eq!(
+    ///     res,
+    ///     vec![(0, vec![1, 2]), (0, vec![3]), (1, vec![1, 2]), (1, vec![3]), (2, vec![1, 2])]
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn cycle_chunks(self) -> CycleChunks<I> {
+        CycleChunks::new(self)
+    }
+
+    /// Splits the chunks into two independent halves at a chunk boundary.
+    ///
+    /// The first half yields chunks `[0, k)` and the second half yields chunks
+    /// `[k, ...)`. Both halves keep the same chunk size, so they can be moved to
+    /// different threads and processed separately.
+    ///
+    /// The underlying iterator is cloned once, and the second half skips the
+    /// elements of the first `k` chunks of the clone lazily.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let (mut left, mut right) = (0..7).chunks(2).split_at_chunk(2);
+    ///
+    /// assert_eq!(left.next().unwrap().collect::<Vec<_>>(), vec![0, 1]);
+    /// assert_eq!(left.next().unwrap().collect::<Vec<_>>(), vec![2, 3]);
+    /// assert!(left.next().is_none());
+    ///
+    /// assert_eq!(right.next().unwrap().collect::<Vec<_>>(), vec![4, 5]);
+    /// assert_eq!(right.next().unwrap().collect::<Vec<_>>(), vec![6]);
+    /// assert!(right.next().is_none());
+    /// ```
+    pub fn split_at_chunk(self, k: usize) -> (Chunks<Take<I>>, Chunks<Skip<I>>) {
+        let mid = self.chunks_len(k);
+        let consumed = self.consumed();
+        // Both halves count from `consumed`, so the injected chunks keep their
+        // offsets from it.
+        let front = self.front();
+        let put_back = self.put_back_mark.wrapping_sub(front).min(mid);
+        let mut left_prepended = Vec::new();
+        let mut right_prepended = Vec::new();
+        for &(start, len) in &self.prepended {
+            let offset = start.wrapping_sub(front) as isize;
+            if offset < 0 {
+                continue;
+            }
+            let entry = (consumed.wrapping_add(offset as usize), len);
+            if (offset as usize) < mid {
+                left_prepended.push(entry);
+            } else {
+                right_prepended.push(entry);
+            }
+        }
+        let mut left_buffered = self.buffered;
+        let right_buffered = if left_buffered.len() > mid {
+            left_buffered.split_off(mid)
+        } else {
+            VecDeque::new()
+        };
+        let rest = mid - left_buffered.len();
+        let left = Chunks {
+            inner: self.inner.clone().take(rest),
+            n: self.n,
+            end_flag: self.end_flag,
+            resumable: self.resumable,
+            exhausted: self.exhausted,
+            pulled: consumed + left_buffered.len(),
+            buffered: left_buffered,
+            prepended: left_prepended,
+            chunk_end: self.chunk_end,
+            put_back_mark: consumed.wrapping_add(put_back),
+            #[cfg(feature = "tracing")]
+            chunk_index: self.chunk_index,
+            #[cfg(feature = "tracing")]
+            span: None,
+        };
+        let right = Chunks {
+            inner: self.inner.skip(rest),
+            n: self.n,
+            end_flag: false,
+            resumable: self.resumable,
+            exhausted: self.exhausted,
+            pulled: consumed.saturating_add(mid) + right_buffered.len(),
+            buffered: right_buffered,
+            prepended: right_prepended,
+            chunk_end: 0,
+            put_back_mark: 0,
+            #[cfg(feature = "tracing")]
+            chunk_index: self.chunk_index.saturating_add(k),
+            #[cfg(feature = "tracing")]
+            span: None,
+        };
+        (left, right)
+    }
+}
+
+/// An iterator over a chunk of data.
+///
+/// Unlike [`Chunks`], `Chuuk` implements `Iterator` and can be used in for
+/// loop.
+///
+/// This `struct` is created by [`Chunks::next`].
+pub struct Chunk<'a, I: Iterator> {
+    first: Option<I::Item>,
+    parent: &'a mut Chunks<I>,
+    n: usize,
+    // The number of elements yielded by this chunk.
+    position: usize,
+}
+
+impl<'a, I: Iterator> Chunk<'a, I> {
+    /// Returns the configured chunk size of the parent [`Chunks`].
+    ///
+    /// This is the maximum number of elements the chunk may yield, not the
+    /// number of remaining elements, unless the chunk is sized differently by
+    /// [`Chunks::merge_next`] or [`Chunks::next_n`].
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut chunks = [1, 2, 3].into_iter().chunks(2);
+    /// let mut chunk = chunks.next().unwrap();
+    /// chunk.next();
+    /// assert_eq!(chunk.chunk_size(), 2);
+    /// ```
+    pub fn chunk_size(&self) -> usize {
+        self.parent.n
+    }
+
+    /// Returns the number of elements already yielded by this chunk, i.e. the
+    /// index within the chunk of the next element.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut chunks = "frame".bytes().chunks(4);
+    /// let mut chunk = chunks.next().unwrap();
+    /// assert_eq!(chunk.position(), 0);
+    /// chunk.next();
+    /// chunk.next();
+    /// assert_eq!(chunk.position(), 2);
+    /// ```
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Drains the rest of the chunk into `collection`, and returns the number
+    /// of elements moved.
+    ///
+    /// Unlike [`Iterator::collect`], this appends to an existing collection
+    /// that outlives the chunk.
+    ///
+    /// ```
+    /// use std::collections::HashSet;
+    ///
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut chunks = [1, 2, 2, 3].into_iter().chunks(3);
+    /// let mut set = HashSet::new();
+    /// assert_eq!(chunks.next().unwrap().extend_into(&mut set), 3);
+    /// assert_eq!(set.len(), 2);
+    /// ```
+    pub fn extend_into<E: Extend<I::Item>>(&mut self, collection: &mut E) -> usize {
+        let mut count = 0;
+        collection.extend(self.inspect(|_| count += 1));
+        count
+    }
+
+    /// Drains the chunk into the front of `buf`, and returns the number of
+    /// elements written.
+    ///
+    /// If `buf` is shorter than the rest of the chunk, it's filled and the
+    /// remaining elements are left in the chunk.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut chunks = (1..=5).chunks(4);
+    /// let mut buf = [0; 8];
+    /// let len = chunks.next().unwrap().copy_to_slice(&mut buf);
+    /// assert_eq!(&buf[..len], [1, 2, 3, 4]);
+    /// ```
+    pub fn copy_to_slice(&mut self, buf: &mut [I::Item]) -> usize {
+        let mut count = 0;
+        for (slot, v) in buf.iter_mut().zip(self) {
+            *slot = v;
+            count += 1;
+        }
+        count
+    }
+
+    /// Skips the rest of the chunk, and returns the number of elements
+    /// skipped.
+    ///
+    /// Like [`Chunks::skip_chunks`], the underlying iterator is advanced in
+    /// bulk where possible. [`Iterator::count`] and [`Iterator::nth`] skip the
+    /// same way.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut chunks = (0..1u64 << 40).chunks(1 << 30);
+    /// let mut chunk = chunks.next().unwrap();
+    /// assert_eq!(chunk.next(), Some(0));
+    /// assert_eq!(chunk.skip_rest(), (1 << 30) - 1);
+    /// assert_eq!(chunks.next().unwrap().next(), Some(1 << 30));
+    /// ```
+    pub fn skip_rest(mut self) -> usize {
+        self.advance(usize::MAX)
+    }
+
+    /// Skips up to `k` elements, and returns the number skipped.
+    fn advance(&mut self, k: usize) -> usize {
+        if k == 0 {
+            return 0;
+        }
+        let mut skipped = self.first.take().is_some() as usize;
+        let rest = (k - skipped).min(self.n);
+        let ended = self.parent.end_flag;
+        let pulled = self.parent.skip_elements(rest);
+        self.n -= pulled;
+        if pulled < rest {
+            if ended {
+                // Like `next_element`, the end was already reported.
+                self.n = 0;
+            } else {
+                self.end();
+            }
         }
+        skipped += pulled;
+        self.position += skipped;
+        skipped
     }
-}
 
-/// An iterator over a chunk of data.
-///
-/// Unlike [`Chunks`], `Chuuk` implements `Iterator` and can be used in for
-/// loop.
-///
-/// This `struct` is created by [`Chunks::next`].
-pub struct Chunk<'a, I: Iterator> {
-    first: Option<I::Item>,
-    parent: &'a mut Chunks<I>,
-    n: usize,
+    fn next_element(&mut self) -> Option<I::Item> {
+        match self.first.take() {
+            Some(v) => Some(v),
+            None if self.n > 0 => {
+                self.n -= 1;
+                // `Chunks::next_in_chunk`, inlined by hand: the call costs
+                // about half the throughput over a range.
+                if let Some(v) = self.parent.buffered.pop_front() {
+                    return Some(v);
+                }
+                if self.parent.end_flag {
+                    // The inner iterator already ended while the elements were
+                    // buffered.
+                    self.n = 0;
+                    return None;
+                }
+                let v = self.parent.pull_inner();
+                if v.is_none() {
+                    self.end();
+                }
+                v
+            }
+            None => None,
+        }
+    }
+
+    /// Ends the chunk after the inner iterator returned `None`.
+    fn end(&mut self) {
+        // The current chunk iterator should output None and end forever.
+        self.n = 0;
+        self.parent.end_chunk();
+    }
 }
 
 impl<'a, I> Iterator for Chunk<'a, I>
@@ -114,29 +2663,72 @@ where
     type Item = <I as Iterator>::Item;
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.first.take() {
-            Some(v) => Some(v),
-            None if self.n > 0 => {
-                self.n -= 1;
-                match self.parent.inner.next() {
-                    Some(v) => Some(v),
-                    None => {
-                        // The current chunk iterator should output None and end forever.
-                        self.n = 0;
+        let v = self.next_element();
+        self.position += v.is_some() as usize;
+        v
+    }
 
-                        // The parent chunks iterator should output None once.
-                        self.parent.end_flag = true;
+    fn nth(&mut self, k: usize) -> Option<Self::Item> {
+        if self.advance(k) < k {
+            return None;
+        }
+        self.next()
+    }
 
-                        None
-                    }
-                }
-            }
-            None => None,
+    fn count(self) -> usize {
+        self.skip_rest()
+    }
+
+    fn fold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        // Hand out the first element and the buffered ones up front, so that
+        // the rest is a tight loop over the inner iterator without the checks
+        // of `next`.
+        let mut acc = init;
+        if let Some(v) = self.first.take() {
+            acc = f(acc, v);
+        }
+        while self.n > 0 {
+            let Some(v) = self.parent.buffered.pop_front() else {
+                break;
+            };
+            self.n -= 1;
+            acc = f(acc, v);
+        }
+        if self.n == 0 || self.parent.end_flag {
+            return acc;
+        }
+        if self.parent.exhausted {
+            self.end();
+            return acc;
+        }
+        let n = self.n;
+        let mut taken = 0;
+        let acc = self.parent.inner.by_ref().take(n).fold(acc, |acc, v| {
+            taken += 1;
+            f(acc, v)
+        });
+        self.parent.pulled += taken;
+        if taken < n {
+            self.parent.inner_ended();
+            self.end();
         }
+        acc
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
         let (lower, upper) = self.parent.inner.size_hint();
+        let buffered = self.parent.buffered.len();
+        let (lower, upper) = if self.parent.end_flag || self.parent.exhausted {
+            (buffered, Some(buffered))
+        } else {
+            (
+                lower.saturating_add(buffered),
+                upper.and_then(|v| v.checked_add(buffered)),
+            )
+        };
         let has_first = self.first.is_some() as usize;
         let n = self.n;
         // SAFETY: `checked_add` is unnecessary here since n is always less than
@@ -162,6 +2754,21 @@ mod tests {
 
         assert_sync(&chunks);
         assert_send(&chunks);
+
+        #[cfg(feature = "std")]
+        {
+            use crate::OversizedPolicy;
+
+            for policy in [
+                OversizedPolicy::OwnChunk,
+                OversizedPolicy::Error,
+                OversizedPolicy::split(|v| vec![v]),
+            ] {
+                let chunks = [0i32].into_iter().chunks_by_weight(1, |_| 1, policy);
+                assert_sync(&chunks);
+                assert_send(&chunks);
+            }
+        }
     }
 
     #[test]
@@ -220,6 +2827,42 @@ mod tests {
         assert!(chunks.next().is_none());
     }
 
+    #[test]
+    fn test_non_resumable() {
+        let inner_gen = |rem| {
+            let mut i = 0;
+            let polled = std::rc::Rc::new(std::cell::Cell::new(0));
+            let counter = polled.clone();
+            let iter = std::iter::from_fn(move || {
+                counter.set(counter.get() + 1);
+                i += 1;
+                if i % rem == 0 {
+                    None
+                } else {
+                    Some(i)
+                }
+            });
+            (iter, polled)
+        };
+
+        // The inner iterator ends in the middle of a chunk.
+        let (inner, polled) = inner_gen(3);
+        let mut chunks = inner.chunks(4).non_resumable();
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![1, 2]);
+        assert!(chunks.next().is_none());
+        assert!(chunks.next().is_none());
+        assert_eq!(polled.get(), 3);
+
+        // The inner iterator ends at a chunk boundary.
+        let (inner, polled) = inner_gen(5);
+        let mut chunks = inner.chunks(2).non_resumable();
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![3, 4]);
+        assert!(chunks.next().is_none());
+        assert!(chunks.next().is_none());
+        assert_eq!(polled.get(), 5);
+    }
+
     #[test]
     fn test_chunks_count() {
         let arr: [bool; 0] = [];
@@ -243,6 +2886,549 @@ mod tests {
         assert_eq!(i, 1);
     }
 
+    #[test]
+    fn test_chunk_size() {
+        let mut chunks = [1, 2, 3].into_iter().chunks(2);
+        assert_eq!(chunks.chunk_size(), 2);
+
+        while let Some(chunk) = chunks.next() {
+            assert_eq!(chunk.chunk_size(), 2);
+        }
+    }
+
+    #[test]
+    fn test_elements_consumed() {
+        let mut chunks = (0..10).chunks(4);
+        assert_eq!(chunks.elements_consumed(), 0);
+        while let Some(chunk) = chunks.next() {
+            chunk.for_each(drop);
+        }
+        assert_eq!(chunks.elements_consumed(), 10);
+
+        let mut chunks = (0..10).chunks(4);
+        let mut buf = Vec::new();
+        assert!(chunks.next_buffered(&mut buf));
+        let (mut left, right) = chunks.split_at_chunk(1);
+        assert_eq!(left.elements_consumed(), 4);
+        assert_eq!(right.elements_consumed(), 8);
+        assert!(left.next_buffered(&mut buf));
+        assert_eq!(left.elements_consumed(), 8);
+    }
+
+    #[test]
+    fn test_put_back() {
+        let mut chunks = (0..6).chunks(3);
+        let over_read = chunks.next().unwrap().take(2).collect::<Vec<_>>();
+        chunks.put_back_many(over_read).unwrap();
+        assert_eq!(chunks.elements_consumed(), 0);
+        assert_eq!(chunks.put_back(-1), Ok(()));
+        assert_eq!(chunks.put_back(-2), Err(-2));
+        assert_eq!(chunks.put_back_many([-3, -4]), Err(vec![-3, -4]));
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![-1, 0, 1]);
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![2, 3, 4]);
+
+        // The inner iterator already ended in the current chunk.
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![5]);
+        chunks.put_back(5).unwrap();
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![5]);
+        assert!(chunks.next().is_none());
+    }
+
+    #[test]
+    fn test_peek_len_put_back() {
+        // The elements pulled ahead don't count against the cap.
+        let mut chunks = (0..10).chunks(2);
+        let mut chunk = chunks.next().unwrap();
+        let (a, b) = (chunk.next().unwrap(), chunk.next().unwrap());
+        assert_eq!(chunks.peek_len(), 2);
+        assert_eq!(chunks.put_back(b), Ok(()));
+        assert_eq!(chunks.put_back(a), Ok(()));
+        assert_eq!(chunks.put_back(-1), Err(-1));
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(chunks.peek_len(), 2);
+        assert_eq!(chunks.put_back_many([0, 1]), Ok(()));
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![2, 3]);
+        assert_eq!(chunks.elements_consumed(), 4);
+    }
+
+    #[test]
+    fn test_peek_len() {
+        let mut chunks = (0..5).chunks(3);
+        assert_eq!(chunks.peek_len(), 3);
+        assert_eq!(chunks.peek_len(), 3);
+        assert_eq!(chunks.elements_consumed(), 0);
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![0, 1, 2]);
+        assert_eq!(chunks.peek_len(), 2);
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![3, 4]);
+        assert_eq!(chunks.peek_len(), 0);
+        assert!(chunks.next().is_none());
+
+        // A resumable iterator is polled again after the `None`.
+        let mut i = 0;
+        let resumable = std::iter::from_fn(move || {
+            i += 1;
+            (i != 3).then_some(i)
+        });
+        let mut chunks = resumable.chunks(4);
+        assert_eq!(chunks.peek_len(), 2);
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![1, 2]);
+        assert!(chunks.next().is_none());
+        assert_eq!(chunks.peek_len(), 4);
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn test_align_to_next_boundary() {
+        let mut chunks = (0..10).chunks(4);
+        assert_eq!(chunks.align_to_next_boundary(), 0);
+        let mut chunk = chunks.next().unwrap();
+        assert_eq!(chunk.next(), Some(0));
+        assert_eq!(chunks.align_to_next_boundary(), 3);
+        assert_eq!(chunks.align_to_next_boundary(), 0);
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![4, 5, 6, 7]);
+        assert_eq!(chunks.align_to_next_boundary(), 0);
+
+        // Elements pulled ahead are skipped too, and the skip may hit the end.
+        let mut chunk = chunks.next_n(2).unwrap();
+        assert_eq!(chunk.next(), Some(8));
+        assert_eq!(chunks.peek_len(), 1);
+        assert_eq!(chunks.align_to_next_boundary(), 1);
+        assert!(chunks.next().is_none());
+        assert_eq!(chunks.elements_consumed(), 10);
+
+        // A chunk that ended short has nothing to skip.
+        let mut chunks = (0..2).chunks(4);
+        assert_eq!(chunks.next().unwrap().count(), 2);
+        assert_eq!(chunks.align_to_next_boundary(), 0);
+        assert!(chunks.next().is_none());
+        assert_eq!(chunks.align_to_next_boundary(), 0);
+    }
+
+    #[test]
+    fn test_prepend_chunk() {
+        let mut chunks = (0..5).chunks(2);
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(chunks.peek_len(), 2);
+        chunks.prepend_chunk(vec![-1]);
+        chunks.prepend_chunk(vec![]);
+        chunks.prepend_chunk(vec![-4, -3, -2]);
+        assert_eq!(chunks.peek_len(), 3);
+        assert_eq!(chunks.elements_consumed(), 2);
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![-4, -3, -2]);
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![-1]);
+        assert_eq!(chunks.elements_consumed(), 6);
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![2, 3]);
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![4]);
+        assert!(chunks.next().is_none());
+
+        // A chunk injected after the end is still yielded.
+        chunks.prepend_chunk(vec![9]);
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![9]);
+        assert!(chunks.next().is_none());
+    }
+
+    fn collect_chunks<I: Iterator>(chunks: &mut super::Chunks<I>) -> Vec<Vec<I::Item>> {
+        let mut res = vec![];
+        chunks.for_each(|chunk| res.push(chunk.collect()));
+        res
+    }
+
+    #[test]
+    fn test_prepend_chunk_skip() {
+        let mut chunks = (0..10).chunks(2);
+        chunks.prepend_chunk(vec![100, 101, 102]);
+        assert_eq!(chunks.skip_chunks(1), 3);
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![0, 1]);
+
+        // The rest of a partially consumed chunk is cut at the next injected
+        // one.
+        let mut chunks = (0..10).chunks(3);
+        chunks.prepend_chunk(vec![100, 101]);
+        chunks.prepend_chunk(vec![98, 99]);
+        chunks.next().unwrap().next();
+        assert_eq!(chunks.skip_chunks(1), 1);
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![100, 101]);
+
+        let mut chunks = (0..6).chunks(4);
+        chunks.prepend_chunk(vec![100]);
+        chunks.prepend_chunk(vec![99]);
+        assert_eq!(chunks.skip_chunks(2), 2);
+        assert_eq!(
+            collect_chunks(&mut chunks),
+            vec![vec![0, 1, 2, 3], vec![4, 5]]
+        );
+
+        // Skipping the rest of a chunk doesn't touch the injected one after it.
+        let mut chunks = (0..6).chunks(3);
+        chunks.prepend_chunk(vec![100, 101]);
+        chunks.prepend_chunk(vec![98, 99]);
+        chunks.next().unwrap().skip_rest();
+        assert_eq!(
+            collect_chunks(&mut chunks),
+            vec![vec![100, 101], vec![0, 1, 2], vec![3, 4, 5]]
+        );
+    }
+
+    #[test]
+    fn test_prepend_chunk_put_back() {
+        let mut chunks = (0..6).chunks(3);
+        chunks.prepend_chunk(vec![100]);
+        chunks.put_back(99).unwrap();
+        assert_eq!(chunks.peek_len(), 2);
+        assert_eq!(
+            collect_chunks(&mut chunks),
+            vec![vec![99, 100], vec![0, 1, 2], vec![3, 4, 5]]
+        );
+
+        let mut chunks = (0..6).chunks(3);
+        chunks.prepend_chunk(vec![100]);
+        chunks.put_back_many([98, 99]).unwrap();
+        assert_eq!(
+            collect_chunks(&mut chunks),
+            vec![vec![98, 99, 100], vec![0, 1, 2], vec![3, 4, 5]]
+        );
+    }
+
+    #[test]
+    fn test_prepend_chunk_for_each_buffered() {
+        let mut chunks = (0..5).chunks(2);
+        chunks.prepend_chunk(vec![10, 11, 12]);
+        let mut res = vec![];
+        chunks.for_each_buffered(|chunk| res.push(chunk.to_vec()));
+        assert_eq!(res, vec![vec![10, 11, 12], vec![0, 1], vec![2, 3], vec![4]]);
+    }
+
+    #[test]
+    fn test_prepend_chunk_split_at_chunk() {
+        let mut chunks = (0..6).chunks(2);
+        chunks.prepend_chunk(vec![100, 101, 102]);
+        chunks.prepend_chunk(vec![99]);
+        let (mut left, mut right) = chunks.split_at_chunk(1);
+        assert_eq!(collect_chunks(&mut left), vec![vec![99]]);
+        assert_eq!(
+            collect_chunks(&mut right),
+            vec![vec![100, 101, 102], vec![0, 1], vec![2, 3], vec![4, 5]]
+        );
+    }
+
+    #[test]
+    fn test_resume_with() {
+        // A short chunk yielded before the swap isn't merged.
+        let mut chunks = (0..3).chunks(2).non_resumable();
+        chunks.next().unwrap().for_each(drop);
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![2]);
+        assert!(chunks.next().is_none());
+        let mut chunks = chunks.resume_with([3, 4, 5].into_iter());
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![3, 4]);
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![5]);
+        assert!(chunks.next().is_none());
+        assert_eq!(chunks.elements_consumed(), 6);
+
+        // The elements put back carry over.
+        let mut chunks = (0..2).chunks(3);
+        chunks.put_back(-1).unwrap();
+        let mut chunks = chunks.resume_with(std::iter::empty());
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![-1]);
+        assert!(chunks.next().is_none());
+    }
+
+    #[test]
+    fn test_merge_next() {
+        let mut chunks = (0..7).chunks(2);
+        let mut merged = chunks.merge_next(2).unwrap();
+        assert_eq!(merged.size_hint(), (4, Some(4)));
+        assert_eq!(merged.by_ref().take(3).count(), 3);
+        // The next chunk starts from the first element not yielded.
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![3, 4]);
+        assert_eq!(
+            chunks.merge_next(5).unwrap().collect::<Vec<_>>(),
+            vec![5, 6]
+        );
+        assert!(chunks.merge_next(5).is_none());
+        assert_eq!(chunks.elements_consumed(), 7);
+    }
+
+    #[test]
+    fn test_next_n() {
+        let mut chunks = (0..10).chunks(3);
+        assert_eq!(chunks.next_n(1).unwrap().collect::<Vec<_>>(), vec![0]);
+        let mut chunk = chunks.next_n(5).unwrap();
+        assert_eq!(chunk.size_hint(), (5, Some(5)));
+        assert_eq!(chunk.chunk_size(), 3);
+        assert_eq!(chunk.by_ref().count(), 5);
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![6, 7, 8]);
+        assert_eq!(chunks.next_n(4).unwrap().collect::<Vec<_>>(), vec![9]);
+        assert!(chunks.next_n(4).is_none());
+    }
+
+    #[test]
+    fn test_chunk_position() {
+        let mut chunks = (0..5).chunks(3);
+        let mut chunk = chunks.next().unwrap();
+        assert_eq!(chunk.position(), 0);
+        assert_eq!(chunk.by_ref().count(), 3);
+        assert_eq!(chunk.position(), 3);
+        chunk.next();
+        assert_eq!(chunk.position(), 3);
+
+        let mut chunk = chunks.next().unwrap();
+        assert_eq!(chunk.by_ref().count(), 2);
+        assert_eq!(chunk.position(), 2);
+    }
+
+    #[test]
+    fn test_split_at_chunk() {
+        let (left, right) = (0..10).chunks(3).split_at_chunk(2);
+        let handle = std::thread::spawn(move || {
+            let mut right = right;
+            let mut sums = vec![];
+            right.for_each(|chunk| sums.push(chunk.sum::<i32>()));
+            sums
+        });
+
+        let mut left = left;
+        let mut sums = vec![];
+        left.for_each(|chunk| sums.push(chunk.sum::<i32>()));
+        assert_eq!(sums, vec![3, 12]);
+        assert_eq!(handle.join().unwrap(), vec![21, 9]);
+
+        let (mut left, mut right) = (0..4).chunks(2).split_at_chunk(5);
+        assert_eq!(left.next().unwrap().collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(left.next().unwrap().collect::<Vec<_>>(), vec![2, 3]);
+        assert!(left.next().is_none());
+        assert!(right.next().is_none());
+    }
+
+    #[test]
+    fn test_next_buffered() {
+        let mut chunks = (0..7).chunks(3);
+        let mut buf = Vec::new();
+        let mut res = vec![];
+        while chunks.next_buffered(&mut buf) {
+            res.push(buf.clone());
+        }
+        assert_eq!(res, vec![vec![0, 1, 2], vec![3, 4, 5], vec![6]]);
+        assert!(buf.is_empty());
+
+        let mut chunks = (0..7).chunks(3);
+        let mut res = vec![];
+        chunks.for_each_buffered(|chunk| {
+            chunk.reverse();
+            res.push(chunk.to_vec());
+        });
+        assert_eq!(res, vec![vec![2, 1, 0], vec![5, 4, 3], vec![6]]);
+
+        // The buffered elements come first, and a resumable iterator is
+        // drained up to its first `None`, like with `next_buffered`.
+        let mut i = 0;
+        let resumable = std::iter::from_fn(move || {
+            i += 1;
+            (i != 6).then_some(i)
+        });
+        let mut chunks = resumable.chunks(2);
+        chunks.put_back(0).unwrap();
+        let mut res = vec![];
+        chunks.for_each_buffered(|chunk| res.push(chunk.to_vec()));
+        assert_eq!(res, vec![vec![0, 1], vec![2, 3], vec![4, 5]]);
+        assert_eq!(chunks.elements_consumed(), 5);
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![7, 8]);
+    }
+
+    #[test]
+    fn test_for_each_buffered_like_for_each() {
+        // The same chunks as `for_each`, with or without the `tracing`
+        // feature.
+        let setups: [fn(&mut super::Chunks<std::ops::Range<i32>>); 5] = [
+            |_| {},
+            |chunks| chunks.next().unwrap().for_each(drop),
+            |chunks| {
+                chunks.next().unwrap().next();
+                chunks.peek_len();
+            },
+            |chunks| {
+                chunks.skip_chunks(3);
+                chunks.peek_len();
+                chunks.put_back(-1).unwrap();
+            },
+            |chunks| chunks.prepend_chunk(vec![-3, -2, -1]),
+        ];
+        for setup in setups {
+            let mut a = (0..8).chunks(3);
+            let mut b = (0..8).chunks(3);
+            setup(&mut a);
+            setup(&mut b);
+            let mut res = vec![];
+            a.for_each_buffered(|chunk| res.push(chunk.to_vec()));
+            assert_eq!(res, collect_chunks(&mut b));
+            assert_eq!(a.elements_consumed(), b.elements_consumed());
+            assert!(a.next().is_none());
+        }
+    }
+
+    #[test]
+    fn test_for_each_with_carry() {
+        let mut seen = vec![];
+        let rest = (0..7).chunks(3).for_each_with_carry(|buf| {
+            seen.push(buf.clone());
+            // Consume pairs only.
+            let even = buf.len() / 2 * 2;
+            buf.drain(..even);
+        });
+        assert_eq!(seen, vec![vec![0, 1, 2], vec![2, 3, 4, 5], vec![6]]);
+        assert_eq!(rest, vec![6]);
+
+        let rest = std::iter::empty::<i32>()
+            .chunks(3)
+            .for_each_with_carry(|_| unreachable!());
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn test_extend_into() {
+        let mut chunks = "hello world".chars().chunks(4);
+        let mut s = String::new();
+        while let Some(mut chunk) = chunks.next() {
+            chunk.next();
+            assert!(chunk.extend_into(&mut s) <= 3);
+        }
+        assert_eq!(s, "ell wold");
+    }
+
+    #[test]
+    fn test_copy_to_slice() {
+        let mut chunks = (0..5).chunks(3);
+        let mut buf = [-1; 2];
+        let mut chunk = chunks.next().unwrap();
+        assert_eq!(chunk.copy_to_slice(&mut buf), 2);
+        assert_eq!(buf, [0, 1]);
+        assert_eq!(chunk.copy_to_slice(&mut buf), 1);
+        assert_eq!(buf, [2, 1]);
+        assert_eq!(chunk.copy_to_slice(&mut buf), 0);
+        assert_eq!(chunks.next().unwrap().copy_to_slice(&mut []), 0);
+        assert_eq!(chunks.elements_consumed(), 4);
+    }
+
+    #[test]
+    fn test_rechunk() {
+        let batches = [vec![], vec![0, 1], vec![2, 3, 4, 5, 6], vec![7]];
+        let mut chunks = batches.into_iter().rechunk(3);
+        let mut res = vec![];
+        while let Some(chunk) = chunks.next() {
+            res.push(chunk.collect::<Vec<_>>());
+        }
+        assert_eq!(res, vec![vec![0, 1, 2], vec![3, 4, 5], vec![6, 7]]);
+
+        let mut chunks = std::iter::empty::<Vec<i32>>().rechunk(3);
+        assert!(chunks.next().is_none());
+    }
+
+    #[test]
+    fn test_for_each_async() {
+        let mut chunks = (0..5).chunks(2);
+        let mut polls = 0;
+        let mut res = vec![];
+        futures::executor::block_on(chunks.for_each_async(async |mut chunk| {
+            res.push(chunk.next().unwrap());
+            // Yield once to the executor in the middle of the chunk.
+            let mut yielded = false;
+            std::future::poll_fn(|cx| {
+                polls += 1;
+                if std::mem::replace(&mut yielded, true) {
+                    std::task::Poll::Ready(())
+                } else {
+                    cx.waker().wake_by_ref();
+                    std::task::Poll::Pending
+                }
+            })
+            .await;
+            res.extend(chunk);
+        }));
+        assert_eq!(res, [0, 1, 2, 3, 4]);
+        assert_eq!(polls, 6);
+        assert!(chunks.next().is_none());
+    }
+
+    #[test]
+    fn test_fold() {
+        let chunks = (0..10).chunks(4);
+        let res = chunks.fold(vec![], |mut acc, chunk| {
+            acc.push(chunk.count());
+            acc
+        });
+        assert_eq!(res, vec![4, 4, 2]);
+
+        let res = std::iter::empty::<i32>().chunks(4).fold(1, |_, _| 0);
+        assert_eq!(res, 1);
+    }
+
+    #[test]
+    fn test_skip() {
+        // Without a size hint, the elements are skipped one by one.
+        let mut chunks = (0..10).filter(|_| true).chunks(3);
+        chunks.put_back(-1).unwrap();
+        assert_eq!(chunks.skip_chunks(1), 3);
+        let mut chunk = chunks.next().unwrap();
+        assert_eq!(chunk.nth(1), Some(3));
+        assert_eq!(chunk.position(), 2);
+        assert_eq!(chunk.next(), Some(4));
+        assert_eq!(chunk.next(), None);
+        assert_eq!(chunks.next().unwrap().count(), 3);
+        assert_eq!(chunks.skip_chunks(2), 2);
+        assert!(chunks.next().is_none());
+
+        // The end is reported once by a chunk cut short.
+        let mut chunks = (0..5).chunks(3);
+        chunks.next().unwrap().for_each(drop);
+        assert_eq!(chunks.next().unwrap().skip_rest(), 2);
+        assert!(chunks.next().is_none());
+        let mut chunks = (0..5).chunks(3);
+        chunks.next().unwrap().for_each(drop);
+        assert_eq!(chunks.next().unwrap().nth(5), None);
+        assert!(chunks.next().is_none());
+        assert_eq!(chunks.elements_consumed(), 5);
+    }
+
+    #[test]
+    fn test_chunk_fold() {
+        // Internal iteration of a chunk matches `next`, including the
+        // buffered elements and the end of a resumable iterator.
+        let source = || {
+            let mut i = 0;
+            std::iter::from_fn(move || {
+                i += 1;
+                (i % 5 != 0).then_some(i)
+            })
+        };
+        let mut by_next = source().chunks(3);
+        let mut by_fold = source().chunks(3);
+        for chunks in [&mut by_next, &mut by_fold] {
+            chunks.put_back(0).unwrap();
+        }
+        for _ in 0..8 {
+            let a = by_next.next().map(|mut chunk| {
+                let mut res = vec![];
+                for v in chunk.by_ref() {
+                    res.push(v);
+                }
+                res
+            });
+            let b = by_fold.next().map(|chunk| {
+                chunk.fold(vec![], |mut acc, v| {
+                    acc.push(v);
+                    acc
+                })
+            });
+            assert_eq!(a, b);
+            assert_eq!(by_next.elements_consumed(), by_fold.elements_consumed());
+        }
+
+        let mut chunks = source().chunks(10).non_resumable();
+        assert_eq!(chunks.next().unwrap().sum::<i32>(), 10);
+        assert!(chunks.next().is_none());
+        assert!(chunks.next().is_none());
+    }
+
     #[test]
     fn test_size_hint() {
         let iter = [1, 2, 3, 4]
@@ -271,3 +3457,115 @@ mod tests {
         assert_eq!(chunk3.size_hint(), (0, Some(0)));
     }
 }
+
+#[cfg(all(test, feature = "tracing"))]
+mod tracing_tests {
+    use std::fmt::Debug;
+    use std::sync::{Arc, Mutex};
+
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    use super::IterChunks;
+
+    /// A subscriber that records the fields of spans and events as strings.
+    #[derive(Default, Clone)]
+    struct Recorder {
+        logs: Arc<Mutex<Vec<String>>>,
+        next_id: Arc<Mutex<u64>>,
+    }
+
+    struct Fields(String);
+
+    impl Visit for Fields {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            self.0.push_str(&format!(" {}={:?}", field.name(), value));
+        }
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            let mut fields = Fields(format!("new {}", span.metadata().name()));
+            span.record(&mut fields);
+            self.logs.lock().unwrap().push(fields.0);
+            let mut id = self.next_id.lock().unwrap();
+            *id += 1;
+            Id::from_u64(*id)
+        }
+
+        fn record(&self, _: &Id, values: &Record<'_>) {
+            let mut fields = Fields("record".to_owned());
+            values.record(&mut fields);
+            self.logs.lock().unwrap().push(fields.0);
+        }
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut fields = Fields("event".to_owned());
+            event.record(&mut fields);
+            self.logs.lock().unwrap().push(fields.0);
+        }
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    #[test]
+    fn test_tracing() {
+        let recorder = Recorder::default();
+        tracing::subscriber::with_default(recorder.clone(), || {
+            let mut chunks = (0..5).chunks(2);
+            chunks.for_each(|chunk| for _ in chunk {});
+        });
+        let logs = recorder.logs.lock().unwrap();
+        assert_eq!(
+            *logs,
+            [
+                "new chunk index=0",
+                "record len=2",
+                "new chunk index=1",
+                "record len=2",
+                "new chunk index=2",
+                "event message=inner iterator exhausted consumed=5 resumable=true",
+                "event message=short final chunk expected=2 got=1",
+                "record len=1",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tracing_for_each_buffered() {
+        // The same spans and events as `for_each`.
+        let recorder = Recorder::default();
+        tracing::subscriber::with_default(recorder.clone(), || {
+            let mut chunks = (0..5).chunks(2);
+            chunks.for_each_buffered(|_| {});
+            chunks.prepend_chunk(vec![7]);
+            chunks.for_each_buffered(|_| {});
+        });
+        let logs = recorder.logs.lock().unwrap();
+        assert_eq!(
+            *logs,
+            [
+                "new chunk index=0",
+                "record len=2",
+                "new chunk index=1",
+                "record len=2",
+                "new chunk index=2",
+                "event message=inner iterator exhausted consumed=5 resumable=true",
+                "event message=short final chunk expected=2 got=1",
+                "record len=1",
+                "new chunk index=3",
+                "record len=1",
+                "event message=inner iterator exhausted consumed=6 resumable=true",
+            ]
+        );
+    }
+}
@@ -0,0 +1,186 @@
+use std::cmp::Ordering;
+use std::mem;
+
+/// Merges sorted runs into one sorted iterator, e.g. the runs generated by
+/// [`IterChunks::sorted_chunks`] in an external merge sort.
+///
+/// Every run is pulled lazily, one element at a time, so the runs can be
+/// read back from spooled files. Equal elements are yielded in the order of
+/// the runs, so the merge of stable runs is stable.
+///
+/// ```
+/// use iter_chunks::{merge_sorted, IterChunks};
+///
+/// let runs = [5, 3, 9, 1, 4, 8, 2].into_iter().sorted_chunks(3);
+/// let merged = merge_sorted(runs.map(Vec::into_iter)).collect::<Vec<_>>();
+/// assert_eq!(merged, [1, 2, 3, 4, 5, 8, 9]);
+/// ```
+///
+/// [`IterChunks::sorted_chunks`]: crate::IterChunks::sorted_chunks
+#[allow(clippy::type_complexity)]
+pub fn merge_sorted<R>(
+    runs: R,
+) -> MergeSorted<
+    <R::Item as IntoIterator>::IntoIter,
+    fn(&<R::Item as IntoIterator>::Item, &<R::Item as IntoIterator>::Item) -> Ordering,
+>
+where
+    R: IntoIterator,
+    R::Item: IntoIterator,
+    <R::Item as IntoIterator>::Item: Ord,
+{
+    merge_sorted_by(runs, Ord::cmp)
+}
+
+/// Similar to [`merge_sorted`], but compares the elements with the `compare`
+/// function, which every run must be sorted by, e.g. the runs generated by
+/// [`IterChunks::sorted_chunks_by`].
+///
+/// ```
+/// use iter_chunks::merge_sorted_by;
+///
+/// let a = [(1, 'a'), (3, 'b')];
+/// let b = [(1, 'c'), (2, 'd')];
+/// let merged = merge_sorted_by([a, b], |x, y| x.0.cmp(&y.0)).collect::<Vec<_>>();
+/// assert_eq!(merged, [(1, 'a'), (1, 'c'), (2, 'd'), (3, 'b')]);
+/// ```
+///
+/// [`IterChunks::sorted_chunks_by`]: crate::IterChunks::sorted_chunks_by
+pub fn merge_sorted_by<R, F>(
+    runs: R,
+    compare: F,
+) -> MergeSorted<<R::Item as IntoIterator>::IntoIter, F>
+where
+    R: IntoIterator,
+    R::Item: IntoIterator,
+    F: FnMut(&<R::Item as IntoIterator>::Item, &<R::Item as IntoIterator>::Item) -> Ordering,
+{
+    MergeSorted {
+        runs: runs.into_iter().map(IntoIterator::into_iter).collect(),
+        heap: vec![],
+        started: false,
+        compare,
+    }
+}
+
+/// An iterator that merges sorted runs into one sorted iterator.
+///
+/// This `struct` is created by [`merge_sorted`] and [`merge_sorted_by`]. See
+/// their documentation for more.
+#[derive(Debug, Clone)]
+pub struct MergeSorted<I: Iterator, F> {
+    runs: Vec<I>,
+    // A min-heap of the next element of every run that isn't exhausted, with
+    // the index of the run.
+    heap: Vec<(I::Item, usize)>,
+    started: bool,
+    compare: F,
+}
+
+impl<I, F> MergeSorted<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item, &I::Item) -> Ordering,
+{
+    fn less(&mut self, a: usize, b: usize) -> bool {
+        let (a, b) = (&self.heap[a], &self.heap[b]);
+        (self.compare)(&a.0, &b.0).then(a.1.cmp(&b.1)) == Ordering::Less
+    }
+
+    fn sift_up(&mut self, mut i: usize) {
+        while i > 0 {
+            let parent = (i - 1) / 2;
+            if !self.less(i, parent) {
+                break;
+            }
+            self.heap.swap(i, parent);
+            i = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut i: usize) {
+        loop {
+            let mut min = i;
+            for child in [2 * i + 1, 2 * i + 2] {
+                if child < self.heap.len() && self.less(child, min) {
+                    min = child;
+                }
+            }
+            if min == i {
+                break;
+            }
+            self.heap.swap(i, min);
+            i = min;
+        }
+    }
+}
+
+impl<I, F> Iterator for MergeSorted<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item, &I::Item) -> Ordering,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !mem::replace(&mut self.started, true) {
+            for i in 0..self.runs.len() {
+                if let Some(v) = self.runs[i].next() {
+                    self.heap.push((v, i));
+                    self.sift_up(self.heap.len() - 1);
+                }
+            }
+        }
+        let i = self.heap.first()?.1;
+        let item = match self.runs[i].next() {
+            Some(v) => mem::replace(&mut self.heap[0].0, v),
+            None => self.heap.swap_remove(0).0,
+        };
+        self.sift_down(0);
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.runs.iter().fold(
+            (self.heap.len(), Some(self.heap.len())),
+            |(lower, upper), run| {
+                let (l, u) = run.size_hint();
+                (
+                    lower.saturating_add(l),
+                    upper.zip(u).and_then(|(a, b)| a.checked_add(b)),
+                )
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{merge_sorted, merge_sorted_by, IterChunks};
+
+    #[test]
+    fn test_merge_sorted() {
+        let data = (0..100u32)
+            .map(|v| v.wrapping_mul(37) % 101)
+            .collect::<Vec<_>>();
+        let runs = data.iter().copied().sorted_chunks(7).collect::<Vec<_>>();
+        let merged = merge_sorted(runs);
+        assert_eq!(merged.size_hint(), (100, Some(100)));
+        let mut expected = data.clone();
+        expected.sort();
+        assert_eq!(merged.collect::<Vec<_>>(), expected);
+
+        // Ties are taken from the earlier run first.
+        let rows = [(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (2, 'e')];
+        let runs = rows
+            .into_iter()
+            .sorted_chunks_by(2, |a, b| a.0.cmp(&b.0))
+            .collect::<Vec<_>>();
+        let merged = merge_sorted_by(runs, |a, b| a.0.cmp(&b.0)).collect::<Vec<_>>();
+        assert_eq!(merged, [(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c'), (2, 'e')]);
+
+        let empty: [Vec<i32>; 3] = [vec![], vec![1], vec![]];
+        assert_eq!(merge_sorted(empty).collect::<Vec<_>>(), [1]);
+        assert!(merge_sorted(Vec::<Vec<i32>>::new()).next().is_none());
+    }
+}
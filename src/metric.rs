@@ -0,0 +1,215 @@
+use std::time::Instant;
+
+use metrics::{counter, histogram, Counter, Histogram};
+
+use crate::{Chunk, Chunks};
+
+impl<I: Iterator> Chunks<I> {
+    /// Records metrics of every chunk with the [`metrics`] crate, named with
+    /// `prefix`:
+    ///
+    /// - `{prefix}_chunks_total`, a counter of the chunks yielded.
+    /// - `{prefix}_elements_total`, a counter of the elements yielded.
+    /// - `{prefix}_chunk_fill_ratio`, a histogram of the length of every
+    ///   chunk divided by `n`.
+    /// - `{prefix}_chunk_duration_seconds`, a histogram of the time spent on
+    ///   every chunk by the closure of [`MetricsChunks::for_each`].
+    ///
+    /// The metrics are registered with the recorder installed when this is
+    /// called. A chunk is recorded when the next one is requested, or when
+    /// [`MetricsChunks::next`] returns `None`.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut chunks = (0..10).chunks(4).with_metrics("ingest");
+    /// chunks.for_each(|chunk| {
+    ///     chunk.for_each(drop);
+    /// });
+    /// ```
+    pub fn with_metrics(self, prefix: &str) -> MetricsChunks<I> {
+        MetricsChunks::new(self, prefix)
+    }
+}
+
+/// An iterator-like struct that records metrics of every chunk.
+///
+/// This `struct` is created by [`Chunks::with_metrics`]. See its
+/// documentation for more.
+pub struct MetricsChunks<I: Iterator> {
+    chunks: Chunks<I>,
+    chunks_total: Counter,
+    elements_total: Counter,
+    fill_ratio: Histogram,
+    duration: Histogram,
+    // The consumed count when the last yielded chunk started, if it's not
+    // recorded yet.
+    pending: Option<usize>,
+}
+
+impl<I: Iterator> MetricsChunks<I> {
+    fn new(chunks: Chunks<I>, prefix: &str) -> Self {
+        Self {
+            chunks,
+            chunks_total: counter!(format!("{prefix}_chunks_total")),
+            elements_total: counter!(format!("{prefix}_elements_total")),
+            fill_ratio: histogram!(format!("{prefix}_chunk_fill_ratio")),
+            duration: histogram!(format!("{prefix}_chunk_duration_seconds")),
+            pending: None,
+        }
+    }
+
+    /// Similar to [`Iterator::next`], but not implements [`Iterator`] due to
+    /// lifetime.
+    ///
+    /// The metrics of the previous chunk are recorded first.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<Chunk<'_, I>> {
+        self.record();
+        let start = self.chunks.consumed();
+        let first = self.chunks.next_first()?;
+        self.pending = Some(start);
+        Some(self.chunks.chunk_with_first(first))
+    }
+
+    /// Similar to [`Iterator::for_each`], and records the time spent in `f`
+    /// on every chunk.
+    pub fn for_each(&mut self, mut f: impl FnMut(Chunk<'_, I>)) {
+        loop {
+            let Some(chunk) = self.next() else {
+                break;
+            };
+            let start = Instant::now();
+            f(chunk);
+            self.duration.record(start.elapsed());
+            self.record();
+        }
+    }
+
+    /// Consumes the adaptor and returns the underlying [`Chunks`].
+    ///
+    /// The metrics of the last yielded chunk are recorded first, if not yet.
+    pub fn into_inner(mut self) -> Chunks<I> {
+        self.record();
+        self.chunks
+    }
+
+    fn record(&mut self) {
+        if let Some(start) = self.pending.take() {
+            let len = self.chunks.consumed() - start;
+            self.chunks_total.increment(1);
+            self.elements_total.increment(len as u64);
+            self.fill_ratio
+                .record(len as f64 / self.chunks.chunk_size() as f64);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    use metrics::{
+        Counter, CounterFn, Gauge, Histogram, HistogramFn, Key, KeyName, Metadata, Recorder,
+        SharedString, Unit,
+    };
+
+    use crate::IterChunks;
+
+    /// A recorder that keeps the sum and the values of every metric.
+    #[derive(Default)]
+    struct TestRecorder {
+        counters: Mutex<HashMap<String, Arc<TestCounter>>>,
+        histograms: Mutex<HashMap<String, Arc<TestHistogram>>>,
+    }
+
+    #[derive(Default)]
+    struct TestCounter(Mutex<u64>);
+
+    impl CounterFn for TestCounter {
+        fn increment(&self, value: u64) {
+            *self.0.lock().unwrap() += value;
+        }
+
+        fn absolute(&self, value: u64) {
+            *self.0.lock().unwrap() = value;
+        }
+    }
+
+    #[derive(Default)]
+    struct TestHistogram(Mutex<Vec<f64>>);
+
+    impl HistogramFn for TestHistogram {
+        fn record(&self, value: f64) {
+            self.0.lock().unwrap().push(value);
+        }
+    }
+
+    impl Recorder for TestRecorder {
+        fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn register_counter(&self, key: &Key, _: &Metadata<'_>) -> Counter {
+            let mut counters = self.counters.lock().unwrap();
+            Counter::from_arc(counters.entry(key.name().to_string()).or_default().clone())
+        }
+
+        fn register_gauge(&self, _: &Key, _: &Metadata<'_>) -> Gauge {
+            Gauge::noop()
+        }
+
+        fn register_histogram(&self, key: &Key, _: &Metadata<'_>) -> Histogram {
+            let mut histograms = self.histograms.lock().unwrap();
+            Histogram::from_arc(
+                histograms
+                    .entry(key.name().to_string())
+                    .or_default()
+                    .clone(),
+            )
+        }
+    }
+
+    impl TestRecorder {
+        fn counter(&self, name: &str) -> u64 {
+            *self.counters.lock().unwrap()[name].0.lock().unwrap()
+        }
+
+        fn histogram(&self, name: &str) -> Vec<f64> {
+            self.histograms.lock().unwrap()[name]
+                .0
+                .lock()
+                .unwrap()
+                .clone()
+        }
+    }
+
+    #[test]
+    fn test_with_metrics() {
+        let recorder = TestRecorder::default();
+        let mut chunks =
+            metrics::with_local_recorder(&recorder, || (0..10).chunks(4).with_metrics("test"));
+        chunks.next().unwrap().for_each(drop);
+        chunks.for_each(|chunk| {
+            chunk.for_each(drop);
+        });
+        assert_eq!(recorder.counter("test_chunks_total"), 3);
+        assert_eq!(recorder.counter("test_elements_total"), 10);
+        assert_eq!(recorder.histogram("test_chunk_fill_ratio"), [1.0, 1.0, 0.5]);
+        // Only the chunks of `for_each` are timed.
+        assert_eq!(recorder.histogram("test_chunk_duration_seconds").len(), 2);
+
+        // A partially consumed chunk is recorded by `into_inner`.
+        let mut chunks = chunks.into_inner();
+        assert!(chunks.next().is_none());
+        let mut chunks =
+            metrics::with_local_recorder(&recorder, || (0..10).chunks(4).with_metrics("partial"));
+        chunks.next().unwrap().next();
+        chunks.into_inner();
+        assert_eq!(recorder.counter("partial_elements_total"), 1);
+        assert_eq!(recorder.histogram("partial_chunk_fill_ratio"), [0.25]);
+    }
+}
@@ -0,0 +1,234 @@
+use std::collections::VecDeque;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, SyncSender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+
+use crate::Chunks;
+
+type Job<T> = (usize, Vec<T>);
+type JobResult<R> = (usize, thread::Result<R>);
+
+/// An iterator that maps chunks on a pool of threads, and yields the results
+/// in the original chunk order.
+///
+/// This `struct` is created by [`Chunks::par_map_ordered`]. See its
+/// documentation for more.
+pub struct ParMapOrdered<I: Iterator, R> {
+    chunks: Chunks<I>,
+    source_done: bool,
+    job_tx: Option<SyncSender<Job<I::Item>>>,
+    result_rx: Receiver<JobResult<R>>,
+    // Set on drop, so that the workers discard the queued jobs.
+    cancelled: Arc<AtomicBool>,
+    workers: Vec<JoinHandle<()>>,
+    // Results of chunks `[next_out, next_in)`, in chunk order. `None` means the
+    // result has not arrived yet.
+    pending: VecDeque<Option<R>>,
+    next_in: usize,
+    next_out: usize,
+    max_in_flight: usize,
+}
+
+impl<I, R> ParMapOrdered<I, R>
+where
+    I: Iterator,
+    I::Item: Send + 'static,
+    R: Send + 'static,
+{
+    pub(crate) fn new<F>(chunks: Chunks<I>, workers: usize, f: F) -> Self
+    where
+        F: Fn(Vec<I::Item>) -> R + Send + Sync + 'static,
+    {
+        assert_ne!(workers, 0);
+        let max_in_flight = workers * 2;
+        let (job_tx, job_rx) = mpsc::sync_channel::<Job<I::Item>>(max_in_flight);
+        let (result_tx, result_rx) = mpsc::channel();
+        let job_rx = Arc::new(Mutex::new(job_rx));
+        let f = Arc::new(f);
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let workers = (0..workers)
+            .map(|_| {
+                let job_rx = job_rx.clone();
+                let result_tx = result_tx.clone();
+                let f = f.clone();
+                let cancelled = cancelled.clone();
+                thread::spawn(move || loop {
+                    // The lock guard is dropped before running `f`.
+                    let job = job_rx.lock().unwrap().recv();
+                    let Ok((index, chunk)) = job else {
+                        return;
+                    };
+                    if cancelled.load(Ordering::Relaxed) {
+                        return;
+                    }
+                    let res = panic::catch_unwind(AssertUnwindSafe(|| f(chunk)));
+                    if result_tx.send((index, res)).is_err() {
+                        return;
+                    }
+                })
+            })
+            .collect();
+        Self {
+            chunks,
+            source_done: false,
+            job_tx: Some(job_tx),
+            result_rx,
+            cancelled,
+            workers,
+            pending: VecDeque::new(),
+            next_in: 0,
+            next_out: 0,
+            max_in_flight,
+        }
+    }
+
+    fn dispatch(&mut self) {
+        while !self.source_done && self.next_in - self.next_out < self.max_in_flight {
+            let mut chunk = Vec::with_capacity(self.chunks.chunk_size());
+            if !self.chunks.next_buffered(&mut chunk) {
+                // The results are yielded as a fused iterator, so the source is
+                // not resumed.
+                self.source_done = true;
+                break;
+            }
+            let job_tx = self.job_tx.as_ref().unwrap();
+            job_tx
+                .send((self.next_in, chunk))
+                .expect("all workers exited");
+            self.next_in += 1;
+            self.pending.push_back(None);
+        }
+    }
+}
+
+impl<I, R> Iterator for ParMapOrdered<I, R>
+where
+    I: Iterator,
+    I::Item: Send + 'static,
+    R: Send + 'static,
+{
+    type Item = R;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.dispatch();
+        if self.next_out == self.next_in {
+            return None;
+        }
+        while self.pending[0].is_none() {
+            let (index, res) = self.result_rx.recv().expect("all workers exited");
+            match res {
+                Ok(v) => self.pending[index - self.next_out] = Some(v),
+                Err(payload) => panic::resume_unwind(payload),
+            }
+        }
+        self.next_out += 1;
+        self.pending.pop_front().unwrap()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let in_flight = self.next_in - self.next_out;
+        if self.source_done {
+            return (in_flight, Some(in_flight));
+        }
+        let (lower, upper) = self.chunks.inner.size_hint();
+        let buffered = self.chunks.buffered.len();
+        let n = self.chunks.chunk_size();
+        let lower = lower.saturating_add(buffered).div_ceil(n);
+        let upper = upper
+            .and_then(|v| v.checked_add(buffered))
+            .map(|v| v.div_ceil(n));
+        (
+            lower.saturating_add(in_flight),
+            upper.and_then(|v| v.checked_add(in_flight)),
+        )
+    }
+}
+
+impl<I: Iterator, R> Drop for ParMapOrdered<I, R> {
+    fn drop(&mut self) {
+        // The workers finish their current job, then discard the queued ones.
+        self.cancelled.store(true, Ordering::Relaxed);
+        self.job_tx = None;
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use crate::IterChunks;
+
+    #[test]
+    fn test_par_map_ordered() {
+        let res = (0..100)
+            .chunks(7)
+            .par_map_ordered(4, |chunk| {
+                // Make later chunks finish earlier.
+                let delay = 20 - chunk[0] / 7;
+                std::thread::sleep(Duration::from_millis(delay as u64));
+                chunk.iter().sum::<i32>()
+            })
+            .collect::<Vec<_>>();
+        let expected = (0..100)
+            .collect::<Vec<_>>()
+            .chunks(7)
+            .map(|c| c.iter().sum::<i32>())
+            .collect::<Vec<_>>();
+        assert_eq!(res, expected);
+    }
+
+    #[test]
+    fn test_par_map_ordered_lazy() {
+        let mut iter = (0..).chunks(2).par_map_ordered(2, |chunk| chunk[1]);
+        assert_eq!(iter.size_hint().1, None);
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), Some(3));
+        // Dropping the iterator of an infinite source must not hang.
+        drop(iter);
+
+        let mut iter = std::iter::empty::<i32>()
+            .chunks(2)
+            .par_map_ordered(2, |chunk| chunk.len());
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn test_par_map_ordered_drop() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut iter = (0..100).chunks(1).par_map_ordered(2, {
+            let calls = calls.clone();
+            move |chunk| {
+                calls.fetch_add(1, Ordering::Relaxed);
+                if chunk[0] > 0 {
+                    std::thread::sleep(Duration::from_millis(100));
+                }
+                chunk[0]
+            }
+        });
+        // Chunks 0 to 3 are dispatched, and chunk 3 is still queued behind the
+        // slow ones when the iterator is dropped.
+        assert_eq!(iter.next(), Some(0));
+        drop(iter);
+        assert!(calls.load(Ordering::Relaxed) <= 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "bad chunk")]
+    fn test_par_map_ordered_panic() {
+        let iter = (0..10).chunks(2).par_map_ordered(2, |chunk| {
+            if chunk[0] == 4 {
+                panic!("bad chunk");
+            }
+            chunk.len()
+        });
+        for _ in iter {}
+    }
+}
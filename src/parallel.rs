@@ -0,0 +1,160 @@
+use rayon::prelude::*;
+
+use crate::Chunks;
+
+// The number of chunks handed to the pool at once, per thread.
+const CHUNKS_PER_THREAD: usize = 4;
+
+impl<I: Iterator> Chunks<I> {
+    /// Maps every chunk with `f` on the rayon thread pool, and returns the
+    /// results in chunk order.
+    ///
+    /// Every chunk is collected into a `Vec` and passed to `f`. Chunks are
+    /// pulled in waves of a few chunks per thread of the pool, so a long
+    /// source is never collected at once. A panic in `f` is propagated to the
+    /// caller.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let sums = (0..10)
+    ///     .chunks(3)
+    ///     .par_map_chunks(|chunk| chunk.iter().sum::<i32>());
+    /// assert_eq!(sums, vec![3, 12, 21, 9]);
+    /// ```
+    pub fn par_map_chunks<R, F>(&mut self, f: F) -> Vec<R>
+    where
+        I::Item: Send,
+        R: Send,
+        F: Fn(Vec<I::Item>) -> R + Sync + Send,
+    {
+        let mut res = vec![];
+        let mut wave = vec![];
+        while self.next_wave(&mut wave) {
+            let mapped = wave.par_drain(..).map(&f).collect::<Vec<_>>();
+            res.extend(mapped);
+        }
+        res
+    }
+
+    /// Folds the chunks in parallel on the rayon thread pool: every worker
+    /// folds whole chunks into an accumulator that starts as `identity()`,
+    /// and the accumulators are combined with `reduce`.
+    ///
+    /// Like [`ParallelIterator::fold`], `identity` may be called any number
+    /// of times, and `reduce` should be associative. The accumulators are
+    /// reduced in chunk order, so `reduce` doesn't need to be commutative.
+    /// Chunks are pulled in waves like [`Chunks::par_map_chunks`]. Returns
+    /// `identity()` if there's no chunk.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// // A histogram of the last digits.
+    /// let hist = (0..1000).chunks(64).par_fold_chunks(
+    ///     || [0; 10],
+    ///     |mut hist, chunk| {
+    ///         for v in chunk {
+    ///             hist[v % 10] += 1;
+    ///         }
+    ///         hist
+    ///     },
+    ///     |mut a, b| {
+    ///         a.iter_mut().zip(b).for_each(|(a, b)| *a += b);
+    ///         a
+    ///     },
+    /// );
+    /// assert_eq!(hist, [100; 10]);
+    /// ```
+    pub fn par_fold_chunks<T, ID, F, R>(&mut self, identity: ID, fold: F, reduce: R) -> T
+    where
+        I::Item: Send,
+        T: Send,
+        ID: Fn() -> T + Sync + Send,
+        F: Fn(T, Vec<I::Item>) -> T + Sync + Send,
+        R: Fn(T, T) -> T + Sync + Send,
+    {
+        let mut acc = identity();
+        let mut wave = vec![];
+        while self.next_wave(&mut wave) {
+            let folded = wave
+                .par_drain(..)
+                .fold(&identity, &fold)
+                .reduce(&identity, &reduce);
+            acc = reduce(acc, folded);
+        }
+        acc
+    }
+
+    /// Collects the next wave of chunks into `wave`, and returns `false` if
+    /// there's no chunk left.
+    fn next_wave(&mut self, wave: &mut Vec<Vec<I::Item>>) -> bool {
+        let len = rayon::current_num_threads() * CHUNKS_PER_THREAD;
+        while wave.len() < len {
+            let mut chunk = Vec::with_capacity(self.n);
+            if !self.next_buffered(&mut chunk) {
+                break;
+            }
+            wave.push(chunk);
+        }
+        !wave.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::panic::{self, AssertUnwindSafe};
+    use std::thread;
+    use std::time::Duration;
+
+    use crate::IterChunks;
+
+    #[test]
+    fn test_par_map_chunks() {
+        // Later chunks finish first, but the results stay in chunk order.
+        let res = (0..1000u64).chunks(7).par_map_chunks(|chunk| {
+            thread::sleep(Duration::from_micros(1000 - chunk[0]));
+            chunk.iter().sum::<u64>()
+        });
+        let mut expected = vec![];
+        (0..1000u64)
+            .chunks(7)
+            .for_each(|chunk| expected.push(chunk.sum::<u64>()));
+        assert_eq!(res, expected);
+
+        let mut chunks = (0..0).chunks(3);
+        assert!(chunks.par_map_chunks(|chunk| chunk.len()).is_empty());
+    }
+
+    #[test]
+    fn test_par_fold_chunks() {
+        // Concatenation is associative but not commutative.
+        let res = (0..1000).chunks(7).par_fold_chunks(
+            Vec::new,
+            |mut acc, chunk| {
+                acc.extend(chunk);
+                acc
+            },
+            |mut a, b| {
+                a.extend(b);
+                a
+            },
+        );
+        assert_eq!(res, (0..1000).collect::<Vec<_>>());
+
+        let res = (0..0)
+            .chunks(3)
+            .par_fold_chunks(|| 1, |acc, _| acc + 1, |a, b| a + b);
+        assert_eq!(res, 1);
+    }
+
+    #[test]
+    fn test_par_map_chunks_panic() {
+        let res = panic::catch_unwind(AssertUnwindSafe(|| {
+            (0..10).chunks(2).par_map_chunks(|chunk| {
+                assert_ne!(chunk[0], 4, "bad chunk");
+            })
+        }));
+        assert!(res.is_err());
+    }
+}
@@ -0,0 +1,200 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use crate::Chunks;
+
+/// An iterator that yields every chunk of `n` results split into the `Ok`
+/// values and the `Err`s.
+///
+/// This `struct` is created by [`partition_result_chunks`] method on
+/// [`IterChunks`]. See its documentation for more.
+///
+/// [`partition_result_chunks`]: crate::IterChunks::partition_result_chunks
+/// [`IterChunks`]: crate::IterChunks
+#[derive(Debug, Clone)]
+pub struct PartitionResultChunks<I> {
+    inner: I,
+    n: usize,
+}
+
+impl<I> PartitionResultChunks<I> {
+    pub(crate) fn new(inner: I, n: usize) -> Self {
+        assert_ne!(n, 0);
+        Self { inner, n }
+    }
+}
+
+impl<I, T, E> Iterator for PartitionResultChunks<I>
+where
+    I: Iterator<Item = Result<T, E>>,
+{
+    type Item = (Vec<T>, Vec<E>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.inner.next()?;
+        let mut oks = Vec::new();
+        let mut errs = Vec::new();
+        for v in std::iter::once(first).chain(self.inner.by_ref().take(self.n - 1)) {
+            match v {
+                Ok(v) => oks.push(v),
+                Err(e) => errs.push(e),
+            }
+        }
+        Some((oks, errs))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        (lower.div_ceil(self.n), upper.map(|v| v.div_ceil(self.n)))
+    }
+}
+
+impl<I: Iterator> Chunks<I> {
+    /// Splits into two iterators of chunks, routing every chunk to the first
+    /// one if `pred` returns `true` for it, or to the second one otherwise.
+    ///
+    /// Every chunk is buffered, so that `pred` sees it as a whole. The chunks
+    /// routed to one side are queued until that side pulls them, so the sides
+    /// can be consumed in any order, or from two threads. Dropping a side
+    /// discards its chunks.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let rows = [1, 2, 3, -4, 5, 6, 7];
+    /// let (clean, dirty) = rows
+    ///     .into_iter()
+    ///     .chunks(2)
+    ///     .partition_chunks(|chunk| chunk.iter().all(|&v| v > 0));
+    /// assert_eq!(dirty.collect::<Vec<_>>(), vec![vec![3, -4]]);
+    /// assert_eq!(clean.collect::<Vec<_>>(), vec![vec![1, 2], vec![5, 6], vec![7]]);
+    /// ```
+    pub fn partition_chunks<P>(self, pred: P) -> (PartitionedChunks<I, P>, PartitionedChunks<I, P>)
+    where
+        P: FnMut(&[I::Item]) -> bool,
+    {
+        let state = PartitionState {
+            chunks: self,
+            pred,
+            queues: [VecDeque::new(), VecDeque::new()],
+            alive: [true; 2],
+        };
+        let shared = Arc::new(Mutex::new(state));
+        let side = |side| PartitionedChunks {
+            shared: shared.clone(),
+            side,
+        };
+        (side(0), side(1))
+    }
+}
+
+/// An iterator over the chunks routed to one side by
+/// [`Chunks::partition_chunks`].
+///
+/// This `struct` is created by [`Chunks::partition_chunks`]. See its
+/// documentation for more.
+pub struct PartitionedChunks<I: Iterator, P> {
+    shared: Arc<Mutex<PartitionState<I, P>>>,
+    side: usize,
+}
+
+struct PartitionState<I: Iterator, P> {
+    chunks: Chunks<I>,
+    pred: P,
+    // The chunks routed to every side but not pulled yet.
+    queues: [VecDeque<Vec<I::Item>>; 2],
+    alive: [bool; 2],
+}
+
+impl<I, P> Iterator for PartitionedChunks<I, P>
+where
+    I: Iterator,
+    P: FnMut(&[I::Item]) -> bool,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut state = self.shared.lock().unwrap();
+        let state = &mut *state;
+        if let Some(chunk) = state.queues[self.side].pop_front() {
+            return Some(chunk);
+        }
+        loop {
+            let mut buf = Vec::with_capacity(state.chunks.chunk_size());
+            if !state.chunks.next_buffered(&mut buf) {
+                return None;
+            }
+            let side = if (state.pred)(&buf) { 0 } else { 1 };
+            if side == self.side {
+                return Some(buf);
+            }
+            if state.alive[side] {
+                state.queues[side].push_back(buf);
+            }
+        }
+    }
+}
+
+impl<I: Iterator, P> Drop for PartitionedChunks<I, P> {
+    fn drop(&mut self) {
+        // Don't panic again in drop if the lock is poisoned.
+        if let Ok(mut state) = self.shared.lock() {
+            state.alive[self.side] = false;
+            state.queues[self.side].clear();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IterChunks;
+
+    #[test]
+    fn test_partition_result_chunks() {
+        let rows = ["1", "x", "3", "4", "y", "z", "7"];
+        let res = rows
+            .into_iter()
+            .map(|s| s.parse::<i32>().map_err(|_| s))
+            .partition_result_chunks(3)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            res,
+            vec![
+                (vec![1, 3], vec!["x"]),
+                (vec![4], vec!["y", "z"]),
+                (vec![7], vec![]),
+            ]
+        );
+
+        let mut chunks = std::iter::empty::<Result<i32, ()>>().partition_result_chunks(3);
+        assert!(chunks.next().is_none());
+    }
+
+    #[test]
+    fn test_partition_chunks() {
+        // Pulled alternately.
+        let (mut even, mut odd) = (0..9).chunks(2).partition_chunks(|chunk| chunk[0] % 4 == 0);
+        assert_eq!(odd.next(), Some(vec![2, 3]));
+        assert_eq!(odd.next(), Some(vec![6, 7]));
+        assert_eq!(even.next(), Some(vec![0, 1]));
+        assert_eq!(even.next(), Some(vec![4, 5]));
+        assert_eq!(even.next(), Some(vec![8]));
+        assert_eq!(even.next(), None);
+        assert_eq!(odd.next(), None);
+
+        // A dropped side doesn't queue its chunks.
+        let (all, none) = (0..100).chunks(3).partition_chunks(|_| false);
+        drop(all);
+        assert_eq!(none.flatten().count(), 100);
+    }
+
+    #[test]
+    fn test_partition_chunks_threads() {
+        let (small, large) = (0..1000)
+            .chunks(10)
+            .partition_chunks(|chunk| chunk[0] < 500);
+        let handle = std::thread::spawn(move || large.count());
+        assert_eq!(small.count(), 50);
+        assert_eq!(handle.join().unwrap(), 50);
+    }
+}
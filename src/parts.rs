@@ -0,0 +1,162 @@
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::Chunks;
+
+/// The decomposed state of a [`Chunks`].
+///
+/// This `struct` is created by [`Chunks::into_parts`], and turned back into a
+/// [`Chunks`] by [`Chunks::from_parts`].
+pub struct ChunksParts<I: Iterator> {
+    /// The underlying iterator.
+    pub inner: I,
+    /// The chunk size.
+    pub n: usize,
+    /// The elements pulled ahead or put back, yielded before the underlying
+    /// iterator.
+    pub buffered: Vec<I::Item>,
+    /// The ranges of `buffered` injected by [`Chunks::prepend_chunk`] and not
+    /// yielded yet, in order. Each of them is yielded as a whole chunk.
+    pub prepended: Vec<Range<usize>>,
+    /// The number of elements yielded so far, see
+    /// [`Chunks::elements_consumed`].
+    pub consumed: usize,
+    /// Whether the underlying iterator has returned `None`, which the next
+    /// call to [`Chunks::next`] reports.
+    pub end_flag: bool,
+    /// Whether the underlying iterator may be polled again after returning
+    /// `None`, see [`Chunks::non_resumable`].
+    pub resumable: bool,
+    /// Whether a non-resumable underlying iterator has returned `None`.
+    pub exhausted: bool,
+}
+
+impl<I: Iterator> Chunks<I> {
+    /// Decomposes into the underlying iterator and the state carried between
+    /// chunks, e.g. the buffered elements. [`Chunks::from_parts`] restores
+    /// the same [`Chunks`], so a chunking pipeline can be persisted, or moved
+    /// between components.
+    ///
+    /// With the `tracing` feature, the index of the chunk spans is not kept.
+    ///
+    /// ```
+    /// use iter_chunks::{Chunks, IterChunks};
+    ///
+    /// let mut chunks = (0..5).chunks(2);
+    /// chunks.next().unwrap().for_each(drop);
+    /// chunks.put_back(-1).unwrap();
+    ///
+    /// let parts = chunks.into_parts();
+    /// assert_eq!(parts.buffered, vec![-1]);
+    /// assert_eq!(parts.consumed, 1);
+    ///
+    /// let mut chunks = Chunks::from_parts(parts);
+    /// assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![-1, 2]);
+    /// ```
+    pub fn into_parts(self) -> ChunksParts<I> {
+        let front = self.front();
+        let prepended = self
+            .prepended
+            .iter()
+            .rev()
+            .filter_map(|&(start, len)| {
+                let offset = start.wrapping_sub(front) as isize;
+                // Skip the ones passed by a previous chunk.
+                (offset >= 0).then(|| offset as usize..offset as usize + len)
+            })
+            .collect();
+        ChunksParts {
+            prepended,
+            consumed: self.consumed(),
+            inner: self.inner,
+            n: self.n,
+            buffered: self.buffered.into(),
+            end_flag: self.end_flag,
+            resumable: self.resumable,
+            exhausted: self.exhausted,
+        }
+    }
+
+    /// Creates a [`Chunks`] from the parts returned by
+    /// [`Chunks::into_parts`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `parts.n` is 0.
+    pub fn from_parts(parts: ChunksParts<I>) -> Self {
+        let mut chunks = Chunks::new(parts.inner, parts.n);
+        chunks.pulled = parts.consumed.saturating_add(parts.buffered.len());
+        chunks.buffered = parts.buffered.into();
+        let front = chunks.front();
+        chunks.prepended = parts
+            .prepended
+            .into_iter()
+            .rev()
+            .filter(|range| !range.is_empty())
+            .map(|range| (front.wrapping_add(range.start), range.len()))
+            .collect();
+        chunks.end_flag = parts.end_flag;
+        chunks.resumable = parts.resumable;
+        chunks.exhausted = parts.exhausted;
+        chunks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Chunks, IterChunks};
+
+    #[test]
+    fn test_parts_round_trip() {
+        let mut chunks = (0..7).chunks(3).non_resumable();
+        chunks.next().unwrap().for_each(drop);
+        assert_eq!(chunks.peek_len(), 3);
+
+        let parts = chunks.into_parts();
+        assert_eq!(parts.n, 3);
+        assert_eq!(parts.buffered, vec![3, 4, 5]);
+        assert!(parts.prepended.is_empty());
+        assert_eq!(parts.consumed, 3);
+        assert!(!parts.end_flag);
+        assert!(!parts.resumable);
+
+        let mut chunks = Chunks::from_parts(parts);
+        assert_eq!(chunks.elements_consumed(), 3);
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![3, 4, 5]);
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![6]);
+        assert!(chunks.next().is_none());
+        assert_eq!(chunks.elements_consumed(), 7);
+
+        // The pending end is kept.
+        let mut chunks = (0..2).chunks(3);
+        assert_eq!(chunks.peek_len(), 2);
+        let parts = chunks.into_parts();
+        assert!(parts.end_flag);
+        let mut chunks = Chunks::from_parts(parts);
+        assert_eq!(chunks.next().unwrap().count(), 2);
+        assert!(chunks.next().is_none());
+    }
+    #[test]
+    fn test_parts_prepended() {
+        let mut chunks = (0..5).chunks(2);
+        chunks.prepend_chunk(vec![100, 101, 102]);
+        chunks.prepend_chunk(vec![99]);
+        let parts = chunks.into_parts();
+        assert_eq!(parts.buffered, vec![99, 100, 101, 102]);
+        assert_eq!(parts.prepended, vec![0..1, 1..4]);
+
+        let mut chunks = Chunks::from_parts(parts);
+        let mut res = vec![];
+        chunks.for_each(|chunk| res.push(chunk.collect::<Vec<_>>()));
+        assert_eq!(
+            res,
+            vec![
+                vec![99],
+                vec![100, 101, 102],
+                vec![0, 1],
+                vec![2, 3],
+                vec![4]
+            ]
+        );
+    }
+}
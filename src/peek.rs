@@ -0,0 +1,163 @@
+use crate::Chunk;
+
+impl<'a, I: Iterator> Chunk<'a, I> {
+    /// Returns a reference to the next element of the chunk without
+    /// consuming it.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut chunks = (0..3).chunks(2);
+    /// let mut chunk = chunks.next().unwrap();
+    /// assert_eq!(chunk.peek(), Some(&0));
+    /// assert_eq!(chunk.next(), Some(0));
+    /// ```
+    pub fn peek(&mut self) -> Option<&I::Item> {
+        self.peek_nth(0)
+    }
+
+    /// Returns a reference to the `k`-th next element of the chunk without
+    /// consuming anything, or `None` if the chunk ends before it.
+    ///
+    /// The elements up to the `k`-th are pulled ahead from the underlying
+    /// iterator and buffered in the parent [`Chunks`], so they're still
+    /// yielded by this chunk. Elements past the end of the chunk are never
+    /// pulled.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut chunks = (0..5).chunks(3);
+    /// let mut chunk = chunks.next().unwrap();
+    /// assert_eq!(chunk.peek_nth(2), Some(&2));
+    /// assert_eq!(chunk.peek_nth(3), None);
+    /// assert_eq!(chunk.collect::<Vec<_>>(), vec![0, 1, 2]);
+    /// ```
+    ///
+    /// [`Chunks`]: crate::Chunks
+    pub fn peek_nth(&mut self, k: usize) -> Option<&I::Item> {
+        let k = match (self.first.is_some(), k) {
+            (true, 0) => return self.first.as_ref(),
+            (true, k) => k - 1,
+            (false, k) => k,
+        };
+        if k >= self.n {
+            return None;
+        }
+        // Only the elements of this chunk can be buffered here, since the
+        // buffer is shorter than the rest of the chunk.
+        while self.parent.buffered.len() <= k {
+            if self.parent.end_flag {
+                return None;
+            }
+            match self.parent.pull_inner() {
+                Some(v) => self.parent.buffered.push_back(v),
+                None => {
+                    self.end();
+                    // The buffered elements are still yielded.
+                    self.n = self.parent.buffered.len();
+                    return None;
+                }
+            }
+        }
+        self.parent.buffered.get(k)
+    }
+
+    /// Returns a cursor that peeks the next elements of the chunk one by one,
+    /// without consuming them.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// // Parse `key=value` pairs, and skip a pair only if it's complete.
+    /// let mut chunks = "a=1b=".chars().chunks(8);
+    /// let mut chunk = chunks.next().unwrap();
+    /// let mut pairs = vec![];
+    /// loop {
+    ///     let mut peek = chunk.multipeek();
+    ///     let k = peek.peek().copied();
+    ///     let eq = peek.peek().copied();
+    ///     let v = peek.peek().copied();
+    ///     let (Some(k), Some('='), Some(v)) = (k, eq, v) else {
+    ///         break;
+    ///     };
+    ///     pairs.push((k, v));
+    ///     chunk.nth(2);
+    /// }
+    /// assert_eq!(pairs, [('a', '1')]);
+    /// assert_eq!(chunk.collect::<String>(), "b=");
+    /// ```
+    pub fn multipeek(&mut self) -> MultiPeek<'_, 'a, I> {
+        MultiPeek {
+            chunk: self,
+            index: 0,
+        }
+    }
+}
+
+/// A cursor that peeks ahead in a [`Chunk`].
+///
+/// This `struct` is created by [`Chunk::multipeek`]. See its documentation
+/// for more.
+pub struct MultiPeek<'c, 'a, I: Iterator> {
+    chunk: &'c mut Chunk<'a, I>,
+    index: usize,
+}
+
+impl<I: Iterator> MultiPeek<'_, '_, I> {
+    /// Returns a reference to the element after the last peeked one, or
+    /// `None` if the chunk ends before it.
+    pub fn peek(&mut self) -> Option<&I::Item> {
+        self.index += 1;
+        self.chunk.peek_nth(self.index - 1)
+    }
+
+    /// Rewinds the cursor, so that the next [`MultiPeek::peek`] returns the
+    /// next element of the chunk again.
+    pub fn reset(&mut self) {
+        self.index = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IterChunks;
+
+    #[test]
+    fn test_peek_nth() {
+        let mut chunks = (0..5).chunks(3);
+        let mut chunk = chunks.next().unwrap();
+        assert_eq!(chunk.next(), Some(0));
+        assert_eq!(chunk.peek_nth(1), Some(&2));
+        assert_eq!(chunk.peek_nth(2), None);
+        assert_eq!(chunk.peek(), Some(&1));
+        // Peeking doesn't consume, so the rest goes to the next chunk.
+        assert_eq!(chunks.elements_consumed(), 1);
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![1, 2, 3]);
+
+        // Peeking past the end of the underlying iterator.
+        let mut chunk = chunks.next().unwrap();
+        assert_eq!(chunk.peek_nth(1), None);
+        assert_eq!(chunk.peek_nth(2), None);
+        assert_eq!(chunk.peek(), Some(&4));
+        assert_eq!(chunk.collect::<Vec<_>>(), vec![4]);
+        assert!(chunks.next().is_none());
+        assert_eq!(chunks.elements_consumed(), 5);
+    }
+
+    #[test]
+    fn test_multipeek() {
+        let mut chunks = (0..3).chunks(2);
+        let mut chunk = chunks.next().unwrap();
+        let mut peek = chunk.multipeek();
+        assert_eq!(peek.peek(), Some(&0));
+        assert_eq!(peek.peek(), Some(&1));
+        assert_eq!(peek.peek(), None);
+        peek.reset();
+        assert_eq!(peek.peek(), Some(&0));
+        assert_eq!(chunk.next(), Some(0));
+        assert_eq!(chunk.multipeek().peek(), Some(&1));
+        assert_eq!(chunk.collect::<Vec<_>>(), vec![1]);
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![2]);
+    }
+}
@@ -0,0 +1,138 @@
+use crate::{Chunk, Chunks};
+
+impl<I: ExactSizeIterator> Chunks<I> {
+    /// Calls `f` with `(chunks_done, chunks_total, elements_done,
+    /// elements_total)` after every chunk.
+    ///
+    /// The totals are computed from the length of the underlying iterator
+    /// when this is called. A chunk is done when the next one is requested,
+    /// or when [`ProgressChunks::next`] returns `None`.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut log = vec![];
+    /// let mut chunks = (0..5).chunks(2).with_progress(|chunks, total, done, len| {
+    ///     log.push(format!("{chunks}/{total} chunks, {done}/{len} elements"));
+    /// });
+    /// chunks.for_each(|chunk| {
+    ///     chunk.for_each(drop);
+    /// });
+    /// drop(chunks);
+    /// assert_eq!(
+    ///     log,
+    ///     [
+    ///         "1/3 chunks, 2/5 elements",
+    ///         "2/3 chunks, 4/5 elements",
+    ///         "3/3 chunks, 5/5 elements",
+    ///     ]
+    /// );
+    /// ```
+    pub fn with_progress<F>(self, f: F) -> ProgressChunks<I, F>
+    where
+        F: FnMut(usize, usize, usize, usize),
+    {
+        ProgressChunks::new(self, f)
+    }
+}
+
+/// An iterator-like struct that reports the progress after every chunk.
+///
+/// This `struct` is created by [`Chunks::with_progress`]. See its
+/// documentation for more.
+pub struct ProgressChunks<I: Iterator, F> {
+    chunks: Chunks<I>,
+    f: F,
+    // The number of elements yielded before this adaptor was created.
+    start: usize,
+    elements_total: usize,
+    chunks_done: usize,
+    chunks_total: usize,
+    // Whether a yielded chunk is not reported yet.
+    pending: bool,
+}
+
+impl<I, F> ProgressChunks<I, F>
+where
+    I: ExactSizeIterator,
+    F: FnMut(usize, usize, usize, usize),
+{
+    fn new(chunks: Chunks<I>, f: F) -> Self {
+        let elements_total = chunks.inner.len() + chunks.buffered.len();
+        Self {
+            start: chunks.consumed(),
+            chunks_total: elements_total.div_ceil(chunks.n),
+            chunks,
+            f,
+            elements_total,
+            chunks_done: 0,
+            pending: false,
+        }
+    }
+
+    /// Similar to [`Iterator::next`], but not implements [`Iterator`] due to
+    /// lifetime.
+    ///
+    /// The progress of the previous chunk is reported first.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<Chunk<'_, I>> {
+        self.report();
+        let first = self.chunks.next_first()?;
+        self.pending = true;
+        Some(self.chunks.chunk_with_first(first))
+    }
+
+    /// Similar to [`Iterator::for_each`].
+    pub fn for_each(&mut self, mut f: impl FnMut(Chunk<'_, I>)) {
+        while let Some(item) = self.next() {
+            f(item)
+        }
+    }
+
+    /// Consumes the adaptor and returns the underlying [`Chunks`].
+    ///
+    /// The progress of the last yielded chunk is reported first, if not yet.
+    pub fn into_inner(mut self) -> Chunks<I> {
+        self.report();
+        self.chunks
+    }
+
+    fn report(&mut self) {
+        if std::mem::take(&mut self.pending) {
+            self.chunks_done += 1;
+            (self.f)(
+                self.chunks_done,
+                self.chunks_total,
+                self.chunks.consumed() - self.start,
+                self.elements_total,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IterChunks;
+
+    #[test]
+    fn test_with_progress() {
+        let mut log = vec![];
+        let mut chunks = (0..7)
+            .chunks(3)
+            .with_progress(|c, ct, e, et| log.push((c, ct, e, et)));
+        chunks.next().unwrap().for_each(drop);
+        chunks.next().unwrap().next();
+        let rest = chunks.into_inner();
+        assert_eq!(log, [(1, 3, 3, 7), (2, 3, 4, 7)]);
+
+        // The totals start from the current position.
+        let mut log = vec![];
+        let mut chunks = rest.with_progress(|c, ct, e, et| log.push((c, ct, e, et)));
+        while let Some(chunk) = chunks.next() {
+            chunk.for_each(drop);
+        }
+        assert!(chunks.next().is_none());
+        drop(chunks);
+        assert_eq!(log, [(1, 1, 3, 3)]);
+    }
+}
@@ -0,0 +1,167 @@
+use indicatif::{ProgressBar, ProgressStyle};
+
+use crate::{Chunk, Chunks};
+
+/// Returns a [`ProgressStyle`] for a bar that counts elements, as used by
+/// [`Chunks::with_progress_bar`] over an exact-size iterator.
+pub fn element_progress_style() -> ProgressStyle {
+    ProgressStyle::with_template(
+        "{spinner} [{elapsed_precise}] [{wide_bar}] {pos}/{len} elements ({per_sec}, eta {eta}) {msg}",
+    )
+    .expect("the template is valid")
+}
+
+/// Returns a [`ProgressStyle`] for a bar that counts chunks, as used by
+/// [`Chunks::with_progress_bar`] when the number of elements is unknown.
+pub fn chunk_progress_style() -> ProgressStyle {
+    ProgressStyle::with_template("{spinner} [{elapsed_precise}] {pos} chunks ({per_sec}) {msg}")
+        .expect("the template is valid")
+}
+
+impl<I: Iterator> Chunks<I> {
+    /// Advances an [`indicatif`] progress bar as the chunks are done.
+    ///
+    /// If the size hint of the underlying iterator is exact, the length of
+    /// `bar` is set to the number of elements left, and its position follows
+    /// the elements yielded. Otherwise, `bar` is advanced by one for every
+    /// chunk. The style of `bar` is kept; see [`element_progress_style`] and
+    /// [`chunk_progress_style`] for templates of both cases.
+    ///
+    /// A chunk is done when the next one is requested. `bar` is finished when
+    /// [`ProgressBarChunks::next`] returns `None`.
+    ///
+    /// ```
+    /// use indicatif::ProgressBar;
+    /// use iter_chunks::{element_progress_style, IterChunks};
+    ///
+    /// let bar = ProgressBar::hidden().with_style(element_progress_style());
+    /// let mut chunks = (0..10).chunks(4).with_progress_bar(bar.clone());
+    /// chunks.for_each(|chunk| {
+    ///     chunk.for_each(drop);
+    /// });
+    /// assert_eq!(bar.position(), 10);
+    /// assert_eq!(bar.length(), Some(10));
+    /// assert!(bar.is_finished());
+    /// ```
+    pub fn with_progress_bar(self, bar: ProgressBar) -> ProgressBarChunks<I> {
+        ProgressBarChunks::new(self, bar)
+    }
+}
+
+/// An iterator-like struct that advances a progress bar as the chunks are
+/// done.
+///
+/// This `struct` is created by [`Chunks::with_progress_bar`]. See its
+/// documentation for more.
+pub struct ProgressBarChunks<I: Iterator> {
+    chunks: Chunks<I>,
+    bar: ProgressBar,
+    // The number of elements yielded before this adaptor was created.
+    start: usize,
+    // Whether the bar counts elements rather than chunks.
+    elements: bool,
+    // Whether a yielded chunk is not reported yet.
+    pending: bool,
+}
+
+impl<I: Iterator> ProgressBarChunks<I> {
+    fn new(chunks: Chunks<I>, bar: ProgressBar) -> Self {
+        let (lower, upper) = chunks.inner.size_hint();
+        let elements = upper == Some(lower);
+        if elements {
+            bar.set_length((lower + chunks.buffered.len()) as u64);
+        }
+        Self {
+            start: chunks.consumed(),
+            chunks,
+            bar,
+            elements,
+            pending: false,
+        }
+    }
+
+    /// Returns the progress bar.
+    pub fn progress_bar(&self) -> &ProgressBar {
+        &self.bar
+    }
+
+    /// Similar to [`Iterator::next`], but not implements [`Iterator`] due to
+    /// lifetime.
+    ///
+    /// The progress of the previous chunk is reported first.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<Chunk<'_, I>> {
+        self.report();
+        let Some(first) = self.chunks.next_first() else {
+            self.bar.finish();
+            return None;
+        };
+        self.pending = true;
+        Some(self.chunks.chunk_with_first(first))
+    }
+
+    /// Similar to [`Iterator::for_each`].
+    pub fn for_each(&mut self, mut f: impl FnMut(Chunk<'_, I>)) {
+        while let Some(item) = self.next() {
+            f(item)
+        }
+    }
+
+    /// Consumes the adaptor and returns the underlying [`Chunks`].
+    ///
+    /// The progress of the last yielded chunk is reported first, if not yet.
+    /// The bar is left unfinished.
+    pub fn into_inner(mut self) -> Chunks<I> {
+        self.report();
+        self.chunks
+    }
+
+    fn report(&mut self) {
+        if std::mem::take(&mut self.pending) {
+            if self.elements {
+                self.bar
+                    .set_position((self.chunks.consumed() - self.start) as u64);
+            } else {
+                self.bar.inc(1);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use indicatif::ProgressBar;
+
+    use crate::IterChunks;
+
+    #[test]
+    fn test_with_progress_bar() {
+        // An exact-size iterator counts elements, from where it's started.
+        let bar = ProgressBar::hidden();
+        let mut chunks = (0..7).chunks(3);
+        chunks.next().unwrap().next();
+        let mut chunks = chunks.with_progress_bar(bar.clone());
+        assert_eq!(bar.length(), Some(6));
+        assert_eq!(chunks.next().unwrap().count(), 3);
+        assert_eq!(bar.position(), 0);
+        chunks.next().unwrap().next();
+        assert_eq!(bar.position(), 3);
+        let chunks = chunks.into_inner();
+        assert_eq!(bar.position(), 4);
+        assert!(!bar.is_finished());
+        drop(chunks);
+
+        // Otherwise, chunks are counted.
+        let bar = ProgressBar::hidden();
+        let mut chunks = (0..7)
+            .filter(|v| v % 2 == 0)
+            .chunks(3)
+            .with_progress_bar(bar.clone());
+        chunks.for_each(|chunk| {
+            chunk.for_each(drop);
+        });
+        assert_eq!(bar.length(), None);
+        assert_eq!(bar.position(), 2);
+        assert!(bar.is_finished());
+    }
+}
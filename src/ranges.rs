@@ -0,0 +1,106 @@
+use std::ops::Range;
+
+/// Splits the index space `0..len` into ranges of `n` indices each, or fewer
+/// for the last one.
+///
+/// The boundaries are the same as the ones of `(0..len).chunks(n)`, but no data
+/// is touched, so the ranges can be used to slice several parallel arrays
+/// identically.
+///
+/// # Panics
+///
+/// Panics if `n` is 0.
+///
+/// ```
+/// use iter_chunks::chunk_ranges;
+///
+/// let ids = [1, 2, 3, 4, 5];
+/// let names = ["a", "b", "c", "d", "e"];
+/// for range in chunk_ranges(ids.len(), 2) {
+///     assert_eq!(ids[range.clone()].len(), names[range].len());
+/// }
+/// let ranges = chunk_ranges(5, 2).collect::<Vec<_>>();
+/// assert_eq!(ranges, [0..2, 2..4, 4..5]);
+/// ```
+pub fn chunk_ranges(len: usize, n: usize) -> ChunkRanges {
+    assert_ne!(n, 0);
+    ChunkRanges { start: 0, len, n }
+}
+
+/// An iterator over the index ranges of chunks of `n` indices.
+///
+/// This `struct` is created by [`chunk_ranges`]. See its documentation for
+/// more.
+#[derive(Debug, Clone)]
+pub struct ChunkRanges {
+    start: usize,
+    len: usize,
+    n: usize,
+}
+
+impl Iterator for ChunkRanges {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start >= self.len {
+            return None;
+        }
+        let start = self.start;
+        self.start = start.saturating_add(self.n).min(self.len);
+        Some(start..self.start)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.len - self.start).div_ceil(self.n);
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for ChunkRanges {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start >= self.len {
+            return None;
+        }
+        // The last range is the short one, so it's aligned from the start.
+        let rem = (self.len - self.start) % self.n;
+        let end = self.len;
+        self.len -= if rem == 0 { self.n } else { rem };
+        Some(self.len..end)
+    }
+}
+
+impl ExactSizeIterator for ChunkRanges {}
+
+impl std::iter::FusedIterator for ChunkRanges {}
+
+#[cfg(test)]
+mod tests {
+    use super::chunk_ranges;
+    use crate::IterChunks;
+
+    #[test]
+    fn test_chunk_ranges() {
+        assert_eq!(chunk_ranges(0, 3).count(), 0);
+        assert_eq!(chunk_ranges(6, 3).collect::<Vec<_>>(), [0..3, 3..6]);
+        assert_eq!(
+            chunk_ranges(usize::MAX, usize::MAX - 1).collect::<Vec<_>>(),
+            [0..usize::MAX - 1, usize::MAX - 1..usize::MAX]
+        );
+
+        for len in 0..10 {
+            for n in 1..5 {
+                let ranges = chunk_ranges(len, n);
+                assert_eq!(ranges.len(), len.div_ceil(n));
+                let mut lens = vec![];
+                (0..len)
+                    .chunks(n)
+                    .for_each(|chunk| lens.push(chunk.count()));
+                assert_eq!(ranges.clone().map(|r| r.len()).collect::<Vec<_>>(), lens);
+
+                let mut rev = ranges.rev().collect::<Vec<_>>();
+                rev.reverse();
+                assert_eq!(rev, chunk_ranges(len, n).collect::<Vec<_>>());
+            }
+        }
+    }
+}
@@ -0,0 +1,106 @@
+/// An iterator that regroups byte buffers into chunks of exactly `n` bytes.
+///
+/// This `struct` is created by [`rechunk_bytes`] method on [`IterChunks`].
+/// See its documentation for more.
+///
+/// [`rechunk_bytes`]: crate::IterChunks::rechunk_bytes
+/// [`IterChunks`]: crate::IterChunks
+#[derive(Debug, Clone)]
+pub struct RechunkBytes<I: Iterator> {
+    inner: I,
+    n: usize,
+    // The input buffer being split, and the offset of its unread part.
+    current: Option<(I::Item, usize)>,
+}
+
+impl<I> RechunkBytes<I>
+where
+    I: Iterator,
+    I::Item: AsRef<[u8]>,
+{
+    pub(crate) fn new(inner: I, n: usize) -> Self {
+        assert_ne!(n, 0);
+        Self {
+            inner,
+            n,
+            current: None,
+        }
+    }
+
+    /// Returns the unread bytes of the input buffer being split.
+    pub fn remainder(&self) -> &[u8] {
+        match &self.current {
+            Some((buf, offset)) => &buf.as_ref()[*offset..],
+            None => &[],
+        }
+    }
+}
+
+impl<I> Iterator for RechunkBytes<I>
+where
+    I: Iterator,
+    I::Item: AsRef<[u8]>,
+{
+    type Item = Vec<u8>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chunk = Vec::new();
+        while chunk.len() < self.n {
+            let drained = match &self.current {
+                Some((buf, offset)) => *offset == buf.as_ref().len(),
+                None => true,
+            };
+            if drained {
+                match self.inner.next() {
+                    Some(buf) => {
+                        self.current = Some((buf, 0));
+                        continue;
+                    }
+                    None => {
+                        self.current = None;
+                        break;
+                    }
+                }
+            }
+            let (buf, offset) = self.current.as_mut().unwrap();
+            let bytes = &buf.as_ref()[*offset..];
+            let len = (self.n - chunk.len()).min(bytes.len());
+            if chunk.capacity() == 0 {
+                chunk.reserve_exact(self.n);
+            }
+            chunk.extend_from_slice(&bytes[..len]);
+            *offset += len;
+        }
+        if chunk.is_empty() {
+            None
+        } else {
+            Some(chunk)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IterChunks;
+
+    #[test]
+    fn test_rechunk_bytes() {
+        let frames: [&[u8]; 5] = [b"ab", b"", b"cdefg", b"h", b"ijk"];
+        let chunks = frames.into_iter().rechunk_bytes(3).collect::<Vec<_>>();
+        assert_eq!(chunks, [&b"abc"[..], b"def", b"ghi", b"jk"]);
+
+        let chunks = [vec![0u8; 6]].into_iter().rechunk_bytes(2).count();
+        assert_eq!(chunks, 3);
+
+        let frames: [&[u8]; 2] = [b"", b""];
+        assert_eq!(frames.into_iter().rechunk_bytes(2).next(), None);
+    }
+
+    #[test]
+    fn test_rechunk_bytes_remainder() {
+        let mut chunks = [b"abcde".to_vec()].into_iter().rechunk_bytes(2);
+        assert_eq!(chunks.remainder(), b"");
+        assert_eq!(chunks.next().unwrap(), b"ab");
+        assert_eq!(chunks.remainder(), b"cde");
+    }
+}
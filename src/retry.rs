@@ -0,0 +1,143 @@
+use std::thread;
+use std::time::Duration;
+
+use crate::Chunks;
+
+/// How [`Chunks::try_for_each_with_retry`] retries a failed chunk.
+///
+/// By default, a chunk is retried up to `max_retries` times right away.
+/// [`RetryPolicy::backoff`] waits between the attempts instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_retries: usize,
+    backoff: Duration,
+    factor: u32,
+}
+
+impl RetryPolicy {
+    /// Creates a policy that retries a failed chunk up to `max_retries`
+    /// times, i.e. makes up to `max_retries + 1` attempts.
+    pub fn new(max_retries: usize) -> Self {
+        Self {
+            max_retries,
+            backoff: Duration::ZERO,
+            factor: 1,
+        }
+    }
+
+    /// Waits `initial` before the first retry, and `factor` times longer
+    /// before every following one. A `factor` of 1 waits the same time
+    /// before every retry.
+    pub fn backoff(mut self, initial: Duration, factor: u32) -> Self {
+        self.backoff = initial;
+        self.factor = factor;
+        self
+    }
+
+    /// Returns the time to wait before the retry `retry`, counting from 0.
+    fn delay(&self, retry: usize) -> Duration {
+        let factor = u32::try_from(retry)
+            .ok()
+            .and_then(|retry| self.factor.checked_pow(retry))
+            .unwrap_or(u32::MAX);
+        self.backoff.saturating_mul(factor)
+    }
+}
+
+impl<I: Iterator> Chunks<I> {
+    /// Calls `f` with every chunk as a slice, and retries a chunk for which
+    /// `f` returns `Err` as configured by `policy`.
+    ///
+    /// The chunk is buffered, so the same elements are presented to every
+    /// attempt. If the retries are exhausted, the error of the last attempt
+    /// is returned, and the failed chunk is given back to `self`, so that the
+    /// iteration can be resumed from it.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use iter_chunks::{IterChunks, RetryPolicy};
+    ///
+    /// let policy = RetryPolicy::new(2).backoff(Duration::from_millis(1), 2);
+    /// let mut failures = 2;
+    /// let mut written = vec![];
+    /// let res = (0..5).chunks(2).try_for_each_with_retry(policy, |chunk| {
+    ///     if chunk[0] == 2 && failures > 0 {
+    ///         failures -= 1;
+    ///         return Err("transient");
+    ///     }
+    ///     written.push(chunk.to_vec());
+    ///     Ok(())
+    /// });
+    /// assert_eq!(res, Ok(()));
+    /// assert_eq!(written, vec![vec![0, 1], vec![2, 3], vec![4]]);
+    /// ```
+    pub fn try_for_each_with_retry<E>(
+        &mut self,
+        policy: RetryPolicy,
+        mut f: impl FnMut(&[I::Item]) -> Result<(), E>,
+    ) -> Result<(), E> {
+        let mut buf = Vec::with_capacity(self.n);
+        while self.next_buffered(&mut buf) {
+            let mut retry = 0;
+            while let Err(e) = f(&buf) {
+                if retry == policy.max_retries {
+                    self.unread(&mut buf);
+                    return Err(e);
+                }
+                thread::sleep(policy.delay(retry));
+                retry += 1;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::RetryPolicy;
+    use crate::IterChunks;
+
+    #[test]
+    fn test_retry_policy_delay() {
+        let policy = RetryPolicy::new(3);
+        assert_eq!(policy.delay(2), Duration::ZERO);
+
+        let policy = RetryPolicy::new(3).backoff(Duration::from_millis(10), 3);
+        assert_eq!(policy.delay(0), Duration::from_millis(10));
+        assert_eq!(policy.delay(2), Duration::from_millis(90));
+        assert_eq!(policy.delay(100), Duration::from_millis(10) * u32::MAX);
+    }
+
+    #[test]
+    fn test_try_for_each_with_retry() {
+        // The retries are exhausted on the second chunk.
+        let mut chunks = (0..7).chunks(3);
+        let mut attempts = vec![];
+        let res = chunks.try_for_each_with_retry(RetryPolicy::new(2), |chunk| {
+            attempts.push(chunk.to_vec());
+            if chunk[0] == 3 {
+                Err(chunk.len())
+            } else {
+                Ok(())
+            }
+        });
+        assert_eq!(res, Err(3));
+        assert_eq!(
+            attempts,
+            vec![vec![0, 1, 2], vec![3, 4, 5], vec![3, 4, 5], vec![3, 4, 5]]
+        );
+
+        // The failed chunk is given back.
+        assert_eq!(chunks.elements_consumed(), 3);
+        let mut rest = vec![];
+        let res = chunks.try_for_each_with_retry(RetryPolicy::new(0), |chunk| {
+            rest.push(chunk.to_vec());
+            Ok::<_, ()>(())
+        });
+        assert_eq!(res, Ok(()));
+        assert_eq!(rest, vec![vec![3, 4, 5], vec![6]]);
+    }
+}
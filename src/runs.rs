@@ -0,0 +1,196 @@
+/// An iterator that groups consecutive elements with equal keys into chunks of
+/// at most `max_n` elements.
+///
+/// This `struct` is created by [`chunk_runs`] method on [`IterChunks`]. See its
+/// documentation for more.
+///
+/// [`chunk_runs`]: crate::IterChunks::chunk_runs
+/// [`IterChunks`]: crate::IterChunks
+#[derive(Debug, Clone)]
+pub struct ChunkRuns<I: Iterator, K, F> {
+    inner: I,
+    max_n: usize,
+    key_fn: F,
+    // The first element of the next chunk, pulled ahead to close the previous
+    // one.
+    peeked: Option<(K, I::Item)>,
+}
+
+impl<I, K, F> ChunkRuns<I, K, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> K,
+{
+    pub(crate) fn new(inner: I, max_n: usize, key_fn: F) -> Self {
+        assert_ne!(max_n, 0);
+        Self {
+            inner,
+            max_n,
+            key_fn,
+            peeked: None,
+        }
+    }
+
+    fn pull(&mut self) -> Option<(K, I::Item)> {
+        let v = self.inner.next()?;
+        Some(((self.key_fn)(&v), v))
+    }
+}
+
+impl<I, K, F> Iterator for ChunkRuns<I, K, F>
+where
+    I: Iterator,
+    K: PartialEq,
+    F: FnMut(&I::Item) -> K,
+{
+    type Item = (K, Vec<I::Item>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, first) = match self.peeked.take() {
+            Some(v) => v,
+            None => self.pull()?,
+        };
+        let mut chunk = vec![first];
+        while chunk.len() < self.max_n {
+            let Some((k, v)) = self.pull() else {
+                break;
+            };
+            if k != key {
+                self.peeked = Some((k, v));
+                break;
+            }
+            chunk.push(v);
+        }
+        Some((key, chunk))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        let peeked = self.peeked.is_some() as usize;
+        let lower = lower.saturating_add(peeked).div_ceil(self.max_n);
+        let upper = upper.and_then(|v| v.checked_add(peeked));
+        (lower, upper)
+    }
+}
+
+/// An iterator that yields runs of equal consecutive elements as
+/// `(item, run_length)` entries.
+///
+/// This `struct` is created by [`rle_chunks`] method on [`IterChunks`]. See
+/// its documentation for more.
+///
+/// [`rle_chunks`]: crate::IterChunks::rle_chunks
+/// [`IterChunks`]: crate::IterChunks
+#[derive(Debug, Clone)]
+pub struct RleChunks<I: Iterator> {
+    inner: I,
+    max_run: usize,
+    // The first element of the next run, pulled ahead to close the previous
+    // one.
+    peeked: Option<I::Item>,
+}
+
+impl<I: Iterator> RleChunks<I> {
+    pub(crate) fn new(inner: I, max_run: Option<usize>) -> Self {
+        assert_ne!(max_run, Some(0));
+        Self {
+            inner,
+            max_run: max_run.unwrap_or(usize::MAX),
+            peeked: None,
+        }
+    }
+}
+
+impl<I> Iterator for RleChunks<I>
+where
+    I: Iterator,
+    I::Item: PartialEq,
+{
+    type Item = (I::Item, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = match self.peeked.take() {
+            Some(v) => v,
+            None => self.inner.next()?,
+        };
+        let mut len = 1;
+        while len < self.max_run {
+            let Some(v) = self.inner.next() else {
+                break;
+            };
+            if v != item {
+                self.peeked = Some(v);
+                break;
+            }
+            len += 1;
+        }
+        Some((item, len))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        let peeked = self.peeked.is_some() as usize;
+        let lower = match lower.saturating_add(peeked) {
+            0 => 0,
+            len => len.div_ceil(self.max_run),
+        };
+        let upper = upper.and_then(|v| v.checked_add(peeked));
+        (lower, upper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IterChunks;
+
+    #[test]
+    fn test_chunk_runs() {
+        let rows = [(1, 'a'), (1, 'b'), (1, 'c'), (2, 'd'), (1, 'e'), (1, 'f')];
+        let res = rows
+            .into_iter()
+            .chunk_runs(2, |&(k, _)| k)
+            .map(|(k, chunk)| (k, chunk.into_iter().map(|(_, v)| v).collect::<String>()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            res,
+            vec![
+                (1, "ab".to_owned()),
+                (1, "c".to_owned()),
+                (2, "d".to_owned()),
+                (1, "ef".to_owned()),
+            ]
+        );
+
+        let mut runs = (0..5).chunk_runs(1, |_| ());
+        assert_eq!(runs.size_hint(), (5, Some(5)));
+        assert_eq!(runs.by_ref().count(), 5);
+        assert!(std::iter::empty::<i32>()
+            .chunk_runs(3, |&v| v)
+            .next()
+            .is_none());
+    }
+
+    #[test]
+    fn test_rle_chunks() {
+        let res = "aaabccaaaaa".chars().rle_chunks(None).collect::<Vec<_>>();
+        assert_eq!(res, vec![('a', 3), ('b', 1), ('c', 2), ('a', 5)]);
+
+        let mut runs = "aaabccaaaaa"
+            .chars()
+            .collect::<Vec<_>>()
+            .into_iter()
+            .rle_chunks(Some(2));
+        assert_eq!(
+            runs.by_ref().take(4).collect::<Vec<_>>(),
+            vec![('a', 2), ('a', 1), ('b', 1), ('c', 2)]
+        );
+        assert_eq!(runs.size_hint(), (3, Some(5)));
+        assert_eq!(runs.collect::<Vec<_>>(), vec![('a', 2), ('a', 2), ('a', 1)]);
+
+        assert_eq!(
+            [1; 7].into_iter().rle_chunks(Some(3)).size_hint(),
+            (3, Some(7))
+        );
+        assert!(std::iter::empty::<i32>().rle_chunks(None).next().is_none());
+    }
+}
@@ -0,0 +1,210 @@
+use rand::{Rng, RngExt};
+
+use crate::{Chunk, Chunks};
+
+/// An iterator that yields up to `k` uniformly sampled elements from every
+/// chunk of `n` elements.
+///
+/// This `struct` is created by [`sample_chunks`] method on [`IterChunks`]. See
+/// its documentation for more.
+///
+/// [`sample_chunks`]: crate::IterChunks::sample_chunks
+/// [`IterChunks`]: crate::IterChunks
+#[derive(Debug, Clone)]
+pub struct SampleChunks<I, R> {
+    inner: I,
+    n: usize,
+    k: usize,
+    rng: R,
+}
+
+impl<I, R> SampleChunks<I, R> {
+    pub(crate) fn new(inner: I, n: usize, k: usize, rng: R) -> Self {
+        assert_ne!(n, 0);
+        Self { inner, n, k, rng }
+    }
+}
+
+impl<I, R> Iterator for SampleChunks<I, R>
+where
+    I: Iterator,
+    R: Rng,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.inner.next()?;
+        let mut reservoir = Vec::with_capacity(self.k.min(self.n));
+        if self.k > 0 {
+            reservoir.push(first);
+        }
+        // Algorithm R: the i-th element replaces a random slot with
+        // probability k / (i + 1).
+        for i in 1..self.n {
+            let Some(v) = self.inner.next() else {
+                break;
+            };
+            if i < self.k {
+                reservoir.push(v);
+            } else {
+                let j = self.rng.random_range(0..=i);
+                if j < self.k {
+                    reservoir[j] = v;
+                }
+            }
+        }
+        Some(reservoir)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        (lower.div_ceil(self.n), upper.map(|v| v.div_ceil(self.n)))
+    }
+}
+
+impl<I: Iterator> Chunks<I> {
+    /// Keeps every chunk with probability `p`, independently, and skips the
+    /// others.
+    ///
+    /// The elements of the skipped chunks are discarded lazily, with the
+    /// same bulk skipping as [`Chunks::skip_chunks`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` is not in `0.0..=1.0`.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    /// use rand::rngs::SmallRng;
+    /// use rand::SeedableRng;
+    ///
+    /// let rng = SmallRng::seed_from_u64(0);
+    /// let mut audited = 0;
+    /// (0..1000)
+    ///     .chunks(10)
+    ///     .sample_fraction(0.1, rng)
+    ///     .for_each(|chunk| {
+    ///         assert_eq!(chunk.count(), 10);
+    ///         audited += 1;
+    ///     });
+    /// assert!(audited < 100);
+    /// ```
+    pub fn sample_fraction<R: Rng>(self, p: f64, rng: R) -> SampleFraction<I, R> {
+        assert!((0.0..=1.0).contains(&p), "p must be in 0.0..=1.0");
+        SampleFraction {
+            chunks: self,
+            p,
+            rng,
+        }
+    }
+}
+
+/// An iterator-like struct that keeps every chunk with a probability.
+///
+/// This `struct` is created by [`Chunks::sample_fraction`]. See its
+/// documentation for more.
+pub struct SampleFraction<I: Iterator, R> {
+    chunks: Chunks<I>,
+    p: f64,
+    rng: R,
+}
+
+impl<I: Iterator, R: Rng> SampleFraction<I, R> {
+    /// Similar to [`Iterator::next`], but not implements [`Iterator`] due to
+    /// lifetime.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<Chunk<'_, I>> {
+        let n = self.chunks.chunk_size();
+        while !self.rng.random_bool(self.p) {
+            if self.chunks.skip_chunks(1) < n {
+                // The end is reported by `Chunks::next`.
+                break;
+            }
+        }
+        self.chunks.next()
+    }
+
+    /// Similar to [`Iterator::for_each`].
+    pub fn for_each(&mut self, mut f: impl FnMut(Chunk<'_, I>)) {
+        while let Some(item) = self.next() {
+            f(item)
+        }
+    }
+
+    /// Consumes the adaptor and returns the underlying [`Chunks`].
+    pub fn into_inner(self) -> Chunks<I> {
+        self.chunks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    use crate::IterChunks;
+
+    #[test]
+    fn test_sample_chunks() {
+        let rng = SmallRng::seed_from_u64(42);
+        let samples = (0..25).sample_chunks(10, 3, rng).collect::<Vec<_>>();
+        assert_eq!(samples.len(), 3);
+        for (i, sample) in samples.iter().enumerate() {
+            assert_eq!(sample.len(), 3);
+            for v in sample {
+                assert_eq!(v / 10, i as i32);
+            }
+        }
+
+        // Short chunks and large `k` keep every element.
+        let rng = SmallRng::seed_from_u64(42);
+        let samples = (0..5).sample_chunks(3, 4, rng).collect::<Vec<_>>();
+        assert_eq!(samples, vec![vec![0, 1, 2], vec![3, 4]]);
+
+        let rng = SmallRng::seed_from_u64(42);
+        let samples = (0..5).sample_chunks(3, 0, rng).collect::<Vec<_>>();
+        assert_eq!(samples, vec![vec![], vec![]]);
+    }
+
+    #[test]
+    fn test_sample_chunks_uniform() {
+        let mut rng = SmallRng::seed_from_u64(7);
+        let mut hits = [0usize; 4];
+        for _ in 0..4000 {
+            for sample in (0..4).sample_chunks(4, 1, &mut rng) {
+                hits[sample[0]] += 1;
+            }
+        }
+        for hit in hits {
+            assert!((800..1200).contains(&hit), "{hits:?}");
+        }
+    }
+
+    #[test]
+    fn test_sample_fraction() {
+        let mut rng = SmallRng::seed_from_u64(42);
+        let mut chunks = (0..10).chunks(3).sample_fraction(0.0, &mut rng);
+        assert!(chunks.next().is_none());
+        assert_eq!(chunks.into_inner().elements_consumed(), 10);
+
+        let mut res = vec![];
+        (0..5)
+            .chunks(2)
+            .sample_fraction(1.0, &mut rng)
+            .for_each(|chunk| res.push(chunk.collect::<Vec<_>>()));
+        assert_eq!(res, vec![vec![0, 1], vec![2, 3], vec![4]]);
+
+        // The kept chunks are whole and aligned.
+        let mut kept = 0;
+        (0..100_000)
+            .chunks(10)
+            .sample_fraction(0.25, &mut rng)
+            .for_each(|chunk| {
+                let chunk = chunk.collect::<Vec<_>>();
+                assert_eq!(chunk.len(), 10);
+                assert_eq!(chunk[0] % 10, 0);
+                kept += 1;
+            });
+        assert!((2250..2750).contains(&kept), "{kept}");
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{Chunk, Chunks};
+
+impl<I: Iterator> Chunks<I> {
+    /// Yields every `k`-th chunk, starting with the first one, and skips the
+    /// others.
+    ///
+    /// The elements of the skipped chunks are discarded lazily, with the
+    /// same bulk skipping as [`Chunks::skip_chunks`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is 0.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut res = vec![];
+    /// (0..10)
+    ///     .chunks(2)
+    ///     .sample_every(3)
+    ///     .for_each(|chunk| res.push(chunk.collect::<Vec<_>>()));
+    /// assert_eq!(res, vec![vec![0, 1], vec![6, 7]]);
+    /// ```
+    pub fn sample_every(self, k: usize) -> SampleEvery<I> {
+        assert_ne!(k, 0);
+        SampleEvery {
+            chunks: self,
+            k,
+            started: false,
+        }
+    }
+}
+
+/// An iterator-like struct that yields every `k`-th chunk.
+///
+/// This `struct` is created by [`Chunks::sample_every`]. See its
+/// documentation for more.
+pub struct SampleEvery<I: Iterator> {
+    chunks: Chunks<I>,
+    k: usize,
+    // Whether a chunk was yielded, so that the next ones are skipped first.
+    started: bool,
+}
+
+impl<I: Iterator> SampleEvery<I> {
+    /// Similar to [`Iterator::next`], but not implements [`Iterator`] due to
+    /// lifetime.
+    ///
+    /// The `k - 1` chunks after the previous one are skipped first. The rest
+    /// of the previous chunk, if not consumed, counts towards them.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<Chunk<'_, I>> {
+        if std::mem::replace(&mut self.started, true) {
+            self.chunks.skip_chunks(self.k - 1);
+        }
+        self.chunks.next()
+    }
+
+    /// Similar to [`Iterator::for_each`].
+    pub fn for_each(&mut self, mut f: impl FnMut(Chunk<'_, I>)) {
+        while let Some(item) = self.next() {
+            f(item)
+        }
+    }
+
+    /// Consumes the adaptor and returns the underlying [`Chunks`].
+    pub fn into_inner(self) -> Chunks<I> {
+        self.chunks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IterChunks;
+
+    #[test]
+    fn test_sample_every() {
+        let mut chunks = (0..11).chunks(2).sample_every(2);
+        let mut res = vec![];
+        while let Some(chunk) = chunks.next() {
+            res.push(chunk.collect::<Vec<_>>());
+        }
+        assert_eq!(res, vec![vec![0, 1], vec![4, 5], vec![8, 9]]);
+        assert_eq!(chunks.into_inner().elements_consumed(), 11);
+
+        // Every chunk is kept with `k` of 1.
+        let mut count = 0;
+        (0..5).chunks(2).sample_every(1).for_each(|chunk| {
+            chunk.for_each(drop);
+            count += 1;
+        });
+        assert_eq!(count, 3);
+
+        // Over a large range, the skipped chunks are not pulled one by one.
+        let mut chunks = (0..u64::MAX).chunks(1 << 20).sample_every(1 << 30);
+        chunks.next().unwrap().for_each(drop);
+        assert_eq!(chunks.next().unwrap().next(), Some(1 << 50));
+    }
+}
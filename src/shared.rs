@@ -0,0 +1,257 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+/// An iterator that yields every chunk as an [`Iterator`] sharing the
+/// underlying iterator.
+///
+/// This `struct` is created by [`shared_chunks`] method on [`IterChunks`].
+/// See its documentation for more.
+///
+/// [`shared_chunks`]: crate::IterChunks::shared_chunks
+/// [`IterChunks`]: crate::IterChunks
+pub struct SharedChunks<I: Iterator> {
+    shared: Arc<Mutex<Shared<I>>>,
+    // The index of the next chunk to yield.
+    index: usize,
+}
+
+impl<I: Iterator> SharedChunks<I> {
+    pub(crate) fn new(inner: I, n: usize) -> Self {
+        assert_ne!(n, 0);
+        let shared = Shared {
+            inner,
+            n,
+            pos: 0,
+            done: false,
+            bufs: VecDeque::new(),
+            buf_start: 0,
+        };
+        Self {
+            shared: Arc::new(Mutex::new(shared)),
+            index: 0,
+        }
+    }
+}
+
+impl<I: Iterator> Iterator for SharedChunks<I> {
+    type Item = SharedChunk<I>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.index;
+        if !lock(&self.shared).has_chunk(index) {
+            return None;
+        }
+        self.index += 1;
+        Some(SharedChunk {
+            shared: self.shared.clone(),
+            index,
+        })
+    }
+}
+
+/// An iterator over a chunk of data, sharing the underlying iterator with the
+/// other chunks.
+///
+/// This `struct` is created by [`SharedChunks`].
+pub struct SharedChunk<I: Iterator> {
+    shared: Arc<Mutex<Shared<I>>>,
+    index: usize,
+}
+
+impl<I: Iterator> Iterator for SharedChunk<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        lock(&self.shared).next_of(self.index)
+    }
+}
+
+impl<I: Iterator> Drop for SharedChunk<I> {
+    fn drop(&mut self) {
+        lock(&self.shared).drop_chunk(self.index);
+    }
+}
+
+/// The state shared by [`SharedChunks`] and all its chunks.
+struct Shared<I: Iterator> {
+    inner: I,
+    n: usize,
+    // The number of elements pulled from `inner`. The element at `pos`
+    // belongs to the chunk `pos / n`.
+    pos: usize,
+    done: bool,
+    // The elements pulled for every chunk from `buf_start` but not yielded
+    // yet, or `None` if the chunk was dropped.
+    bufs: VecDeque<Option<VecDeque<I::Item>>>,
+    buf_start: usize,
+}
+
+impl<I: Iterator> Shared<I> {
+    /// Pulls the next element with the index of its chunk.
+    fn pull(&mut self) -> Option<(usize, I::Item)> {
+        if self.done {
+            return None;
+        }
+        match self.inner.next() {
+            Some(v) => {
+                let index = self.pos / self.n;
+                self.pos += 1;
+                Some((index, v))
+            }
+            None => {
+                self.done = true;
+                None
+            }
+        }
+    }
+
+    /// Returns the buffer of the chunk `index`, creating it if necessary.
+    /// Returns `None` if the chunk was dropped or is already fully consumed.
+    fn buf_mut(&mut self, index: usize) -> Option<&mut VecDeque<I::Item>> {
+        let k = index.checked_sub(self.buf_start)?;
+        while self.bufs.len() <= k {
+            self.bufs.push_back(Some(VecDeque::new()));
+        }
+        self.bufs[k].as_mut()
+    }
+
+    /// Whether the chunk `index` has at least one element, pulling and
+    /// buffering the elements before it.
+    fn has_chunk(&mut self, index: usize) -> bool {
+        let start = index.saturating_mul(self.n);
+        while self.pos <= start {
+            let Some((i, v)) = self.pull() else {
+                return false;
+            };
+            if let Some(buf) = self.buf_mut(i) {
+                buf.push_back(v);
+            }
+        }
+        true
+    }
+
+    fn next_of(&mut self, index: usize) -> Option<I::Item> {
+        let v = self.buffered_or_pull(index);
+        self.trim();
+        v
+    }
+
+    fn buffered_or_pull(&mut self, index: usize) -> Option<I::Item> {
+        if let Some(v) = self.buf_mut(index).and_then(|buf| buf.pop_front()) {
+            return Some(v);
+        }
+        let end = index.saturating_add(1).saturating_mul(self.n);
+        while self.pos < end {
+            let (i, v) = self.pull()?;
+            if i == index {
+                return Some(v);
+            }
+            // An element of a previous chunk, which is still alive.
+            if let Some(buf) = self.buf_mut(i) {
+                buf.push_back(v);
+            }
+        }
+        None
+    }
+
+    fn drop_chunk(&mut self, index: usize) {
+        if let Some(k) = index.checked_sub(self.buf_start) {
+            self.buf_mut(index);
+            self.bufs[k] = None;
+        }
+        self.trim();
+    }
+
+    /// Removes the buffers that will never be used again.
+    fn trim(&mut self) {
+        while let Some(front) = self.bufs.front() {
+            let pulled = self.done || (self.buf_start + 1).saturating_mul(self.n) <= self.pos;
+            let unused = front.as_ref().is_none_or(|buf| buf.is_empty());
+            if !(pulled && unused) {
+                break;
+            }
+            self.bufs.pop_front();
+            self.buf_start += 1;
+        }
+    }
+}
+
+/// Locks the shared state. A panic while it's locked, e.g. in the underlying
+/// iterator, leaves it consistent, so poisoning is ignored.
+fn lock<I: Iterator>(shared: &Mutex<Shared<I>>) -> MutexGuard<'_, Shared<I>> {
+    shared.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IterChunks;
+
+    #[test]
+    fn test_impls() {
+        let mut chunks = [0i32].into_iter().shared_chunks(1);
+        let chunk = chunks.next().unwrap();
+
+        // A helper function that asserts a type impl Send.
+        fn assert_send<T: Send>(_: &T) {}
+        // A helper function that asserts a type impl Sync.
+        fn assert_sync<T: Sync>(_: &T) {}
+
+        assert_send(&chunks);
+        assert_sync(&chunks);
+        assert_send(&chunk);
+        assert_sync(&chunk);
+    }
+
+    #[test]
+    fn test_shared_chunks() {
+        let chunks = (0..7).shared_chunks(3);
+        let res = chunks
+            .map(|chunk| chunk.collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(res, vec![vec![0, 1, 2], vec![3, 4, 5], vec![6]]);
+
+        assert_eq!(std::iter::empty::<i32>().shared_chunks(3).count(), 0);
+    }
+
+    #[test]
+    fn test_shared_chunks_out_of_order() {
+        let mut chunks = (0..8).shared_chunks(3);
+        let mut first = chunks.next().unwrap();
+        assert_eq!(first.next(), Some(0));
+        let mut second = chunks.next().unwrap();
+        let third = chunks.next().unwrap();
+        assert!(chunks.next().is_none());
+
+        // The rest of the first chunk is buffered.
+        assert_eq!(third.collect::<Vec<_>>(), vec![6, 7]);
+        assert_eq!(first.collect::<Vec<_>>(), vec![1, 2]);
+        // A dropped chunk doesn't lose the elements of the others.
+        assert_eq!(second.next(), Some(3));
+        drop(second);
+    }
+
+    #[test]
+    fn test_shared_chunks_dropped() {
+        // Not collecting a chunk skips it, like itertools.
+        let sums = (0..10)
+            .shared_chunks(3)
+            .enumerate()
+            .filter(|(i, _)| i % 2 == 0)
+            .map(|(_, chunk)| chunk.sum::<i32>())
+            .collect::<Vec<_>>();
+        assert_eq!(sums, vec![3, 21]);
+    }
+
+    #[test]
+    fn test_shared_chunks_send() {
+        let mut chunks = (0..6).shared_chunks(2);
+        let first = chunks.next().unwrap();
+        let handle = std::thread::spawn(move || first.collect::<Vec<_>>());
+        let rest = chunks.flatten().collect::<Vec<_>>();
+        let first = handle.join().unwrap();
+        assert_eq!(first.len() + rest.len(), 6);
+        let mut all = [first, rest].concat();
+        all.sort();
+        assert_eq!(all, (0..6).collect::<Vec<_>>());
+    }
+}
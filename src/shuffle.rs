@@ -0,0 +1,116 @@
+use rand::{Rng, RngExt};
+
+/// An iterator that yields randomized batches of `n` elements drawn from a
+/// shuffle buffer.
+///
+/// This `struct` is created by [`shuffled_chunks`] method on [`IterChunks`].
+/// See its documentation for more.
+///
+/// [`shuffled_chunks`]: crate::IterChunks::shuffled_chunks
+/// [`IterChunks`]: crate::IterChunks
+#[derive(Debug, Clone)]
+pub struct ShuffledChunks<I: Iterator, R> {
+    inner: I,
+    buf: Vec<I::Item>,
+    buffer_size: usize,
+    n: usize,
+    rng: R,
+}
+
+impl<I: Iterator, R> ShuffledChunks<I, R> {
+    pub(crate) fn new(inner: I, buffer_size: usize, n: usize, rng: R) -> Self {
+        assert_ne!(buffer_size, 0);
+        assert_ne!(n, 0);
+        Self {
+            inner,
+            buf: Vec::with_capacity(buffer_size),
+            buffer_size,
+            n,
+            rng,
+        }
+    }
+}
+
+impl<I: Iterator, R: Rng> ShuffledChunks<I, R> {
+    fn next_item(&mut self) -> Option<I::Item> {
+        // Refill the buffer. After the first fill, this pulls at most one
+        // element per call.
+        while self.buf.len() < self.buffer_size {
+            match self.inner.next() {
+                Some(v) => self.buf.push(v),
+                None => break,
+            }
+        }
+        if self.buf.is_empty() {
+            return None;
+        }
+        let i = self.rng.random_range(0..self.buf.len());
+        Some(self.buf.swap_remove(i))
+    }
+}
+
+impl<I: Iterator, R: Rng> Iterator for ShuffledChunks<I, R> {
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.next_item()?;
+        let mut chunk = Vec::with_capacity(self.n);
+        chunk.push(first);
+        while chunk.len() < self.n {
+            match self.next_item() {
+                Some(v) => chunk.push(v),
+                None => break,
+            }
+        }
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        let buffered = self.buf.len();
+        let lower = lower.saturating_add(buffered).div_ceil(self.n);
+        let upper = upper
+            .and_then(|v| v.checked_add(buffered))
+            .map(|v| v.div_ceil(self.n));
+        (lower, upper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    use crate::IterChunks;
+
+    #[test]
+    fn test_shuffled_chunks() {
+        let rng = SmallRng::seed_from_u64(42);
+        let chunks = (0..10).shuffled_chunks(4, 3, rng).collect::<Vec<_>>();
+        assert_eq!(
+            chunks.iter().map(Vec::len).collect::<Vec<_>>(),
+            vec![3, 3, 3, 1]
+        );
+
+        let mut all = chunks.concat();
+        assert_ne!(all, (0..10).collect::<Vec<_>>());
+        all.sort();
+        assert_eq!(all, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_shuffled_chunks_buffer_bound() {
+        // With a buffer of size 2, the i-th output element must come from the
+        // first i + 2 input elements.
+        let rng = SmallRng::seed_from_u64(1);
+        let all = (0..100).shuffled_chunks(2, 7, rng).flatten();
+        for (i, v) in all.enumerate() {
+            assert!(v < i + 2, "{v} at {i}");
+        }
+
+        // A buffer of size 1 keeps the original order.
+        let rng = SmallRng::seed_from_u64(1);
+        let chunks = (0..5).shuffled_chunks(1, 2, rng).collect::<Vec<_>>();
+        assert_eq!(chunks, vec![vec![0, 1], vec![2, 3], vec![4]]);
+    }
+}
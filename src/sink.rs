@@ -0,0 +1,192 @@
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_util::sink::Sink;
+
+/// A [`Sink`] that buffers items and forwards them to an inner sink in
+/// batches of `n`.
+///
+/// This is the push-side counterpart of [`StreamChunks`]. A partial batch is
+/// forwarded on `poll_flush` and `poll_close`, so no item is held back when
+/// the sink is flushed.
+///
+/// ```
+/// use futures::SinkExt;
+/// use iter_chunks::ChunkedSink;
+///
+/// futures::executor::block_on(async {
+///     let mut sink = ChunkedSink::new(Vec::<Vec<i32>>::new(), 2);
+///     for i in 0..5 {
+///         sink.feed(i).await.unwrap();
+///     }
+///     sink.close().await.unwrap();
+///     assert_eq!(sink.into_inner(), vec![vec![0, 1], vec![2, 3], vec![4]]);
+/// });
+/// ```
+///
+/// [`StreamChunks`]: crate::StreamChunks
+#[derive(Debug)]
+pub struct ChunkedSink<Si, T> {
+    inner: Si,
+    buf: Vec<T>,
+    n: usize,
+}
+
+// The buffered items are never pinned.
+impl<Si: Unpin, T> Unpin for ChunkedSink<Si, T> {}
+
+impl<Si, T> ChunkedSink<Si, T> {
+    /// Creates a sink that forwards batches of `n` items to `inner`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    pub fn new(inner: Si, n: usize) -> Self {
+        assert_ne!(n, 0);
+        Self {
+            inner,
+            buf: Vec::with_capacity(n),
+            n,
+        }
+    }
+
+    /// Returns the configured chunk size `n`.
+    pub fn chunk_size(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the items buffered for the next batch.
+    pub fn buffered(&self) -> &[T] {
+        &self.buf
+    }
+
+    /// Acquires a reference to the inner sink.
+    pub fn get_ref(&self) -> &Si {
+        &self.inner
+    }
+
+    /// Acquires a mutable reference to the inner sink.
+    pub fn get_mut(&mut self) -> &mut Si {
+        &mut self.inner
+    }
+
+    /// Consumes the wrapper and returns the inner sink.
+    ///
+    /// Buffered items that were not flushed are dropped.
+    pub fn into_inner(self) -> Si {
+        self.inner
+    }
+}
+
+impl<Si, T> ChunkedSink<Si, T>
+where
+    Si: Sink<Vec<T>> + Unpin,
+{
+    /// Forwards the buffered items as a batch, if any.
+    fn poll_send_buf(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Si::Error>> {
+        if self.buf.is_empty() {
+            return Poll::Ready(Ok(()));
+        }
+        match Pin::new(&mut self.inner).poll_ready(cx) {
+            Poll::Ready(Ok(())) => {}
+            other => return other,
+        }
+        let batch = std::mem::replace(&mut self.buf, Vec::with_capacity(self.n));
+        Poll::Ready(Pin::new(&mut self.inner).start_send(batch))
+    }
+}
+
+impl<Si, T> Sink<T> for ChunkedSink<Si, T>
+where
+    Si: Sink<Vec<T>> + Unpin,
+{
+    type Error = Si::Error;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.get_mut();
+        if this.buf.len() < this.n {
+            return Poll::Ready(Ok(()));
+        }
+        this.poll_send_buf(cx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, item: T) -> Result<(), Self::Error> {
+        self.get_mut().buf.push(item);
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.get_mut();
+        match this.poll_send_buf(cx) {
+            Poll::Ready(Ok(())) => {}
+            other => return other,
+        }
+        Pin::new(&mut this.inner).poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        let this = self.get_mut();
+        match this.poll_send_buf(cx) {
+            Poll::Ready(Ok(())) => {}
+            other => return other,
+        }
+        Pin::new(&mut this.inner).poll_close(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::channel::mpsc;
+    use futures::executor::block_on;
+    use futures::{SinkExt, StreamExt};
+
+    use super::ChunkedSink;
+
+    #[test]
+    fn test_chunked_sink() {
+        block_on(async {
+            let (tx, rx) = mpsc::unbounded();
+            let mut sink = ChunkedSink::new(tx, 3);
+            for i in 0..4 {
+                sink.feed(i).await.unwrap();
+            }
+            // A full batch is only forwarded when the next item comes.
+            assert_eq!(sink.buffered(), [3]);
+            sink.flush().await.unwrap();
+            assert!(sink.buffered().is_empty());
+            sink.send_all(&mut futures::stream::iter([4, 5].map(Ok)))
+                .await
+                .unwrap();
+            sink.close().await.unwrap();
+            drop(sink);
+
+            let batches = rx.collect::<Vec<_>>().await;
+            assert_eq!(batches, vec![vec![0, 1, 2], vec![3], vec![4, 5]]);
+        });
+    }
+
+    #[test]
+    fn test_chunked_sink_backpressure() {
+        block_on(async {
+            // A bounded channel with no extra capacity.
+            let (tx, mut rx) = mpsc::channel(0);
+            let mut sink = ChunkedSink::new(tx, 2);
+            let producer = async {
+                for i in 0..5 {
+                    sink.send(i).await.unwrap();
+                }
+                sink.close().await.unwrap();
+            };
+            let consumer = async {
+                let mut batches = vec![];
+                while let Some(batch) = rx.next().await {
+                    batches.push(batch);
+                }
+                batches
+            };
+            let ((), batches) = futures::join!(producer, consumer);
+            // `send` flushes after every item.
+            assert_eq!(batches, vec![vec![0], vec![1], vec![2], vec![3], vec![4]]);
+        });
+    }
+}
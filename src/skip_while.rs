@@ -0,0 +1,103 @@
+use crate::{Chunk, Chunks};
+
+/// An iterator-like struct that skips chunks while a predicate holds.
+///
+/// This `struct` is created by [`Chunks::skip_while_chunks`]. See its
+/// documentation for more.
+pub struct SkipWhileChunks<I: Iterator, P> {
+    chunks: Chunks<I>,
+    predicate: Option<P>,
+    buf: Vec<I::Item>,
+}
+
+impl<I, P> SkipWhileChunks<I, P>
+where
+    I: Iterator,
+    P: FnMut(&[I::Item]) -> bool,
+{
+    pub(crate) fn new(chunks: Chunks<I>, predicate: P) -> Self {
+        Self {
+            chunks,
+            predicate: Some(predicate),
+            buf: Vec::new(),
+        }
+    }
+
+    /// Similar to [`Iterator::next`], but not implements [`Iterator`] due to
+    /// lifetime.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<Chunk<'_, I>> {
+        if let Some(mut predicate) = self.predicate.take() {
+            while self.chunks.next_buffered(&mut self.buf) {
+                if !predicate(&self.buf) {
+                    // Give the boundary chunk back, so that it's yielded as is.
+                    self.chunks.unread(&mut self.buf);
+                    self.buf = Vec::new();
+                    return self.chunks.next();
+                }
+            }
+            self.buf = Vec::new();
+            return None;
+        }
+        self.chunks.next()
+    }
+
+    /// Similar to [`Iterator::for_each`].
+    pub fn for_each(&mut self, mut f: impl FnMut(Chunk<'_, I>)) {
+        while let Some(item) = self.next() {
+            f(item)
+        }
+    }
+
+    /// Consumes the adaptor and returns the underlying [`Chunks`].
+    ///
+    /// A chunk that failed the predicate but has not been yielded yet stays
+    /// buffered in the returned [`Chunks`].
+    pub fn into_inner(self) -> Chunks<I> {
+        self.chunks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IterChunks;
+
+    #[test]
+    fn test_skip_while_chunks() {
+        let arr = [0, 0, 0, 0, 1, 0, 2, 3, 0];
+        let mut chunks = arr
+            .into_iter()
+            .chunks(2)
+            .skip_while_chunks(|chunk| chunk.iter().all(|&v| v == 0));
+        let mut res = vec![];
+        while let Some(chunk) = chunks.next() {
+            res.push(chunk.collect::<Vec<_>>());
+        }
+        assert_eq!(res, vec![vec![1, 0], vec![2, 3], vec![0]]);
+
+        // The boundary chunk can be short.
+        let mut res = vec![];
+        (0..5)
+            .chunks(2)
+            .skip_while_chunks(|chunk| chunk.len() == 2)
+            .for_each(|chunk| res.push(chunk.collect::<Vec<_>>()));
+        assert_eq!(res, vec![vec![4]]);
+
+        // All chunks are skipped.
+        let mut chunks = (0..5).chunks(2).skip_while_chunks(|_| true);
+        assert!(chunks.next().is_none());
+        assert!(chunks.next().is_none());
+    }
+
+    #[test]
+    fn test_skip_while_chunks_partial() {
+        let mut chunks = (0..6).chunks(3).skip_while_chunks(|chunk| chunk[0] == 0);
+        let mut chunk = chunks.next().unwrap();
+        assert_eq!(chunk.size_hint(), (3, Some(3)));
+        assert_eq!(chunk.next(), Some(3));
+
+        let mut inner = chunks.into_inner();
+        assert_eq!(inner.next().unwrap().collect::<Vec<_>>(), vec![4, 5]);
+        assert!(inner.next().is_none());
+    }
+}
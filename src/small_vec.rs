@@ -0,0 +1,92 @@
+use smallvec::SmallVec;
+
+use crate::{Chunk, Chunks};
+
+impl<I: Iterator> Chunk<'_, I> {
+    /// Collects the rest of the chunk into a [`SmallVec`], which stays on the
+    /// stack while the chunk has at most `N` elements.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut chunks = (0..10).chunks(4);
+    /// let chunk = chunks.next().unwrap().collect_smallvec::<4>();
+    /// assert!(!chunk.spilled());
+    /// assert_eq!(chunk.as_slice(), &[0, 1, 2, 3]);
+    /// ```
+    pub fn collect_smallvec<const N: usize>(self) -> SmallVec<[I::Item; N]> {
+        self.collect()
+    }
+}
+
+impl<I: Iterator> Chunks<I> {
+    /// Converts into an [`Iterator`] that yields every chunk as a
+    /// [`SmallVec`].
+    ///
+    /// With `N` no less than the chunk size, no chunk is allocated on the
+    /// heap.
+    ///
+    /// ```
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let chunks = (0..10).chunks(4).into_smallvecs::<4>();
+    /// let sums = chunks.map(|c| c.iter().sum::<i32>()).collect::<Vec<_>>();
+    /// assert_eq!(sums, vec![6, 22, 17]);
+    /// ```
+    pub fn into_smallvecs<const N: usize>(self) -> IntoSmallVecs<I, N> {
+        IntoSmallVecs { chunks: self }
+    }
+}
+
+/// An iterator that yields every chunk as a [`SmallVec`].
+///
+/// This `struct` is created by [`Chunks::into_smallvecs`]. See its
+/// documentation for more.
+pub struct IntoSmallVecs<I: Iterator, const N: usize> {
+    chunks: Chunks<I>,
+}
+
+impl<I: Iterator, const N: usize> IntoSmallVecs<I, N> {
+    /// Consumes the adaptor and returns the underlying [`Chunks`].
+    pub fn into_inner(self) -> Chunks<I> {
+        self.chunks
+    }
+}
+
+impl<I: Iterator, const N: usize> Iterator for IntoSmallVecs<I, N> {
+    type Item = SmallVec<[I::Item; N]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.chunks.next().map(Chunk::collect_smallvec)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IterChunks;
+
+    #[test]
+    fn test_collect_smallvec() {
+        let mut chunks = (0..5).chunks(3);
+        let mut chunk = chunks.next().unwrap();
+        chunk.next();
+        let v = chunk.collect_smallvec::<2>();
+        assert!(!v.spilled());
+        assert_eq!(v.as_slice(), &[1, 2]);
+
+        let v = chunks.next().unwrap().collect_smallvec::<1>();
+        assert!(v.spilled());
+        assert_eq!(v.as_slice(), &[3, 4]);
+    }
+
+    #[test]
+    fn test_into_smallvecs() {
+        let res = (0..7)
+            .chunks(3)
+            .into_smallvecs::<3>()
+            .inspect(|v| assert!(!v.spilled()))
+            .map(|v| v.into_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(res, vec![vec![0, 1, 2], vec![3, 4, 5], vec![6]]);
+    }
+}
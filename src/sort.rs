@@ -0,0 +1,80 @@
+use std::cmp::Ordering;
+
+/// An iterator that yields every chunk of `n` elements as a sorted `Vec`.
+///
+/// This `struct` is created by [`sorted_chunks`] and [`sorted_chunks_by`]
+/// methods on [`IterChunks`]. See their documentation for more.
+///
+/// [`sorted_chunks`]: crate::IterChunks::sorted_chunks
+/// [`sorted_chunks_by`]: crate::IterChunks::sorted_chunks_by
+/// [`IterChunks`]: crate::IterChunks
+#[derive(Debug, Clone)]
+pub struct SortedChunks<I, F> {
+    inner: I,
+    n: usize,
+    compare: F,
+}
+
+impl<I, F> SortedChunks<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item, &I::Item) -> Ordering,
+{
+    pub(crate) fn new(inner: I, n: usize, compare: F) -> Self {
+        assert_ne!(n, 0);
+        Self { inner, n, compare }
+    }
+
+    /// Consumes the adaptor and returns the underlying iterator.
+    pub fn into_inner(self) -> I {
+        self.inner
+    }
+}
+
+impl<I, F> Iterator for SortedChunks<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item, &I::Item) -> Ordering,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.inner.next()?;
+        let len = self.inner.size_hint().0.min(self.n - 1) + 1;
+        let mut chunk = Vec::with_capacity(len);
+        chunk.push(first);
+        chunk.extend(self.inner.by_ref().take(self.n - 1));
+        chunk.sort_by(&mut self.compare);
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        (lower.div_ceil(self.n), upper.map(|v| v.div_ceil(self.n)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IterChunks;
+
+    #[test]
+    fn test_sorted_chunks() {
+        let runs = [5, 3, 9, 1, 4, 8, 2].into_iter().sorted_chunks(3);
+        assert_eq!(runs.size_hint(), (3, Some(3)));
+        assert_eq!(
+            runs.collect::<Vec<_>>(),
+            vec![vec![3, 5, 9], vec![1, 4, 8], vec![2]]
+        );
+
+        // The sort is stable.
+        let rows = [(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')];
+        let runs = rows
+            .into_iter()
+            .sorted_chunks_by(4, |a, b| a.0.cmp(&b.0))
+            .collect::<Vec<_>>();
+        assert_eq!(runs, vec![vec![(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')]]);
+
+        assert!(std::iter::empty::<i32>().sorted_chunks(2).next().is_none());
+    }
+}
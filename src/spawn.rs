@@ -0,0 +1,200 @@
+use std::collections::VecDeque;
+use std::future::Future;
+use std::panic;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+
+use futures_core::Stream;
+use tokio::task::JoinHandle;
+
+use crate::Chunks;
+
+impl<I: Iterator> Chunks<I> {
+    /// Spawns a tokio task with the future returned by `f` for every chunk,
+    /// keeping up to `limit` tasks in flight, and returns a [`Stream`] of
+    /// their results in chunk order.
+    ///
+    /// Every chunk is collected into a `Vec` and passed to `f`. The tasks run
+    /// concurrently on the runtime, but a result is only yielded after the
+    /// results of the previous chunks, and a new chunk is only pulled once a
+    /// result is yielded, so a slow consumer holds back the source. If a task
+    /// panics, the panic is resumed when its result is polled. The tasks in
+    /// flight are aborted when the stream is dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `limit` is 0. Polling the stream panics outside a tokio
+    /// runtime.
+    ///
+    /// ```
+    /// use futures::StreamExt;
+    /// use iter_chunks::IterChunks;
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().enable_time().build().unwrap().block_on(async {
+    /// let sums = (0..10)
+    ///     .chunks(3)
+    ///     .spawn_chunks(2, |chunk| async move { chunk.iter().sum::<i32>() })
+    ///     .collect::<Vec<_>>()
+    ///     .await;
+    /// assert_eq!(sums, vec![3, 12, 21, 9]);
+    /// # });
+    /// ```
+    pub fn spawn_chunks<F, Fut>(self, limit: usize, f: F) -> SpawnChunks<I, F, Fut::Output>
+    where
+        F: FnMut(Vec<I::Item>) -> Fut,
+        Fut: Future + Send + 'static,
+        Fut::Output: Send + 'static,
+    {
+        assert_ne!(limit, 0);
+        SpawnChunks {
+            chunks: self,
+            f,
+            limit,
+            tasks: VecDeque::with_capacity(limit),
+            source_done: false,
+        }
+    }
+}
+
+/// A stream that yields the results of the tasks spawned for every chunk, in
+/// chunk order.
+///
+/// This `struct` is created by [`Chunks::spawn_chunks`]. See its
+/// documentation for more.
+pub struct SpawnChunks<I: Iterator, F, T> {
+    chunks: Chunks<I>,
+    f: F,
+    limit: usize,
+    // The tasks in flight or whose results are not yielded yet, in chunk
+    // order.
+    tasks: VecDeque<JoinHandle<T>>,
+    source_done: bool,
+}
+
+// Neither the chunks nor the join handles are pinned.
+impl<I: Iterator, F, T> Unpin for SpawnChunks<I, F, T> {}
+
+impl<I, F, Fut> SpawnChunks<I, F, Fut::Output>
+where
+    I: Iterator,
+    F: FnMut(Vec<I::Item>) -> Fut,
+    Fut: Future + Send + 'static,
+    Fut::Output: Send + 'static,
+{
+    /// Returns the number of tasks in flight or whose results are not
+    /// yielded yet.
+    pub fn in_flight(&self) -> usize {
+        self.tasks.len()
+    }
+
+    fn spawn_tasks(&mut self) {
+        while !self.source_done && self.tasks.len() < self.limit {
+            let mut chunk = Vec::with_capacity(self.chunks.n);
+            if self.chunks.next_buffered(&mut chunk) {
+                self.tasks.push_back(tokio::spawn((self.f)(chunk)));
+            } else {
+                self.source_done = true;
+            }
+        }
+    }
+}
+
+impl<I, F, Fut> Stream for SpawnChunks<I, F, Fut::Output>
+where
+    I: Iterator,
+    F: FnMut(Vec<I::Item>) -> Fut,
+    Fut: Future + Send + 'static,
+    Fut::Output: Send + 'static,
+{
+    type Item = Fut::Output;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        this.spawn_tasks();
+        let Some(task) = this.tasks.front_mut() else {
+            return Poll::Ready(None);
+        };
+        let res = ready!(Pin::new(task).poll(cx));
+        this.tasks.pop_front();
+        match res {
+            Ok(v) => {
+                // Start the next chunk before the result is processed.
+                this.spawn_tasks();
+                Poll::Ready(Some(v))
+            }
+            Err(e) if e.is_panic() => panic::resume_unwind(e.into_panic()),
+            Err(e) => panic!("chunk task failed: {e}"),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.source_done {
+            (self.tasks.len(), Some(self.tasks.len()))
+        } else {
+            (self.tasks.len(), None)
+        }
+    }
+}
+
+impl<I: Iterator, F, T> Drop for SpawnChunks<I, F, T> {
+    fn drop(&mut self) {
+        for task in &self.tasks {
+            task.abort();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use futures::StreamExt;
+    use tokio::time::sleep;
+
+    use crate::IterChunks;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_spawn_chunks_ordered() {
+        let running = Arc::new(AtomicUsize::new(0));
+        let max_running = Arc::new(AtomicUsize::new(0));
+        let mut results = (0..10).chunks(2).spawn_chunks(3, |chunk| {
+            let running = running.clone();
+            let max_running = max_running.clone();
+            async move {
+                let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                max_running.fetch_max(now, Ordering::SeqCst);
+                // Later chunks finish first.
+                sleep(Duration::from_millis(100 - chunk[0] as u64 * 10)).await;
+                running.fetch_sub(1, Ordering::SeqCst);
+                chunk
+            }
+        });
+        let mut res = vec![];
+        while let Some(chunk) = results.next().await {
+            assert!(results.in_flight() <= 3);
+            res.push(chunk);
+        }
+        assert_eq!(
+            res,
+            vec![vec![0, 1], vec![2, 3], vec![4, 5], vec![6, 7], vec![8, 9]]
+        );
+        assert_eq!(max_running.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_spawn_chunks_panic() {
+        let mut results = (0..6).chunks(2).spawn_chunks(2, |chunk| async move {
+            assert_ne!(chunk[0], 2, "bad chunk");
+            chunk.len()
+        });
+        assert_eq!(results.next().await, Some(2));
+        let res = tokio::spawn(async move { results.next().await }).await;
+        let payload = res.unwrap_err().into_panic();
+        assert!(payload
+            .downcast_ref::<String>()
+            .unwrap()
+            .contains("bad chunk"));
+    }
+}
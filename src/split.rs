@@ -0,0 +1,131 @@
+/// How delimiter-based adaptors handle empty chunks, e.g. between two
+/// consecutive delimiters.
+///
+/// A delimiter always terminates a chunk, so the elements after the last
+/// delimiter are yielded as a final chunk only if there are any.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmptyChunks {
+    /// Every delimiter yields a chunk, even an empty one, like
+    /// [`str::split_terminator`].
+    Yield,
+    /// Empty chunks are never yielded.
+    Skip,
+    /// A run of consecutive delimiters counts as one, so only a leading
+    /// delimiter yields an empty chunk.
+    Coalesce,
+}
+
+impl EmptyChunks {
+    /// Whether an empty chunk ended by a delimiter is yielded, given whether
+    /// the previous chunk was also ended by a delimiter.
+    pub(crate) fn keep(self, after_delimiter: bool) -> bool {
+        match self {
+            EmptyChunks::Yield => true,
+            EmptyChunks::Skip => false,
+            EmptyChunks::Coalesce => !after_delimiter,
+        }
+    }
+}
+
+/// An iterator that splits elements into chunks at the elements matching a
+/// predicate.
+///
+/// This `struct` is created by [`split_chunks`] method on [`IterChunks`]. See
+/// its documentation for more.
+///
+/// [`split_chunks`]: crate::IterChunks::split_chunks
+/// [`IterChunks`]: crate::IterChunks
+#[derive(Debug, Clone)]
+pub struct SplitChunks<I, P> {
+    inner: I,
+    predicate: P,
+    empty: EmptyChunks,
+    // Whether the last element pulled was a delimiter.
+    after_delimiter: bool,
+}
+
+impl<I, P> SplitChunks<I, P> {
+    pub(crate) fn new(inner: I, predicate: P) -> Self {
+        Self {
+            inner,
+            predicate,
+            empty: EmptyChunks::Yield,
+            after_delimiter: false,
+        }
+    }
+
+    /// Sets how empty chunks are handled. Defaults to [`EmptyChunks::Yield`].
+    ///
+    /// ```
+    /// use iter_chunks::{EmptyChunks, IterChunks};
+    ///
+    /// let fields = "|a||b|".bytes().split_chunks(|&b| b == b'|');
+    /// let lens = |empty| {
+    ///     fields
+    ///         .clone()
+    ///         .empty_chunks(empty)
+    ///         .map(|chunk| chunk.len())
+    ///         .collect::<Vec<_>>()
+    /// };
+    /// assert_eq!(lens(EmptyChunks::Yield), [0, 1, 0, 1]);
+    /// assert_eq!(lens(EmptyChunks::Skip), [1, 1]);
+    /// assert_eq!(lens(EmptyChunks::Coalesce), [0, 1, 1]);
+    /// ```
+    pub fn empty_chunks(mut self, empty: EmptyChunks) -> Self {
+        self.empty = empty;
+        self
+    }
+}
+
+impl<I, P> Iterator for SplitChunks<I, P>
+where
+    I: Iterator,
+    P: FnMut(&I::Item) -> bool,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chunk = Vec::new();
+        loop {
+            let Some(v) = self.inner.next() else {
+                return (!chunk.is_empty()).then_some(chunk);
+            };
+            if !(self.predicate)(&v) {
+                self.after_delimiter = false;
+                chunk.push(v);
+                continue;
+            }
+            let after_delimiter = std::mem::replace(&mut self.after_delimiter, true);
+            if !chunk.is_empty() || self.empty.keep(after_delimiter) {
+                return Some(chunk);
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.inner.size_hint().1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EmptyChunks;
+    use crate::IterChunks;
+
+    #[test]
+    fn test_split_chunks() {
+        let split = |s: &str, empty| {
+            s.chars()
+                .split_chunks(|&c| c == ',')
+                .empty_chunks(empty)
+                .map(|chunk| chunk.into_iter().collect::<String>())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(split("a,b,c", EmptyChunks::Yield), ["a", "b", "c"]);
+        assert_eq!(split(",a,,,b,", EmptyChunks::Yield), ["", "a", "", "", "b"]);
+        assert_eq!(split(",a,,,b,", EmptyChunks::Skip), ["a", "b"]);
+        assert_eq!(split(",,a,,,b,", EmptyChunks::Coalesce), ["", "a", "b"]);
+        assert_eq!(split(",", EmptyChunks::Yield), [""]);
+        assert!(split("", EmptyChunks::Yield).is_empty());
+    }
+}
@@ -0,0 +1,109 @@
+use std::sync::mpsc::Receiver;
+use std::time::Duration;
+
+/// A trait that extends blocking channel receivers with batch receiving.
+pub trait BlockingRecvChunks<T> {
+    /// Receives the next batch of up to `n` messages, or `None` if the channel
+    /// is disconnected and empty.
+    ///
+    /// Blocks for the first message without a deadline. Then:
+    ///
+    /// - Without `idle`, returns whatever is available right away, which is at
+    ///   least one message.
+    /// - With `idle`, keeps waiting until the batch is full, the channel is
+    ///   disconnected, or no message arrives for `idle`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    ///
+    /// ```
+    /// use std::sync::mpsc;
+    /// use std::time::Duration;
+    ///
+    /// use iter_chunks::BlockingRecvChunks;
+    ///
+    /// let (tx, rx) = mpsc::channel();
+    /// for i in 0..5 {
+    ///     tx.send(i).unwrap();
+    /// }
+    /// drop(tx);
+    ///
+    /// let idle = Some(Duration::from_millis(10));
+    /// assert_eq!(rx.recv_chunks(2, idle), Some(vec![0, 1]));
+    /// assert_eq!(rx.recv_chunks(2, idle), Some(vec![2, 3]));
+    /// assert_eq!(rx.recv_chunks(2, idle), Some(vec![4]));
+    /// assert_eq!(rx.recv_chunks(2, idle), None);
+    /// ```
+    fn recv_chunks(&self, n: usize, idle: Option<Duration>) -> Option<Vec<T>>;
+}
+
+macro_rules! impl_blocking_recv_chunks {
+    ($receiver:ty) => {
+        impl<T> $crate::BlockingRecvChunks<T> for $receiver {
+            fn recv_chunks(&self, n: usize, idle: Option<std::time::Duration>) -> Option<Vec<T>> {
+                assert_ne!(n, 0);
+                let mut buf = Vec::with_capacity(n);
+                buf.push(self.recv().ok()?);
+                while buf.len() < n {
+                    let v = match idle {
+                        Some(idle) => self.recv_timeout(idle).ok(),
+                        None => self.try_recv().ok(),
+                    };
+                    // Timed out, empty, or disconnected.
+                    let Some(v) = v else {
+                        break;
+                    };
+                    buf.push(v);
+                }
+                Some(buf)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "crossbeam")]
+pub(crate) use impl_blocking_recv_chunks;
+
+impl_blocking_recv_chunks!(Receiver<T>);
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::Duration;
+
+    use super::BlockingRecvChunks;
+
+    #[test]
+    fn test_recv_chunks() {
+        let (tx, rx) = mpsc::channel();
+        for i in 0..5 {
+            tx.send(i).unwrap();
+        }
+        assert_eq!(rx.recv_chunks(3, None), Some(vec![0, 1, 2]));
+        assert_eq!(rx.recv_chunks(3, None), Some(vec![3, 4]));
+        drop(tx);
+        assert_eq!(rx.recv_chunks(3, None), None);
+    }
+
+    #[test]
+    fn test_recv_chunks_idle() {
+        let (tx, rx) = mpsc::sync_channel(0);
+        let handle = thread::spawn(move || {
+            for i in 0..3 {
+                tx.send(i).unwrap();
+            }
+            thread::sleep(Duration::from_millis(500));
+            tx.send(3).unwrap();
+        });
+
+        // Messages sent back to back are batched, and the 500ms gap ends the
+        // batch.
+        let idle = Some(Duration::from_millis(100));
+        assert_eq!(rx.recv_chunks(10, idle), Some(vec![0, 1, 2]));
+        assert_eq!(rx.recv_chunks(10, idle), Some(vec![3]));
+        assert_eq!(rx.recv_chunks(10, idle), None);
+        handle.join().unwrap();
+    }
+}
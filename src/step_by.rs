@@ -0,0 +1,78 @@
+use crate::{Chunk, Chunks};
+
+/// An iterator-like struct that yields every `step`-th chunk.
+///
+/// This `struct` is created by [`Chunks::step_by_chunks`]. See its
+/// documentation for more.
+pub struct StepByChunks<I: Iterator> {
+    chunks: Chunks<I>,
+    // The number of chunks skipped between two yielded ones.
+    skip: usize,
+    first_take: bool,
+}
+
+impl<I: Iterator> StepByChunks<I> {
+    pub(crate) fn new(chunks: Chunks<I>, step: usize) -> Self {
+        assert_ne!(step, 0);
+        Self {
+            chunks,
+            skip: step - 1,
+            first_take: true,
+        }
+    }
+
+    /// Similar to [`Iterator::next`], but not implements [`Iterator`] due to
+    /// lifetime.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<Chunk<'_, I>> {
+        if self.first_take {
+            self.first_take = false;
+        } else {
+            self.chunks.align_to_next_boundary();
+            self.chunks.skip_chunks(self.skip);
+        }
+        self.chunks.next()
+    }
+
+    /// Similar to [`Iterator::for_each`].
+    pub fn for_each(&mut self, mut f: impl FnMut(Chunk<'_, I>)) {
+        while let Some(item) = self.next() {
+            f(item)
+        }
+    }
+
+    /// Consumes the adaptor and returns the underlying [`Chunks`].
+    pub fn into_inner(self) -> Chunks<I> {
+        self.chunks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IterChunks;
+
+    #[test]
+    fn test_step_by_chunks() {
+        for len in 0..12 {
+            for step in 1..4 {
+                let mut expected = vec![];
+                (0..len)
+                    .chunks(2)
+                    .for_each(|chunk| expected.push(chunk.collect::<Vec<_>>()));
+                let expected = expected.into_iter().step_by(step).collect::<Vec<_>>();
+                let mut res = vec![];
+                (0..len)
+                    .chunks(2)
+                    .step_by_chunks(step)
+                    .for_each(|chunk| res.push(chunk.collect::<Vec<_>>()));
+                assert_eq!(res, expected);
+            }
+        }
+
+        // The skipped chunks are not pulled one by one, and the rest of a
+        // yielded chunk is skipped with them.
+        let mut chunks = (0..u64::MAX).chunks(1 << 20).step_by_chunks(1 << 30);
+        assert_eq!(chunks.next().unwrap().next(), Some(0));
+        assert_eq!(chunks.next().unwrap().next(), Some(1 << 50));
+    }
+}
@@ -0,0 +1,376 @@
+use crate::Chunks;
+
+/// Custom boundary logic for [`ChunksWith`].
+///
+/// Before an element is added to the current chunk, [`should_close`] is called
+/// with it. If it returns `true`, the chunk ends before the element, the
+/// strategy is [`reset`], and the element starts the next chunk. The first
+/// element of every chunk is always passed to [`should_close`] too, so that
+/// the strategy can account for it, but the result is ignored: a chunk is
+/// never empty.
+///
+/// ```
+/// use iter_chunks::{ChunkStrategy, IterChunks};
+///
+/// /// Closes a chunk before its total length exceeds a byte budget.
+/// struct ByteBudget {
+///     budget: usize,
+///     used: usize,
+/// }
+///
+/// impl ChunkStrategy<&str> for ByteBudget {
+///     fn should_close(&mut self, next: &&str) -> bool {
+///         if self.used + next.len() > self.budget {
+///             return true;
+///         }
+///         self.used += next.len();
+///         false
+///     }
+///
+///     fn reset(&mut self) {
+///         self.used = 0;
+///     }
+/// }
+///
+/// let words = ["aaa", "bb", "c", "dddd", "eeeeee"];
+/// let mut chunks = words
+///     .into_iter()
+///     .chunks_with(ByteBudget { budget: 5, used: 0 });
+/// let mut res = vec![];
+/// chunks.for_each(|chunk| res.push(chunk.collect::<String>()));
+/// assert_eq!(res, ["aaabb", "cdddd", "eeeeee"]);
+/// ```
+///
+/// [`should_close`]: ChunkStrategy::should_close
+/// [`reset`]: ChunkStrategy::reset
+pub trait ChunkStrategy<T> {
+    /// Returns `true` if the current chunk should end before `next`.
+    fn should_close(&mut self, next: &T) -> bool;
+
+    /// Called when a new chunk starts, before its first element is passed to
+    /// [`ChunkStrategy::should_close`].
+    fn reset(&mut self);
+}
+
+/// A [`ChunkStrategy`] that closes every chunk after `n` elements, which gives
+/// the same boundaries as [`chunks`].
+///
+/// [`chunks`]: crate::IterChunks::chunks
+#[derive(Debug, Clone)]
+pub struct CountStrategy {
+    n: usize,
+    count: usize,
+}
+
+impl CountStrategy {
+    /// Creates a strategy for chunks of `n` elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    pub fn new(n: usize) -> Self {
+        assert_ne!(n, 0);
+        Self { n, count: 0 }
+    }
+}
+
+impl<T> ChunkStrategy<T> for CountStrategy {
+    fn should_close(&mut self, _: &T) -> bool {
+        if self.count == self.n {
+            return true;
+        }
+        self.count += 1;
+        false
+    }
+
+    fn reset(&mut self) {
+        self.count = 0;
+    }
+}
+
+/// A [`ChunkStrategy`] that closes a chunk before the total byte length of
+/// its elements exceeds a budget.
+///
+/// An element longer than the budget is never split, and gets its own chunk.
+///
+/// This `struct` is created by [`chunks_by_bytes`] method on [`IterChunks`].
+/// See its documentation for more.
+///
+/// [`chunks_by_bytes`]: crate::IterChunks::chunks_by_bytes
+/// [`IterChunks`]: crate::IterChunks
+#[derive(Debug, Clone)]
+pub struct ByteStrategy {
+    max_bytes: usize,
+    bytes: usize,
+    empty: bool,
+}
+
+impl ByteStrategy {
+    pub(crate) fn new(max_bytes: usize) -> Self {
+        assert_ne!(max_bytes, 0);
+        Self {
+            max_bytes,
+            bytes: 0,
+            empty: true,
+        }
+    }
+
+    /// Returns the configured byte budget.
+    pub fn max_bytes(&self) -> usize {
+        self.max_bytes
+    }
+
+    /// Returns the total byte length of the current chunk so far.
+    pub fn bytes(&self) -> usize {
+        self.bytes
+    }
+}
+
+impl<T: AsRef<[u8]>> ChunkStrategy<T> for ByteStrategy {
+    fn should_close(&mut self, next: &T) -> bool {
+        let len = next.as_ref().len();
+        if !self.empty && self.bytes + len > self.max_bytes {
+            return true;
+        }
+        self.empty = false;
+        self.bytes += len;
+        false
+    }
+
+    fn reset(&mut self) {
+        self.bytes = 0;
+        self.empty = true;
+    }
+}
+
+/// An iterator-like struct that yields chunks with boundaries chosen by a
+/// [`ChunkStrategy`].
+///
+/// Like [`Chunks`], if the underlying iterator returns `None`, the current
+/// chunk ends and [`ChunksWith::next`] returns `None` once, so that a
+/// resumable iterator can be chunked further.
+///
+/// This `struct` is created by [`chunks_with`] method on [`IterChunks`]. See
+/// its documentation for more.
+///
+/// [`chunks_with`]: crate::IterChunks::chunks_with
+/// [`IterChunks`]: crate::IterChunks
+pub struct ChunksWith<I: Iterator, S> {
+    // Only the element and end handling of `Chunks` is used, the strategy
+    // chooses the boundaries. The element that closed the previous chunk is
+    // buffered, and starts the next one.
+    chunks: Chunks<I>,
+    strategy: S,
+}
+
+impl<I, S> ChunksWith<I, S>
+where
+    I: Iterator,
+    S: ChunkStrategy<I::Item>,
+{
+    pub(crate) fn new(inner: I, strategy: S) -> Self {
+        Self {
+            chunks: Chunks::new(inner, usize::MAX),
+            strategy,
+        }
+    }
+
+    /// Returns a reference to the strategy.
+    pub fn strategy(&self) -> &S {
+        &self.strategy
+    }
+
+    /// Similar to [`Iterator::next`], but not implements [`Iterator`] due to
+    /// lifetime.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<ChunkWith<'_, I, S>> {
+        let first = self.chunks.next_first()?;
+        self.strategy.reset();
+        self.strategy.should_close(&first);
+        Some(ChunkWith {
+            first: Some(first),
+            parent: self,
+            done: false,
+        })
+    }
+
+    /// Similar to [`Iterator::for_each`].
+    pub fn for_each(&mut self, mut f: impl FnMut(ChunkWith<'_, I, S>)) {
+        while let Some(item) = self.next() {
+            f(item)
+        }
+    }
+
+    /// Consumes the adaptor and returns the underlying iterator.
+    ///
+    /// An element that closed a chunk but has not been yielded yet is lost.
+    pub fn into_inner(self) -> I {
+        self.chunks.inner
+    }
+}
+
+/// An iterator over a chunk of [`ChunksWith`].
+///
+/// This `struct` is created by [`ChunksWith::next`].
+pub struct ChunkWith<'a, I: Iterator, S> {
+    first: Option<I::Item>,
+    parent: &'a mut ChunksWith<I, S>,
+    done: bool,
+}
+
+impl<I: Iterator, S> ChunkWith<'_, I, S> {
+    /// Returns a reference to the strategy of the parent [`ChunksWith`].
+    pub fn strategy(&self) -> &S {
+        &self.parent.strategy
+    }
+}
+
+impl<I: Iterator> ChunkWith<'_, I, ByteStrategy> {
+    /// Returns the total byte length of the elements in this chunk so far.
+    ///
+    /// The first element is counted as soon as the chunk is yielded, and
+    /// every other element as soon as it's returned by [`Iterator::next`], so
+    /// this is the payload size of the whole chunk once it's exhausted.
+    pub fn bytes(&self) -> usize {
+        self.parent.strategy.bytes()
+    }
+}
+
+impl<I, S> Iterator for ChunkWith<'_, I, S>
+where
+    I: Iterator,
+    S: ChunkStrategy<I::Item>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(v) = self.first.take() {
+            return Some(v);
+        }
+        if self.done {
+            return None;
+        }
+        let Some(v) = self.parent.chunks.next_in_chunk() else {
+            self.done = true;
+            return None;
+        };
+        if self.parent.strategy.should_close(&v) {
+            self.parent.chunks.buffered.push_front(v);
+            self.done = true;
+            return None;
+        }
+        Some(v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ChunkStrategy, CountStrategy};
+    use crate::IterChunks;
+
+    #[test]
+    fn test_count_strategy() {
+        for len in 0..10 {
+            for n in 1..5 {
+                let mut expected = vec![];
+                (0..len)
+                    .chunks(n)
+                    .for_each(|chunk| expected.push(chunk.collect::<Vec<_>>()));
+                let mut res = vec![];
+                (0..len)
+                    .chunks_with(CountStrategy::new(n))
+                    .for_each(|chunk| res.push(chunk.collect::<Vec<_>>()));
+                assert_eq!(res, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_chunks_with() {
+        // Closes a chunk before every zero.
+        struct Delimited;
+
+        impl ChunkStrategy<i32> for Delimited {
+            fn should_close(&mut self, next: &i32) -> bool {
+                *next == 0
+            }
+
+            fn reset(&mut self) {}
+        }
+
+        let arr = [0, 1, 2, 0, 0, 3];
+        let mut chunks = arr.into_iter().chunks_with(Delimited);
+        let mut res = vec![];
+        chunks.for_each(|chunk| res.push(chunk.collect::<Vec<_>>()));
+        assert_eq!(res, vec![vec![0, 1, 2], vec![0], vec![0, 3]]);
+
+        // The rest of a partially consumed chunk starts the next chunk.
+        let mut chunks = (0..5).chunks_with(CountStrategy::new(2));
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(chunks.next().unwrap().take(1).collect::<Vec<_>>(), vec![2]);
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![3, 4]);
+        assert!(chunks.next().is_none());
+    }
+
+    #[test]
+    fn test_chunks_with_resumable() {
+        let inner_gen = |rem| {
+            let mut i = 0;
+            std::iter::from_fn(move || {
+                i += 1;
+                if i % rem == 0 {
+                    None
+                } else {
+                    Some(i)
+                }
+            })
+        };
+
+        let mut chunks = inner_gen(3).chunks_with(CountStrategy::new(4));
+        for expected in [vec![1, 2], vec![4, 5], vec![7, 8]] {
+            while let Some(chunk) = chunks.next() {
+                assert_eq!(chunk.collect::<Vec<_>>(), expected);
+            }
+        }
+
+        // The element that closed a chunk is kept across the end.
+        let mut chunks = inner_gen(6).chunks_with(CountStrategy::new(4));
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![5]);
+        assert!(chunks.next().is_none());
+        assert_eq!(
+            chunks.next().unwrap().collect::<Vec<_>>(),
+            vec![7, 8, 9, 10]
+        );
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![11]);
+        assert!(chunks.next().is_none());
+    }
+
+    #[test]
+    fn test_chunks_by_bytes() {
+        let bodies = ["aaa", "bb", "", "cccccccc", "d", "ee"];
+        let mut chunks = bodies.into_iter().chunks_by_bytes(5);
+        let mut res = vec![];
+        while let Some(mut chunk) = chunks.next() {
+            assert_eq!(chunk.strategy().max_bytes(), 5);
+            let mut body = String::new();
+            for s in chunk.by_ref() {
+                body.push_str(s);
+            }
+            assert_eq!(chunk.bytes(), body.len());
+            res.push(body);
+        }
+        // An element over the budget gets its own chunk.
+        assert_eq!(res, ["aaabb", "cccccccc", "dee"]);
+
+        // The running size grows as the chunk is consumed.
+        let mut chunks = ["ab", "cd", "ef"].into_iter().chunks_by_bytes(4);
+        let mut chunk = chunks.next().unwrap();
+        assert_eq!(chunk.bytes(), 2);
+        chunk.next();
+        chunk.next();
+        assert_eq!(chunk.bytes(), 4);
+        assert_eq!(chunk.next(), None);
+        assert_eq!(chunk.bytes(), 4);
+    }
+}
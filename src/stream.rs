@@ -0,0 +1,436 @@
+use alloc::vec::Vec;
+use core::future::poll_fn;
+use core::mem;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::Stream;
+
+/// A trait that extends [`Stream`] with `stream_chunks` method.
+pub trait StreamChunks: Sized + Stream {
+    /// Create a lending-stream-liked struct that yields chunks of every n
+    /// elements, or fewer if the underlying stream ends sooner. Every chunk is
+    /// itself a [`Stream`].
+    ///
+    /// This is the async analogue of [`IterChunks::chunks`]: chunks borrow the
+    /// underlying stream, so no chunk is collected into a `Vec`, and the usual
+    /// `StreamExt` combinators can be used on each chunk.
+    ///
+    /// ```
+    /// use futures::stream::{self, StreamExt};
+    /// use iter_chunks::StreamChunks;
+    ///
+    /// futures::executor::block_on(async {
+    ///     let mut chunks = stream::iter([1, 2, 3, 4, 5]).stream_chunks(2);
+    ///     let mut sums = vec![];
+    ///     while let Some(chunk) = chunks.next().await {
+    ///         sums.push(chunk.fold(0, |acc, v| async move { acc + v }).await);
+    ///     }
+    ///     assert_eq!(sums, vec![3, 7, 5]);
+    /// });
+    /// ```
+    ///
+    /// [`IterChunks::chunks`]: crate::IterChunks::chunks
+    fn stream_chunks(self, n: usize) -> ChunksStream<Self>;
+
+    /// Create a stream that yields batches of up to `n` elements, whose total
+    /// weight as returned by `weight_fn` doesn't exceed `max_weight`.
+    ///
+    /// A batch is flushed as soon as either limit is hit, so both the item
+    /// count and the payload size of bulk requests can be capped at once. An
+    /// element heavier than `max_weight` gets its own batch.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` or `max_weight` is 0.
+    ///
+    /// ```
+    /// use futures::stream::{self, StreamExt};
+    /// use iter_chunks::StreamChunks;
+    ///
+    /// futures::executor::block_on(async {
+    ///     let docs = stream::iter(["a", "bb", "c", "dddd", "e", "f", "g"]);
+    ///     let batches = docs
+    ///         .stream_chunks_weighted(3, 4, |doc| doc.len())
+    ///         .collect::<Vec<_>>()
+    ///         .await;
+    ///     assert_eq!(
+    ///         batches,
+    ///         vec![vec!["a", "bb", "c"], vec!["dddd"], vec!["e", "f", "g"]]
+    ///     );
+    /// });
+    /// ```
+    fn stream_chunks_weighted<F>(
+        self,
+        n: usize,
+        max_weight: usize,
+        weight_fn: F,
+    ) -> WeightedChunksStream<Self, F>
+    where
+        F: FnMut(&Self::Item) -> usize;
+}
+
+impl<S: Stream> StreamChunks for S {
+    fn stream_chunks(self, n: usize) -> ChunksStream<Self> {
+        assert_ne!(n, 0);
+        ChunksStream {
+            inner: self,
+            n,
+            end_flag: false,
+        }
+    }
+
+    fn stream_chunks_weighted<F>(
+        self,
+        n: usize,
+        max_weight: usize,
+        weight_fn: F,
+    ) -> WeightedChunksStream<Self, F>
+    where
+        F: FnMut(&Self::Item) -> usize,
+    {
+        assert_ne!(n, 0);
+        assert_ne!(max_weight, 0);
+        WeightedChunksStream {
+            inner: self,
+            n,
+            max_weight,
+            weight_fn,
+            buf: Vec::new(),
+            weight: 0,
+            pending: None,
+            end_flag: false,
+        }
+    }
+}
+
+/// A lending-stream-like struct that yields chunks.
+///
+/// This `struct` is created by [`stream_chunks`] method on [`StreamChunks`].
+/// See its documentation for more.
+///
+/// [`stream_chunks`]: StreamChunks::stream_chunks
+pub struct ChunksStream<S> {
+    inner: S,
+    n: usize,
+    end_flag: bool,
+}
+
+impl<S: Stream + Unpin> ChunksStream<S> {
+    /// Returns the configured chunk size `n`.
+    pub fn chunk_size(&self) -> usize {
+        self.n
+    }
+
+    /// Similar to `StreamExt::next`, but the chunk borrows `self`.
+    ///
+    /// Like [`Chunks::next`], this may return `Some` again after returning
+    /// `None` if the underlying stream is resumable.
+    ///
+    /// # Cancel safety
+    ///
+    /// This is cancel safe: the first element of a chunk is only pulled when
+    /// it's ready, and returned right away. If the future is dropped, e.g. in
+    /// `tokio::select!`, no element is lost, and the next call resumes from
+    /// the same position. Polling a [`ChunkStream`] with `StreamExt::next` is
+    /// cancel safe for the same reason.
+    ///
+    /// [`Chunks::next`]: crate::Chunks::next
+    pub async fn next(&mut self) -> Option<ChunkStream<'_, S>> {
+        let first = poll_fn(|cx| self.poll_first(cx)).await?;
+        let n = self.n;
+        Some(ChunkStream {
+            first: Some(first),
+            parent: self,
+            n: n - 1,
+        })
+    }
+
+    fn poll_first(&mut self, cx: &mut Context<'_>) -> Poll<Option<S::Item>> {
+        if self.end_flag {
+            // The inner stream may be resumable.
+            self.end_flag = false;
+            Poll::Ready(None)
+        } else {
+            Pin::new(&mut self.inner).poll_next(cx)
+        }
+    }
+
+    /// Consumes the struct and returns the underlying stream.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+/// A stream over a chunk of data.
+///
+/// This `struct` is created by [`ChunksStream::next`].
+pub struct ChunkStream<'a, S: Stream> {
+    first: Option<S::Item>,
+    parent: &'a mut ChunksStream<S>,
+    n: usize,
+}
+
+// The buffered first element is never pinned.
+impl<S: Stream> Unpin for ChunkStream<'_, S> {}
+
+impl<S: Stream + Unpin> ChunkStream<'_, S> {
+    /// Returns the configured chunk size of the parent [`ChunksStream`].
+    pub fn chunk_size(&self) -> usize {
+        self.parent.n
+    }
+}
+
+impl<S: Stream + Unpin> Stream for ChunkStream<'_, S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if let Some(v) = this.first.take() {
+            return Poll::Ready(Some(v));
+        }
+        if this.n == 0 {
+            return Poll::Ready(None);
+        }
+        match Pin::new(&mut this.parent.inner).poll_next(cx) {
+            Poll::Ready(Some(v)) => {
+                this.n -= 1;
+                Poll::Ready(Some(v))
+            }
+            Poll::Ready(None) => {
+                // The current chunk stream should output None and end forever.
+                this.n = 0;
+
+                // The parent chunks stream should output None once.
+                this.parent.end_flag = true;
+
+                Poll::Ready(None)
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.parent.inner.size_hint();
+        let has_first = self.first.is_some() as usize;
+        let n = self.n;
+        let lower = lower.min(n) + has_first;
+        let upper = upper.map(|v| v.min(n) + has_first);
+        (lower, upper)
+    }
+}
+
+/// A stream that yields batches capped by both a count and a total weight.
+///
+/// This `struct` is created by [`stream_chunks_weighted`] method on
+/// [`StreamChunks`]. See its documentation for more.
+///
+/// [`stream_chunks_weighted`]: StreamChunks::stream_chunks_weighted
+pub struct WeightedChunksStream<S: Stream, F> {
+    inner: S,
+    n: usize,
+    max_weight: usize,
+    weight_fn: F,
+    // The current batch, kept across `Poll::Pending`.
+    buf: Vec<S::Item>,
+    weight: usize,
+    // The element that closed the previous batch, with its weight.
+    pending: Option<(S::Item, usize)>,
+    end_flag: bool,
+}
+
+// The buffered elements are never pinned.
+impl<S: Stream + Unpin, F> Unpin for WeightedChunksStream<S, F> {}
+
+impl<S: Stream, F> WeightedChunksStream<S, F> {
+    /// Returns the configured maximum number of elements of a batch.
+    pub fn chunk_size(&self) -> usize {
+        self.n
+    }
+
+    /// Returns the configured maximum weight of a batch.
+    pub fn max_weight(&self) -> usize {
+        self.max_weight
+    }
+
+    /// Consumes the stream and returns the underlying stream.
+    ///
+    /// The elements of an unfinished batch are lost.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S, F> Stream for WeightedChunksStream<S, F>
+where
+    S: Stream + Unpin,
+    F: FnMut(&S::Item) -> usize,
+{
+    type Item = Vec<S::Item>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if let Some((v, weight)) = this.pending.take() {
+            this.buf.push(v);
+            this.weight = weight;
+        }
+        if this.end_flag && this.buf.is_empty() {
+            // The inner stream may be resumable.
+            this.end_flag = false;
+            return Poll::Ready(None);
+        }
+        while this.buf.len() < this.n {
+            match Pin::new(&mut this.inner).poll_next(cx) {
+                Poll::Ready(Some(v)) => {
+                    let weight = (this.weight_fn)(&v);
+                    if !this.buf.is_empty() && this.weight.saturating_add(weight) > this.max_weight
+                    {
+                        this.pending = Some((v, weight));
+                        break;
+                    }
+                    this.buf.push(v);
+                    this.weight = this.weight.saturating_add(weight);
+                }
+                Poll::Ready(None) => {
+                    if this.buf.is_empty() {
+                        return Poll::Ready(None);
+                    }
+                    // The parent stream should output None once.
+                    this.end_flag = true;
+                    break;
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+        this.weight = 0;
+        Poll::Ready(Some(mem::take(&mut this.buf)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::task::Poll;
+
+    use futures::executor::block_on;
+    use futures::stream::{self, Stream, StreamExt};
+
+    use super::StreamChunks;
+
+    async fn yield_now() {
+        let mut yielded = false;
+        std::future::poll_fn(|cx| {
+            if yielded {
+                Poll::Ready(())
+            } else {
+                yielded = true;
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        })
+        .await
+    }
+
+    #[test]
+    fn test_stream_chunks() {
+        block_on(async {
+            let mut chunks = stream::iter(0..7).stream_chunks(3);
+            let mut res = vec![];
+            while let Some(chunk) = chunks.next().await {
+                assert_eq!(chunk.chunk_size(), 3);
+                res.push(chunk.collect::<Vec<_>>().await);
+            }
+            assert_eq!(res, vec![vec![0, 1, 2], vec![3, 4, 5], vec![6]]);
+        });
+    }
+
+    #[test]
+    fn test_stream_chunks_partial() {
+        block_on(async {
+            let mut chunks = stream::iter(0..5).stream_chunks(2);
+            let mut chunk = chunks.next().await.unwrap();
+            assert_eq!(chunk.size_hint(), (2, Some(2)));
+            assert_eq!(chunk.next().await, Some(0));
+
+            // The rest of a dropped chunk goes to the next chunk.
+            let chunk = chunks.next().await.unwrap();
+            assert_eq!(chunk.collect::<Vec<_>>().await, vec![1, 2]);
+            let chunk = chunks.next().await.unwrap();
+            assert_eq!(chunk.collect::<Vec<_>>().await, vec![3, 4]);
+            assert!(chunks.next().await.is_none());
+        });
+    }
+
+    #[test]
+    fn test_stream_chunks_cancel() {
+        block_on(async {
+            let (tx, rx) = futures::channel::mpsc::unbounded();
+            let mut chunks = rx.stream_chunks(2);
+
+            // Cancel waiting for a chunk.
+            assert!(futures::poll!(Box::pin(chunks.next())).is_pending());
+            tx.unbounded_send(0).unwrap();
+            let mut chunk = chunks.next().await.unwrap();
+            assert_eq!(chunk.next().await, Some(0));
+
+            // Cancel waiting for an element in a chunk.
+            assert!(futures::poll!(chunk.next()).is_pending());
+            tx.unbounded_send(1).unwrap();
+            tx.unbounded_send(2).unwrap();
+            drop(tx);
+            assert_eq!(chunk.next().await, Some(1));
+            assert_eq!(chunk.next().await, None);
+
+            let chunk = chunks.next().await.unwrap();
+            assert_eq!(chunk.collect::<Vec<_>>().await, vec![2]);
+            assert!(chunks.next().await.is_none());
+        });
+    }
+
+    #[test]
+    fn test_stream_chunks_weighted() {
+        block_on(async {
+            // Either limit flushes the batch, and a heavy element is alone.
+            let arr = [1, 1, 1, 3, 9, 2, 2];
+            let chunks = stream::iter(arr).stream_chunks_weighted(3, 4, |&v| v);
+            assert_eq!(
+                chunks.collect::<Vec<_>>().await,
+                vec![vec![1, 1, 1], vec![3], vec![9], vec![2, 2]]
+            );
+
+            // A batch is kept across pending polls.
+            let (tx, rx) = futures::channel::mpsc::unbounded();
+            let mut chunks = rx.stream_chunks_weighted(2, 10, |&v| v);
+            tx.unbounded_send(1).unwrap();
+            assert!(futures::poll!(chunks.next()).is_pending());
+            tx.unbounded_send(2).unwrap();
+            tx.unbounded_send(3).unwrap();
+            assert_eq!(chunks.next().await, Some(vec![1, 2]));
+            drop(tx);
+            assert_eq!(chunks.next().await, Some(vec![3]));
+            assert_eq!(chunks.next().await, None);
+        });
+    }
+
+    #[test]
+    fn test_stream_chunks_pending() {
+        block_on(async {
+            let (tx, rx) = futures::channel::mpsc::unbounded();
+            let producer = async move {
+                for i in 0..5 {
+                    tx.unbounded_send(i).unwrap();
+                    yield_now().await;
+                }
+            };
+            let consumer = async {
+                let mut chunks = rx.stream_chunks(2);
+                let mut res = vec![];
+                while let Some(chunk) = chunks.next().await {
+                    res.push(chunk.collect::<Vec<_>>().await);
+                }
+                res
+            };
+            let ((), res) = futures::join!(producer, consumer);
+            assert_eq!(res, vec![vec![0, 1], vec![2, 3], vec![4]]);
+        });
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{Chunk, Chunks};
+
+/// An iterator-like struct that groups every `m` consecutive chunks into a
+/// [`SuperChunk`].
+///
+/// This `struct` is created by [`Chunks::super_chunks`]. See its
+/// documentation for more.
+pub struct SuperChunks<I: Iterator> {
+    chunks: Chunks<I>,
+    m: usize,
+}
+
+impl<I: Iterator> SuperChunks<I> {
+    pub(crate) fn new(chunks: Chunks<I>, m: usize) -> Self {
+        assert_ne!(m, 0);
+        Self { chunks, m }
+    }
+
+    /// Similar to [`Iterator::next`], but not implements [`Iterator`] due to
+    /// lifetime.
+    ///
+    /// Up to `n` elements are pulled ahead to know whether a super-chunk
+    /// exists, see [`Chunks::peek_len`].
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<SuperChunk<'_, I>> {
+        if self.chunks.peek_len() == 0 {
+            // Consume the `None` of the underlying `Chunks`.
+            let none = self.chunks.next();
+            debug_assert!(none.is_none());
+            return None;
+        }
+        Some(SuperChunk {
+            chunks: &mut self.chunks,
+            remaining: self.m,
+        })
+    }
+
+    /// Similar to [`Iterator::for_each`].
+    pub fn for_each(&mut self, mut f: impl FnMut(SuperChunk<'_, I>)) {
+        while let Some(item) = self.next() {
+            f(item)
+        }
+    }
+
+    /// Consumes the adaptor and returns the underlying [`Chunks`].
+    pub fn into_inner(self) -> Chunks<I> {
+        self.chunks
+    }
+}
+
+/// A group of up to `m` consecutive chunks.
+///
+/// This `struct` is created by [`SuperChunks::next`].
+pub struct SuperChunk<'a, I: Iterator> {
+    chunks: &'a mut Chunks<I>,
+    remaining: usize,
+}
+
+impl<I: Iterator> SuperChunk<'_, I> {
+    /// Similar to [`Chunks::next`], but returns `None` after `m` chunks.
+    ///
+    /// The chunks not yielded by a dropped super-chunk are yielded by the
+    /// next one.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<Chunk<'_, I>> {
+        // The `None` of the underlying `Chunks` is left for `SuperChunks`.
+        if self.remaining == 0 || self.chunks.peek_len() == 0 {
+            self.remaining = 0;
+            return None;
+        }
+        self.remaining -= 1;
+        self.chunks.next()
+    }
+
+    /// Similar to [`Iterator::for_each`].
+    pub fn for_each(&mut self, mut f: impl FnMut(Chunk<'_, I>)) {
+        while let Some(item) = self.next() {
+            f(item)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IterChunks;
+
+    #[test]
+    fn test_super_chunks() {
+        let mut chunks = (0..11).chunks(2).super_chunks(2);
+        let mut res = vec![];
+        chunks.for_each(|mut sup| {
+            let mut group = vec![];
+            sup.for_each(|chunk| group.push(chunk.collect::<Vec<_>>()));
+            res.push(group);
+        });
+        assert_eq!(
+            res,
+            vec![
+                vec![vec![0, 1], vec![2, 3]],
+                vec![vec![4, 5], vec![6, 7]],
+                vec![vec![8, 9], vec![10]],
+            ]
+        );
+        assert!(chunks.next().is_none());
+    }
+
+    #[test]
+    fn test_super_chunks_partial() {
+        let mut chunks = (0..6).chunks(2).super_chunks(2);
+        // Nothing is lost by a super-chunk dropped early.
+        let mut sup = chunks.next().unwrap();
+        assert_eq!(sup.next().unwrap().next(), Some(0));
+        let mut sup = chunks.next().unwrap();
+        assert_eq!(sup.next().unwrap().collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(sup.next().unwrap().collect::<Vec<_>>(), vec![3, 4]);
+        assert!(sup.next().is_none());
+        let mut sup = chunks.next().unwrap();
+        assert_eq!(sup.next().unwrap().collect::<Vec<_>>(), vec![5]);
+        assert!(sup.next().is_none());
+        assert!(chunks.next().is_none());
+
+        let mut rest = chunks.into_inner();
+        assert!(rest.next().is_none());
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{Chunk, Chunks};
+
+/// An iterator-like struct that yields chunks while a predicate holds.
+///
+/// This `struct` is created by [`Chunks::take_while_chunks`]. See its
+/// documentation for more.
+pub struct TakeWhileChunks<I: Iterator, P> {
+    chunks: Chunks<I>,
+    predicate: P,
+    buf: Vec<I::Item>,
+    done: bool,
+}
+
+impl<I, P> TakeWhileChunks<I, P>
+where
+    I: Iterator,
+    P: FnMut(&[I::Item]) -> bool,
+{
+    pub(crate) fn new(chunks: Chunks<I>, predicate: P) -> Self {
+        Self {
+            chunks,
+            predicate,
+            buf: Vec::new(),
+            done: false,
+        }
+    }
+
+    /// Similar to [`Iterator::next`], but not implements [`Iterator`] due to
+    /// lifetime.
+    ///
+    /// Once a chunk fails the predicate or the underlying [`Chunks`] returns
+    /// `None`, this always returns `None`.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<Chunk<'_, I>> {
+        if self.done {
+            return None;
+        }
+        if !self.chunks.next_buffered(&mut self.buf) {
+            self.done = true;
+            return None;
+        }
+        let pass = (self.predicate)(&self.buf);
+        // The chunk is given back either way: to be yielded as is, or to be
+        // left in the underlying `Chunks`.
+        self.chunks.unread(&mut self.buf);
+        if pass {
+            self.chunks.next()
+        } else {
+            self.done = true;
+            None
+        }
+    }
+
+    /// Similar to [`Iterator::for_each`].
+    pub fn for_each(&mut self, mut f: impl FnMut(Chunk<'_, I>)) {
+        while let Some(item) = self.next() {
+            f(item)
+        }
+    }
+
+    /// Consumes the adaptor and returns the underlying [`Chunks`], positioned
+    /// at the chunk that failed the predicate.
+    pub fn into_inner(self) -> Chunks<I> {
+        self.chunks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IterChunks;
+
+    #[test]
+    fn test_take_while_chunks() {
+        let mut chunks = (0..10)
+            .chunks(3)
+            .take_while_chunks(|chunk| chunk.iter().sum::<i32>() < 10);
+        let mut res = vec![];
+        while let Some(chunk) = chunks.next() {
+            res.push(chunk.collect::<Vec<_>>());
+        }
+        assert_eq!(res, vec![vec![0, 1, 2]]);
+        assert!(chunks.next().is_none());
+
+        let mut rest = chunks.into_inner();
+        assert_eq!(rest.next().unwrap().collect::<Vec<_>>(), vec![3, 4, 5]);
+        assert_eq!(rest.next().unwrap().collect::<Vec<_>>(), vec![6, 7, 8]);
+        assert_eq!(rest.next().unwrap().collect::<Vec<_>>(), vec![9]);
+        assert!(rest.next().is_none());
+    }
+
+    #[test]
+    fn test_take_while_chunks_all() {
+        let mut chunks = (0..5).chunks(2).take_while_chunks(|_| true);
+        let mut res = vec![];
+        chunks.for_each(|chunk| res.push(chunk.collect::<Vec<_>>()));
+        assert_eq!(res, vec![vec![0, 1], vec![2, 3], vec![4]]);
+        assert!(chunks.into_inner().next().is_none());
+    }
+
+    #[test]
+    fn test_take_while_chunks_partial() {
+        // A partially consumed chunk leaves its rest to the next chunk.
+        let mut chunks = (0..6).chunks(2).take_while_chunks(|chunk| chunk[0] < 4);
+        assert_eq!(chunks.next().unwrap().next(), Some(0));
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(chunks.next().unwrap().collect::<Vec<_>>(), vec![3, 4]);
+        assert!(chunks.next().is_none());
+        assert_eq!(
+            chunks.into_inner().next().unwrap().collect::<Vec<_>>(),
+            vec![5]
+        );
+    }
+}
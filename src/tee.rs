@@ -0,0 +1,205 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Condvar, Mutex};
+
+use crate::Chunks;
+
+/// An iterator over one side of a teed source.
+///
+/// This `struct` is created by [`Chunks::tee`]. See its documentation for
+/// more.
+pub struct Tee<I: Iterator> {
+    shared: Arc<(Mutex<TeeState<I>>, Condvar)>,
+    side: usize,
+}
+
+struct TeeState<I: Iterator> {
+    inner: I,
+    // The elements pulled by one side but not yet yielded by the other.
+    bufs: [VecDeque<I::Item>; 2],
+    alive: [bool; 2],
+    capacity: usize,
+}
+
+impl<I: Iterator> Iterator for Tee<I>
+where
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (lock, cvar) = &*self.shared;
+        let mut state = lock.lock().unwrap();
+        let other = 1 - self.side;
+        loop {
+            if let Some(v) = state.bufs[self.side].pop_front() {
+                // The other side may wait for the buffer to shrink.
+                cvar.notify_all();
+                return Some(v);
+            }
+            if state.alive[other] && state.bufs[other].len() >= state.capacity {
+                state = cvar.wait(state).unwrap();
+                continue;
+            }
+            let v = state.inner.next()?;
+            if state.alive[other] {
+                state.bufs[other].push_back(v.clone());
+            }
+            return Some(v);
+        }
+    }
+}
+
+impl<I: Iterator> Drop for Tee<I> {
+    fn drop(&mut self) {
+        let (lock, cvar) = &*self.shared;
+        // Don't panic again in drop if the lock is poisoned.
+        if let Ok(mut state) = lock.lock() {
+            state.alive[self.side] = false;
+            state.bufs[self.side].clear();
+        }
+        cvar.notify_all();
+    }
+}
+
+impl<I: Iterator> Chunks<I>
+where
+    I::Item: Clone,
+{
+    /// Splits into two chunk streams over the same elements, cloning every
+    /// element for the second side. Both sides keep the chunk size and the
+    /// state of `self`, e.g. the elements put back.
+    ///
+    /// The elements yielded by one side are buffered until the other side
+    /// yields them, and the leading side may be at most `capacity` chunks
+    /// ahead. Beyond that, it blocks until the other side catches up, so the
+    /// sides are meant to be consumed from two threads, or alternately from
+    /// one thread. Dropping a side stops buffering for it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is 0.
+    ///
+    /// ```
+    /// use std::thread;
+    ///
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let (mut store, mut metrics) = (0..10).chunks(4).tee(1);
+    /// let handle = thread::spawn(move || {
+    ///     let mut sizes = vec![];
+    ///     metrics.for_each(|chunk| sizes.push(chunk.count()));
+    ///     sizes
+    /// });
+    /// let mut stored = vec![];
+    /// store.for_each(|chunk| stored.push(chunk.collect::<Vec<_>>()));
+    /// assert_eq!(stored, vec![vec![0, 1, 2, 3], vec![4, 5, 6, 7], vec![8, 9]]);
+    /// assert_eq!(handle.join().unwrap(), vec![4, 4, 2]);
+    /// ```
+    pub fn tee(self, capacity: usize) -> (Chunks<Tee<I>>, Chunks<Tee<I>>) {
+        assert_ne!(capacity, 0);
+        let state = TeeState {
+            inner: self.inner,
+            bufs: [VecDeque::new(), VecDeque::new()],
+            alive: [true; 2],
+            capacity: capacity.saturating_mul(self.n),
+        };
+        let shared = Arc::new((Mutex::new(state), Condvar::new()));
+        let side = |side, buffered| Chunks {
+            inner: Tee {
+                shared: shared.clone(),
+                side,
+            },
+            n: self.n,
+            end_flag: self.end_flag,
+            resumable: self.resumable,
+            exhausted: self.exhausted,
+            buffered,
+            pulled: self.pulled,
+            prepended: self.prepended.clone(),
+            chunk_end: self.chunk_end,
+            put_back_mark: self.put_back_mark,
+            #[cfg(feature = "tracing")]
+            chunk_index: self.chunk_index,
+            #[cfg(feature = "tracing")]
+            span: None,
+        };
+        (side(0, self.buffered.clone()), side(1, self.buffered))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+    use std::time::Duration;
+
+    use crate::IterChunks;
+
+    #[test]
+    fn test_tee() {
+        let mut chunks = (0..7).chunks(3);
+        chunks.put_back(-1).unwrap();
+        let (mut a, mut b) = chunks.tee(1);
+
+        // Consumed alternately on one thread.
+        let mut res_a = vec![];
+        let mut res_b = vec![];
+        while let Some(chunk) = a.next() {
+            res_a.push(chunk.collect::<Vec<_>>());
+            res_b.push(b.next().unwrap().collect::<Vec<_>>());
+        }
+        assert!(b.next().is_none());
+        let expected = vec![vec![-1, 0, 1], vec![2, 3, 4], vec![5, 6]];
+        assert_eq!(res_a, expected);
+        assert_eq!(res_b, expected);
+    }
+
+    #[test]
+    fn test_tee_dropped_side() {
+        let (mut a, b) = (0..100).chunks(2).tee(1);
+        // The dropped side doesn't block the other.
+        drop(b);
+        let mut count = 0;
+        a.for_each(|chunk| count += chunk.count());
+        assert_eq!(count, 100);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_tee_capacity_zero() {
+        let _ = (0..10).chunks(2).tee(0);
+    }
+
+    #[test]
+    fn test_tee_capacity_one() {
+        // The leading side blocks one chunk ahead until the lagging one
+        // catches up.
+        let (mut a, mut b) = (0..10).chunks(2).tee(1);
+        let handle = thread::spawn(move || {
+            let mut res = vec![];
+            b.for_each(|chunk| {
+                thread::sleep(Duration::from_millis(1));
+                res.push(chunk.collect::<Vec<_>>());
+            });
+            res
+        });
+        let mut res = vec![];
+        a.for_each(|chunk| res.push(chunk.collect::<Vec<_>>()));
+        let expected = vec![vec![0, 1], vec![2, 3], vec![4, 5], vec![6, 7], vec![8, 9]];
+        assert_eq!(res, expected);
+        assert_eq!(handle.join().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_tee_threads() {
+        let (mut a, mut b) = (0..1000).chunks(7).tee(2);
+        let handle = thread::spawn(move || {
+            let mut sum = 0;
+            b.for_each(|chunk| sum += chunk.sum::<i32>());
+            sum
+        });
+        let mut sum = 0;
+        a.for_each(|chunk| sum += chunk.sum::<i32>());
+        assert_eq!(sum, (0..1000).sum::<i32>());
+        assert_eq!(handle.join().unwrap(), sum);
+    }
+}
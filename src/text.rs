@@ -0,0 +1,172 @@
+/// Splits `s` into sub-slices of `n` chars each, or fewer for the last one.
+///
+/// Unlike `s.chars().chunks(n)`, this does not allocate: every chunk is a
+/// `&str` borrowed from `s`, and chunk boundaries always fall on UTF-8 char
+/// boundaries.
+///
+/// # Panics
+///
+/// Panics if `n` is 0.
+///
+/// ```
+/// use iter_chunks::str_chunks;
+///
+/// let chunks = str_chunks("héllo wörld", 4).collect::<Vec<_>>();
+/// assert_eq!(chunks, ["héll", "o wö", "rld"]);
+/// ```
+pub fn str_chunks(s: &str, n: usize) -> StrChunks<'_> {
+    assert_ne!(n, 0);
+    StrChunks { rest: s, n }
+}
+
+/// An iterator over `&str` chunks of `n` chars.
+///
+/// This `struct` is created by [`str_chunks`]. See its documentation for more.
+#[derive(Debug, Clone)]
+pub struct StrChunks<'a> {
+    rest: &'a str,
+    n: usize,
+}
+
+impl<'a> Iterator for StrChunks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let mid = self
+            .rest
+            .char_indices()
+            .nth(self.n)
+            .map_or(self.rest.len(), |(i, _)| i);
+        let (chunk, rest) = self.rest.split_at(mid);
+        self.rest = rest;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every char takes 1 to 4 bytes.
+        let len = self.rest.len();
+        let lower = len.div_ceil(4).div_ceil(self.n);
+        let upper = len.div_ceil(self.n);
+        (lower, Some(upper))
+    }
+}
+
+impl std::iter::FusedIterator for StrChunks<'_> {}
+
+/// Splits `s` into sub-slices of `n` extended grapheme clusters each, or fewer
+/// for the last one.
+///
+/// Unlike [`str_chunks`], this never splits an emoji sequence or a char from
+/// its combining marks.
+///
+/// # Panics
+///
+/// Panics if `n` is 0.
+///
+/// ```
+/// use iter_chunks::grapheme_chunks;
+///
+/// let chunks = grapheme_chunks("e\u{301}a👍🏽b", 2).collect::<Vec<_>>();
+/// assert_eq!(chunks, ["e\u{301}a", "👍🏽b"]);
+/// ```
+#[cfg(feature = "unicode-segmentation")]
+pub fn grapheme_chunks(s: &str, n: usize) -> GraphemeChunks<'_> {
+    assert_ne!(n, 0);
+    GraphemeChunks { rest: s, n }
+}
+
+/// An iterator over `&str` chunks of `n` grapheme clusters.
+///
+/// This `struct` is created by [`grapheme_chunks`]. See its documentation for
+/// more.
+#[cfg(feature = "unicode-segmentation")]
+#[derive(Debug, Clone)]
+pub struct GraphemeChunks<'a> {
+    rest: &'a str,
+    n: usize,
+}
+
+#[cfg(feature = "unicode-segmentation")]
+impl<'a> Iterator for GraphemeChunks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        use unicode_segmentation::UnicodeSegmentation;
+
+        if self.rest.is_empty() {
+            return None;
+        }
+        let mid = self
+            .rest
+            .grapheme_indices(true)
+            .nth(self.n)
+            .map_or(self.rest.len(), |(i, _)| i);
+        let (chunk, rest) = self.rest.split_at(mid);
+        self.rest = rest;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.rest.len();
+        let lower = (!self.rest.is_empty()) as usize;
+        (lower, Some(len.div_ceil(self.n)))
+    }
+}
+
+#[cfg(feature = "unicode-segmentation")]
+impl std::iter::FusedIterator for GraphemeChunks<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::str_chunks;
+
+    #[test]
+    fn test_str_chunks() {
+        assert_eq!(str_chunks("", 3).count(), 0);
+        assert_eq!(
+            str_chunks("abcdefg", 3).collect::<Vec<_>>(),
+            ["abc", "def", "g"]
+        );
+        assert_eq!(str_chunks("abcdef", 3).collect::<Vec<_>>(), ["abc", "def"]);
+        assert_eq!(
+            str_chunks("你好世界！", 2).collect::<Vec<_>>(),
+            ["你好", "世界", "！"]
+        );
+        assert_eq!(str_chunks("a🦀b", 1).collect::<Vec<_>>(), ["a", "🦀", "b"]);
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    #[test]
+    fn test_grapheme_chunks() {
+        use super::grapheme_chunks;
+
+        assert_eq!(grapheme_chunks("", 2).count(), 0);
+        assert_eq!(
+            grapheme_chunks("abcde", 2).collect::<Vec<_>>(),
+            ["ab", "cd", "e"]
+        );
+        // Family emoji joined by ZWJ, and a flag made of two regional
+        // indicators.
+        let s = "👨‍👩‍👧x🇨🇳y";
+        assert_eq!(
+            grapheme_chunks(s, 1).collect::<Vec<_>>(),
+            ["👨‍👩‍👧", "x", "🇨🇳", "y"]
+        );
+        assert_eq!(grapheme_chunks(s, 3).collect::<Vec<_>>(), ["👨‍👩‍👧x🇨🇳", "y"]);
+    }
+
+    #[test]
+    fn test_str_chunks_size_hint() {
+        for s in ["", "abcdefg", "你好世界！", "a🦀b🦀c"] {
+            for n in 1..5 {
+                let (lower, upper) = str_chunks(s, n).size_hint();
+                let count = str_chunks(s, n).count();
+                assert!(lower <= count, "{s} {n}");
+                assert!(count <= upper.unwrap(), "{s} {n}");
+            }
+        }
+    }
+}
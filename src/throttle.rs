@@ -0,0 +1,103 @@
+use std::time::{Duration, Instant};
+
+use crate::{Chunk, Chunks};
+
+/// An iterator-like struct that yields chunks no faster than one per
+/// interval.
+///
+/// This `struct` is created by [`Chunks::throttle`]. See its documentation
+/// for more.
+pub struct ThrottledChunks<I: Iterator> {
+    chunks: Chunks<I>,
+    interval: Duration,
+    busy_wait: bool,
+    // When the last chunk was yielded.
+    last: Option<Instant>,
+}
+
+impl<I: Iterator> ThrottledChunks<I> {
+    pub(crate) fn new(chunks: Chunks<I>, interval: Duration) -> Self {
+        Self {
+            chunks,
+            interval,
+            busy_wait: false,
+            last: None,
+        }
+    }
+
+    /// Spins instead of sleeping until the next chunk is due.
+    ///
+    /// This is more precise for short intervals, at the cost of a busy CPU.
+    pub fn busy_wait(mut self) -> Self {
+        self.busy_wait = true;
+        self
+    }
+
+    /// Similar to [`Iterator::next`], but not implements [`Iterator`] due to
+    /// lifetime.
+    ///
+    /// The first element of the chunk is pulled before waiting, so this
+    /// doesn't wait if there is no next chunk.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<Chunk<'_, I>> {
+        let first = self.chunks.next_first()?;
+        if let Some(last) = self.last {
+            self.wait_until(last + self.interval);
+        }
+        self.last = Some(Instant::now());
+        Some(self.chunks.chunk_with_first(first))
+    }
+
+    /// Similar to [`Iterator::for_each`].
+    pub fn for_each(&mut self, mut f: impl FnMut(Chunk<'_, I>)) {
+        while let Some(item) = self.next() {
+            f(item)
+        }
+    }
+
+    /// Consumes the adaptor and returns the underlying [`Chunks`].
+    pub fn into_inner(self) -> Chunks<I> {
+        self.chunks
+    }
+
+    fn wait_until(&self, deadline: Instant) {
+        if self.busy_wait {
+            while Instant::now() < deadline {
+                std::hint::spin_loop();
+            }
+        } else {
+            let now = Instant::now();
+            if now < deadline {
+                std::thread::sleep(deadline - now);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use crate::IterChunks;
+
+    #[test]
+    fn test_throttle() {
+        let interval = Duration::from_millis(20);
+        for busy_wait in [false, true] {
+            let mut chunks = (0..5).chunks(2).throttle(interval);
+            if busy_wait {
+                chunks = chunks.busy_wait();
+            }
+            let start = Instant::now();
+            let mut times = vec![];
+            chunks.for_each(|chunk| {
+                chunk.for_each(drop);
+                times.push(start.elapsed());
+            });
+            assert_eq!(times.len(), 3);
+            assert!(times[1] >= interval);
+            assert!(times[2] >= 2 * interval);
+            assert!(chunks.next().is_none());
+        }
+    }
+}
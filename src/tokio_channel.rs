@@ -0,0 +1,263 @@
+use std::future::Future;
+use std::time::Duration;
+
+use tokio::sync::mpsc::{Receiver, UnboundedReceiver};
+
+/// A trait that extends tokio mpsc receivers with batch receiving.
+pub trait RecvChunks<T> {
+    /// Receives the next batch of up to `n` messages, or `None` if the channel
+    /// is closed and empty.
+    ///
+    /// Waits for the first message without a deadline. Then:
+    ///
+    /// - Without `idle`, returns whatever is available right away, which is at
+    ///   least one message unless `buf` already holds some.
+    /// - With `idle`, keeps waiting until the batch is full, the channel is
+    ///   closed, or no message arrives for `idle`.
+    ///
+    /// # Cancel safety
+    ///
+    /// This is not cancel safe: if the future is dropped after the first
+    /// message arrives, the messages collected so far are dropped with it. Use
+    /// [`RecvChunks::recv_chunk_into`] in `tokio::select!` instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use iter_chunks::RecvChunks;
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().enable_time().build().unwrap().block_on(async {
+    /// let (tx, mut rx) = tokio::sync::mpsc::channel(16);
+    /// for i in 0..5 {
+    ///     tx.send(i).await.unwrap();
+    /// }
+    /// drop(tx);
+    ///
+    /// let idle = Some(Duration::from_millis(10));
+    /// assert_eq!(rx.recv_chunks(2, idle).await, Some(vec![0, 1]));
+    /// assert_eq!(rx.recv_chunks(2, idle).await, Some(vec![2, 3]));
+    /// assert_eq!(rx.recv_chunks(2, idle).await, Some(vec![4]));
+    /// assert_eq!(rx.recv_chunks(2, idle).await, None);
+    /// # });
+    /// ```
+    fn recv_chunks(
+        &mut self,
+        n: usize,
+        idle: Option<Duration>,
+    ) -> impl Future<Output = Option<Vec<T>>> + '_;
+
+    /// Similar to [`RecvChunks::recv_chunks`], but the messages are appended
+    /// to `buf` until it holds `n` messages. Returns `false` if the channel is
+    /// closed and `buf` is empty.
+    ///
+    /// If `buf` is not empty, the first message is not waited for, and the
+    /// batch in `buf` is completed as in [`RecvChunks::recv_chunks`].
+    ///
+    /// # Cancel safety
+    ///
+    /// This is cancel safe: every message is moved into `buf` as soon as it's
+    /// received, so if the future is dropped, e.g. in `tokio::select!`, the
+    /// messages received so far are left in `buf`, and calling this again
+    /// with the same `buf` resumes the batch.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use iter_chunks::RecvChunks;
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().enable_time().build().unwrap().block_on(async {
+    /// let (tx, mut rx) = tokio::sync::mpsc::channel(16);
+    /// tx.send(0).await.unwrap();
+    ///
+    /// let mut buf = vec![];
+    /// let idle = Some(Duration::from_secs(60));
+    /// tokio::select! {
+    ///     _ = rx.recv_chunk_into(&mut buf, 2, idle) => unreachable!(),
+    ///     _ = tokio::time::sleep(Duration::from_millis(10)) => {}
+    /// }
+    /// // The batch is resumed after the cancellation.
+    /// assert_eq!(buf, [0]);
+    /// tx.send(1).await.unwrap();
+    /// assert!(rx.recv_chunk_into(&mut buf, 2, idle).await);
+    /// assert_eq!(buf, [0, 1]);
+    /// # });
+    /// ```
+    fn recv_chunk_into<'a>(
+        &'a mut self,
+        buf: &'a mut Vec<T>,
+        n: usize,
+        idle: Option<Duration>,
+    ) -> impl Future<Output = bool> + 'a;
+}
+
+macro_rules! impl_recv_chunks {
+    ($receiver:ident) => {
+        impl<T> RecvChunks<T> for $receiver<T> {
+            async fn recv_chunks(&mut self, n: usize, idle: Option<Duration>) -> Option<Vec<T>> {
+                let mut buf = Vec::with_capacity(n);
+                self.recv_chunk_into(&mut buf, n, idle).await.then_some(buf)
+            }
+
+            async fn recv_chunk_into(
+                &mut self,
+                buf: &mut Vec<T>,
+                n: usize,
+                idle: Option<Duration>,
+            ) -> bool {
+                assert_ne!(n, 0);
+                // `recv_many` is cancel safe, and pushes the messages into
+                // `buf` right away.
+                if buf.is_empty() && self.recv_many(buf, n).await == 0 {
+                    return false;
+                }
+                let Some(idle) = idle else {
+                    // Only take what is already queued.
+                    while buf.len() < n {
+                        match self.try_recv() {
+                            Ok(v) => buf.push(v),
+                            Err(_) => break,
+                        }
+                    }
+                    return true;
+                };
+                while buf.len() < n {
+                    let limit = n - buf.len();
+                    match tokio::time::timeout(idle, self.recv_many(buf, limit)).await {
+                        // The channel is closed.
+                        Ok(0) => break,
+                        Ok(_) => {}
+                        // Timed out.
+                        Err(_) => break,
+                    }
+                }
+                true
+            }
+        }
+    };
+}
+
+impl_recv_chunks!(Receiver);
+impl_recv_chunks!(UnboundedReceiver);
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+    use std::time::Duration;
+
+    use tokio::sync::mpsc;
+    use tokio::time::sleep;
+
+    use super::RecvChunks;
+
+    #[tokio::test]
+    async fn test_recv_chunks() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        for i in 0..5 {
+            tx.send(i).unwrap();
+        }
+        assert_eq!(rx.recv_chunks(3, None).await, Some(vec![0, 1, 2]));
+        assert_eq!(rx.recv_chunks(3, None).await, Some(vec![3, 4]));
+        drop(tx);
+        // The future is `Send` for `Send` messages.
+        let res = tokio::spawn(async move { rx.recv_chunks(3, None).await });
+        assert_eq!(res.await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_recv_chunk_into_queued() {
+        let (tx, mut rx) = mpsc::channel(16);
+        for i in 1..5 {
+            tx.send(i).await.unwrap();
+        }
+        // The batch in `buf` is completed with the queued messages only.
+        let mut buf = vec![0];
+        assert!(rx.recv_chunk_into(&mut buf, 3, None).await);
+        assert_eq!(buf, [0, 1, 2]);
+        let mut buf = vec![0];
+        assert!(rx.recv_chunk_into(&mut buf, 5, None).await);
+        assert_eq!(buf, [0, 3, 4]);
+    }
+
+    #[tokio::test]
+    async fn test_recv_chunks_local() {
+        // Messages don't need to be `Send` on a `LocalSet`.
+        let local = tokio::task::LocalSet::new();
+        local
+            .run_until(async {
+                let (tx, mut rx) = mpsc::unbounded_channel();
+                tokio::task::spawn_local(async move {
+                    for i in 0..3 {
+                        tx.send(Rc::new(i)).unwrap();
+                    }
+                });
+                let chunk = tokio::task::spawn_local(async move { rx.recv_chunks(3, None).await })
+                    .await
+                    .unwrap()
+                    .unwrap();
+                assert_eq!(chunk.len(), 3);
+            })
+            .await;
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_recv_chunk_into_cancel() {
+        let (tx, mut rx) = mpsc::channel(16);
+        tokio::spawn(async move {
+            for i in 0..4 {
+                tx.send(i).await.unwrap();
+                sleep(Duration::from_millis(10)).await;
+            }
+        });
+
+        // Cancel the batch every 15ms, before it's full or idle.
+        let idle = Some(Duration::from_millis(50));
+        let mut buf = vec![];
+        let mut cancelled = 0;
+        loop {
+            tokio::select! {
+                more = rx.recv_chunk_into(&mut buf, 3, idle) => {
+                    assert!(more);
+                    break;
+                }
+                _ = sleep(Duration::from_millis(15)) => cancelled += 1,
+            }
+        }
+        assert!(cancelled > 0);
+        assert_eq!(buf, [0, 1, 2]);
+
+        buf.clear();
+        assert!(rx.recv_chunk_into(&mut buf, 3, idle).await);
+        assert_eq!(buf, [3]);
+        buf.clear();
+        assert!(!rx.recv_chunk_into(&mut buf, 3, idle).await);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_recv_chunks_idle() {
+        let (tx, mut rx) = mpsc::channel(1);
+        tokio::spawn(async move {
+            for i in 0..3 {
+                tx.send(i).await.unwrap();
+                sleep(Duration::from_millis(5)).await;
+            }
+            sleep(Duration::from_millis(100)).await;
+            tx.send(3).await.unwrap();
+        });
+
+        let idle = Some(Duration::from_millis(10));
+        // Without an idle timeout, only the available messages are returned.
+        assert_eq!(rx.recv_chunks(10, None).await, Some(vec![0]));
+        // Messages 5ms apart are batched, and the 100ms gap ends the batch.
+        assert_eq!(rx.recv_chunks(10, idle).await, Some(vec![1, 2]));
+        assert_eq!(rx.recv_chunks(10, idle).await, Some(vec![3]));
+        assert_eq!(rx.recv_chunks(10, idle).await, None);
+    }
+}
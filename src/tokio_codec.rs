@@ -0,0 +1,136 @@
+use std::io;
+
+use bytes::BytesMut;
+use tokio_util::codec::Decoder;
+
+use crate::IncompleteChunk;
+
+/// A [`Decoder`] that splits a byte stream into frames of exactly `n` bytes.
+///
+/// At the end of the stream, the remaining bytes are emitted as a short final
+/// frame, like the last chunk of [`chunks`]. With [`ChunkDecoder::exact`], an
+/// [`IncompleteChunk`] error is returned for them instead.
+///
+/// ```
+/// use futures::StreamExt;
+/// use iter_chunks::ChunkDecoder;
+/// use tokio_util::codec::FramedRead;
+///
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let reader = &b"hello world"[..];
+/// let frames = FramedRead::new(reader, ChunkDecoder::new(4))
+///     .map(Result::unwrap)
+///     .collect::<Vec<_>>()
+///     .await;
+/// assert_eq!(frames, [&b"hell"[..], b"o wo", b"rld"]);
+/// # });
+/// ```
+///
+/// [`chunks`]: crate::IterChunks::chunks
+#[derive(Debug, Clone)]
+pub struct ChunkDecoder {
+    n: usize,
+    exact: bool,
+}
+
+impl ChunkDecoder {
+    /// Creates a decoder for frames of `n` bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    pub fn new(n: usize) -> Self {
+        assert_ne!(n, 0);
+        Self { n, exact: false }
+    }
+
+    /// Returns an [`io::ErrorKind::UnexpectedEof`] error wrapping an
+    /// [`IncompleteChunk`] for the bytes left at the end of the stream,
+    /// instead of a short final frame.
+    pub fn exact(mut self) -> Self {
+        self.exact = true;
+        self
+    }
+
+    /// Returns the configured frame size `n`.
+    pub fn chunk_size(&self) -> usize {
+        self.n
+    }
+}
+
+impl Decoder for ChunkDecoder {
+    type Item = BytesMut;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        if src.len() < self.n {
+            src.reserve(self.n - src.len());
+            return Ok(None);
+        }
+        Ok(Some(src.split_to(self.n)))
+    }
+
+    fn decode_eof(&mut self, buf: &mut BytesMut) -> Result<Option<Self::Item>, Self::Error> {
+        if let Some(frame) = self.decode(buf)? {
+            return Ok(Some(frame));
+        }
+        if buf.is_empty() {
+            return Ok(None);
+        }
+        if self.exact {
+            let err = IncompleteChunk {
+                expected: self.n,
+                got: buf.len(),
+            };
+            return Err(io::Error::new(io::ErrorKind::UnexpectedEof, err));
+        }
+        Ok(Some(buf.split()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+
+    use bytes::BytesMut;
+    use futures::StreamExt;
+    use tokio_util::codec::{Decoder, FramedRead};
+
+    use super::ChunkDecoder;
+    use crate::IncompleteChunk;
+
+    #[test]
+    fn test_chunk_decoder() {
+        let mut decoder = ChunkDecoder::new(3);
+        let mut buf = BytesMut::from(&b"ab"[..]);
+        assert_eq!(decoder.decode(&mut buf).unwrap(), None);
+        buf.extend_from_slice(b"cdefg");
+        assert_eq!(decoder.decode(&mut buf).unwrap().unwrap(), &b"abc"[..]);
+        assert_eq!(decoder.decode(&mut buf).unwrap().unwrap(), &b"def"[..]);
+        assert_eq!(decoder.decode(&mut buf).unwrap(), None);
+        assert_eq!(decoder.decode_eof(&mut buf).unwrap().unwrap(), &b"g"[..]);
+        assert_eq!(decoder.decode_eof(&mut buf).unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_chunk_decoder_exact() {
+        let reader = &b"abcdefg"[..];
+        let mut frames = FramedRead::new(reader, ChunkDecoder::new(3).exact());
+        assert_eq!(frames.next().await.unwrap().unwrap(), &b"abc"[..]);
+        assert_eq!(frames.next().await.unwrap().unwrap(), &b"def"[..]);
+        let err = frames.next().await.unwrap().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+        let err = err.into_inner().unwrap().downcast::<IncompleteChunk>();
+        assert_eq!(
+            *err.unwrap(),
+            IncompleteChunk {
+                expected: 3,
+                got: 1
+            }
+        );
+
+        let reader = &b"abcdef"[..];
+        let frames = FramedRead::new(reader, ChunkDecoder::new(3).exact());
+        assert_eq!(frames.count().await, 2);
+    }
+}
@@ -0,0 +1,380 @@
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures_util::stream::Stream;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncRead, Lines, ReadBuf};
+use tokio::time::Sleep;
+
+/// A trait that extends tokio's [`AsyncRead`] with `read_chunks` method.
+pub trait AsyncReadChunks: Sized + AsyncRead {
+    /// Create a [`Stream`] that yields the bytes of the reader by chunk of `n`
+    /// bytes, or fewer for the last chunk if the reader ends sooner.
+    ///
+    /// Short reads are merged, so every chunk but the last one has exactly `n`
+    /// bytes, no matter how the underlying reader frames its data. An I/O
+    /// error is yielded as is, and the bytes read before it are kept for the
+    /// next chunk. The stream ends at the first EOF.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    ///
+    /// ```
+    /// use futures::StreamExt;
+    /// use iter_chunks::AsyncReadChunks;
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let reader = &b"hello world"[..];
+    /// let chunks = reader.read_chunks(4).map(Result::unwrap).collect::<Vec<_>>().await;
+    /// assert_eq!(chunks, [&b"hell"[..], b"o wo", b"rld"]);
+    /// # });
+    /// ```
+    fn read_chunks(self, n: usize) -> ReadChunks<Self>;
+}
+
+impl<R: AsyncRead> AsyncReadChunks for R {
+    fn read_chunks(self, n: usize) -> ReadChunks<Self> {
+        assert_ne!(n, 0);
+        ReadChunks {
+            inner: self,
+            buf: vec![0; n],
+            filled: 0,
+            done: false,
+        }
+    }
+}
+
+/// A stream of byte chunks read from an [`AsyncRead`].
+///
+/// This `struct` is created by [`read_chunks`] method on [`AsyncReadChunks`].
+/// See its documentation for more.
+///
+/// Polling it is cancel safe: the bytes of a partial chunk are kept in the
+/// stream, so dropping a `StreamExt::next` future loses nothing.
+///
+/// [`read_chunks`]: AsyncReadChunks::read_chunks
+#[derive(Debug)]
+pub struct ReadChunks<R> {
+    inner: R,
+    buf: Vec<u8>,
+    filled: usize,
+    done: bool,
+}
+
+impl<R> ReadChunks<R> {
+    /// Consumes the stream and returns the underlying reader.
+    ///
+    /// Bytes read into a chunk that has not been yielded are dropped.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Takes the filled part of the buffer as a chunk.
+    fn take_chunk(&mut self) -> Vec<u8> {
+        let n = self.buf.len();
+        let mut chunk = std::mem::replace(&mut self.buf, vec![0; n]);
+        chunk.truncate(self.filled);
+        self.filled = 0;
+        chunk
+    }
+}
+
+impl<R: AsyncRead + Unpin> Stream for ReadChunks<R> {
+    type Item = io::Result<Vec<u8>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if this.done {
+            return Poll::Ready(None);
+        }
+        while this.filled < this.buf.len() {
+            let mut read_buf = ReadBuf::new(&mut this.buf[this.filled..]);
+            match Pin::new(&mut this.inner).poll_read(cx, &mut read_buf) {
+                Poll::Ready(Ok(())) => {
+                    let len = read_buf.filled().len();
+                    if len == 0 {
+                        this.done = true;
+                        if this.filled == 0 {
+                            return Poll::Ready(None);
+                        }
+                        break;
+                    }
+                    this.filled += len;
+                }
+                Poll::Ready(Err(e)) if e.kind() == io::ErrorKind::Interrupted => {}
+                Poll::Ready(Err(e)) => return Poll::Ready(Some(Err(e))),
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+        Poll::Ready(Some(Ok(this.take_chunk())))
+    }
+}
+
+/// A trait that extends tokio's [`AsyncBufRead`] with `line_chunks` method.
+pub trait AsyncBufReadChunks: Sized + AsyncBufRead {
+    /// Create a [`Stream`] that yields the lines of the reader by chunk of `n`
+    /// lines, or fewer for the last chunk if the reader ends sooner.
+    ///
+    /// Lines are split as in [`AsyncBufReadExt::lines`]. An I/O error is
+    /// yielded as is, and the lines read before it are kept for the next
+    /// chunk. Use [`LineChunks::flush_after`] to bound how long a partial chunk
+    /// waits for more lines.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    ///
+    /// ```
+    /// use futures::StreamExt;
+    /// use iter_chunks::AsyncBufReadChunks;
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+    /// let reader = &b"a\nb\nc\n"[..];
+    /// let chunks = reader.line_chunks(2).map(Result::unwrap).collect::<Vec<_>>().await;
+    /// assert_eq!(chunks, vec![vec!["a", "b"], vec!["c"]]);
+    /// # });
+    /// ```
+    fn line_chunks(self, n: usize) -> LineChunks<Self>;
+}
+
+impl<R: AsyncBufRead> AsyncBufReadChunks for R {
+    fn line_chunks(self, n: usize) -> LineChunks<Self> {
+        assert_ne!(n, 0);
+        LineChunks {
+            lines: self.lines(),
+            n,
+            buf: Vec::with_capacity(n),
+            timeout: None,
+            deadline: None,
+            done: false,
+        }
+    }
+}
+
+/// A stream of line chunks read from an [`AsyncBufRead`].
+///
+/// This `struct` is created by [`line_chunks`] method on
+/// [`AsyncBufReadChunks`]. See its documentation for more.
+///
+/// Polling it is cancel safe: the lines of a partial chunk are kept in the
+/// stream, so dropping a `StreamExt::next` future loses nothing.
+///
+/// [`line_chunks`]: AsyncBufReadChunks::line_chunks
+#[derive(Debug)]
+pub struct LineChunks<R> {
+    lines: Lines<R>,
+    n: usize,
+    buf: Vec<String>,
+    timeout: Option<Duration>,
+    // Set when the first line of a chunk arrives, if `timeout` is set.
+    deadline: Option<Pin<Box<Sleep>>>,
+    done: bool,
+}
+
+impl<R> LineChunks<R> {
+    /// Yields a partial chunk once `timeout` has passed since its first line
+    /// arrived, instead of waiting for `n` lines.
+    ///
+    /// This bounds the latency of every line when the reader is slow, such as
+    /// a log file being followed. The stream must be polled within a tokio
+    /// runtime with the time driver enabled.
+    pub fn flush_after(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    fn take_chunk(&mut self) -> Vec<String> {
+        self.deadline = None;
+        std::mem::replace(&mut self.buf, Vec::with_capacity(self.n))
+    }
+}
+
+impl<R: AsyncBufRead + Unpin> LineChunks<R> {
+    /// Consumes the stream and returns the underlying reader.
+    ///
+    /// Lines of a chunk that has not been yielded are dropped.
+    pub fn into_inner(self) -> R {
+        self.lines.into_inner()
+    }
+}
+
+impl<R: AsyncBufRead + Unpin> Stream for LineChunks<R> {
+    type Item = io::Result<Vec<String>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if this.done {
+            return Poll::Ready(None);
+        }
+        while this.buf.len() < this.n {
+            match Pin::new(&mut this.lines).poll_next_line(cx) {
+                Poll::Ready(Ok(Some(line))) => {
+                    if this.buf.is_empty() {
+                        if let Some(timeout) = this.timeout {
+                            this.deadline = Some(Box::pin(tokio::time::sleep(timeout)));
+                        }
+                    }
+                    this.buf.push(line);
+                }
+                Poll::Ready(Ok(None)) => {
+                    this.done = true;
+                    if this.buf.is_empty() {
+                        return Poll::Ready(None);
+                    }
+                    break;
+                }
+                Poll::Ready(Err(e)) => return Poll::Ready(Some(Err(e))),
+                Poll::Pending => {
+                    let expired = match &mut this.deadline {
+                        Some(deadline) => deadline.as_mut().poll(cx).is_ready(),
+                        None => false,
+                    };
+                    if expired {
+                        break;
+                    }
+                    return Poll::Pending;
+                }
+            }
+        }
+        Poll::Ready(Some(Ok(this.take_chunk())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io;
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    use std::time::Duration;
+
+    use futures::StreamExt;
+    use tokio::io::{AsyncRead, AsyncWriteExt, ReadBuf};
+
+    use super::{AsyncBufReadChunks, AsyncReadChunks};
+
+    /// A reader that returns one part per read, in order.
+    struct PartsReader(Vec<Result<&'static [u8], io::ErrorKind>>);
+
+    impl AsyncRead for PartsReader {
+        fn poll_read(
+            mut self: Pin<&mut Self>,
+            _: &mut Context<'_>,
+            buf: &mut ReadBuf<'_>,
+        ) -> Poll<io::Result<()>> {
+            if self.0.is_empty() {
+                return Poll::Ready(Ok(()));
+            }
+            match self.0.remove(0) {
+                Ok(part) => {
+                    let len = part.len().min(buf.remaining());
+                    buf.put_slice(&part[..len]);
+                    if len < part.len() {
+                        self.0.insert(0, Ok(&part[len..]));
+                    }
+                    Poll::Ready(Ok(()))
+                }
+                Err(kind) => Poll::Ready(Err(kind.into())),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_line_chunks() {
+        let reader = PartsReader(vec![Ok(b"a\nb"), Ok(b"c\r\nd\n"), Ok(b"e")]);
+        let chunks = tokio::io::BufReader::new(reader)
+            .line_chunks(2)
+            .map(Result::unwrap)
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(chunks, vec![vec!["a", "bc"], vec!["d", "e"]]);
+
+        // Invalid UTF-8 is an error, and the previous lines are kept.
+        let reader = PartsReader(vec![Ok(b"a\n\xff\nb\n")]);
+        let mut chunks = tokio::io::BufReader::new(reader).line_chunks(2);
+        let err = chunks.next().await.unwrap().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(chunks.next().await.unwrap().unwrap(), vec!["a", "b"]);
+        assert!(chunks.next().await.is_none());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_line_chunks_flush_after() {
+        let (mut client, server) = tokio::io::duplex(64);
+        tokio::spawn(async move {
+            client.write_all(b"a\nb\n").await.unwrap();
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            client.write_all(b"c\nd\ne\n").await.unwrap();
+        });
+        let mut chunks = tokio::io::BufReader::new(server)
+            .line_chunks(3)
+            .flush_after(Duration::from_millis(10));
+        assert_eq!(chunks.next().await.unwrap().unwrap(), vec!["a", "b"]);
+        assert_eq!(chunks.next().await.unwrap().unwrap(), vec!["c", "d", "e"]);
+        assert!(chunks.next().await.is_none());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_line_chunks_cancel() {
+        let (mut client, server) = tokio::io::duplex(64);
+        let mut chunks = tokio::io::BufReader::new(server).line_chunks(2);
+        client.write_all(b"a\n").await.unwrap();
+        // A partial chunk survives a cancelled `next`.
+        tokio::select! {
+            _ = chunks.next() => unreachable!(),
+            _ = tokio::time::sleep(Duration::from_millis(10)) => {}
+        }
+        client.write_all(b"b\nc\n").await.unwrap();
+        drop(client);
+        assert_eq!(chunks.next().await.unwrap().unwrap(), vec!["a", "b"]);
+        assert_eq!(chunks.next().await.unwrap().unwrap(), vec!["c"]);
+        assert!(chunks.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_read_chunks() {
+        // Reads don't align with chunks.
+        let reader = PartsReader(vec![Ok(b"abc"), Ok(b"def"), Ok(b"gh")]);
+        let chunks = reader
+            .read_chunks(4)
+            .map(Result::unwrap)
+            .collect::<Vec<_>>()
+            .await;
+        assert_eq!(chunks, [&b"abcd"[..], b"efgh"]);
+
+        let chunks = (&b""[..]).read_chunks(4).collect::<Vec<_>>().await;
+        assert!(chunks.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_read_chunks_pending() {
+        let (mut client, server) = tokio::io::duplex(64);
+        let mut chunks = server.read_chunks(4);
+        client.write_all(b"abcdef").await.unwrap();
+        assert_eq!(chunks.next().await.unwrap().unwrap(), b"abcd");
+        drop(client);
+        assert_eq!(chunks.next().await.unwrap().unwrap(), b"ef");
+        assert!(chunks.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_read_chunks_error() {
+        let reader = PartsReader(vec![
+            Ok(b"ab"),
+            Err(io::ErrorKind::Interrupted),
+            Ok(b"c"),
+            Err(io::ErrorKind::Other),
+            Ok(b"de"),
+        ]);
+        let mut chunks = reader.read_chunks(4);
+        let err = chunks.next().await.unwrap().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::Other);
+        // The bytes read before the error are kept.
+        assert_eq!(chunks.next().await.unwrap().unwrap(), b"abcd");
+        assert_eq!(chunks.next().await.unwrap().unwrap(), b"e");
+        assert!(chunks.next().await.is_none());
+    }
+}
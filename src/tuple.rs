@@ -0,0 +1,129 @@
+/// A homogeneous tuple that [`TupleChunks`] can yield.
+///
+/// This trait is implemented for tuples of 2 to 4 elements of the same type.
+pub trait TupleChunk: Sized {
+    /// The element type of the tuple.
+    type Item;
+
+    /// The number of elements of the tuple.
+    const ARITY: usize;
+
+    #[doc(hidden)]
+    fn from_buf(buf: &mut Vec<Self::Item>) -> Self;
+}
+
+macro_rules! impl_tuple_chunk {
+    ($arity:expr; $($t:ident)+) => {
+        impl<T> TupleChunk for ($($t,)+) {
+            type Item = T;
+
+            const ARITY: usize = $arity;
+
+            fn from_buf(buf: &mut Vec<T>) -> Self {
+                let mut iter = buf.drain(..);
+                ($({
+                    let v: $t = iter.next().unwrap();
+                    v
+                },)+)
+            }
+        }
+    };
+}
+
+impl_tuple_chunk!(2; T T);
+impl_tuple_chunk!(3; T T T);
+impl_tuple_chunk!(4; T T T T);
+
+/// An iterator that yields elements as fixed-size tuples.
+///
+/// This `struct` is created by [`tuple_chunks`] method on [`IterChunks`]. See
+/// its documentation for more.
+///
+/// [`tuple_chunks`]: crate::IterChunks::tuple_chunks
+/// [`IterChunks`]: crate::IterChunks
+#[derive(Debug, Clone)]
+pub struct TupleChunks<I: Iterator, T> {
+    inner: I,
+    buf: Vec<I::Item>,
+    _marker: std::marker::PhantomData<fn() -> T>,
+}
+
+impl<I, T> TupleChunks<I, T>
+where
+    I: Iterator,
+    T: TupleChunk<Item = I::Item>,
+{
+    pub(crate) fn new(inner: I) -> Self {
+        Self {
+            inner,
+            buf: Vec::with_capacity(T::ARITY),
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Returns the leftover elements that were not enough to fill a tuple.
+    ///
+    /// The leftover is only filled once the underlying iterator ends.
+    pub fn leftover(&self) -> &[I::Item] {
+        &self.buf
+    }
+
+    /// Consumes the adaptor and returns the leftover elements.
+    ///
+    /// See [`TupleChunks::leftover`].
+    pub fn into_leftover(self) -> Vec<I::Item> {
+        self.buf
+    }
+}
+
+impl<I, T> Iterator for TupleChunks<I, T>
+where
+    I: Iterator,
+    T: TupleChunk<Item = I::Item>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Leftover elements of a resumed iterator are still at the front.
+        while self.buf.len() < T::ARITY {
+            self.buf.push(self.inner.next()?);
+        }
+        Some(T::from_buf(&mut self.buf))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        let buffered = self.buf.len();
+        let lower = lower.saturating_add(buffered) / T::ARITY;
+        let upper = upper
+            .and_then(|v| v.checked_add(buffered))
+            .map(|v| v / T::ARITY);
+        (lower, upper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IterChunks;
+
+    #[test]
+    fn test_tuple_chunks() {
+        let mut iter = (0..7).tuple_chunks::<(_, _)>();
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        assert_eq!(iter.by_ref().collect::<Vec<_>>(), [(0, 1), (2, 3), (4, 5)]);
+        assert_eq!(iter.leftover(), [6]);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+
+        let mut iter = (0..6).tuple_chunks::<(_, _, _)>();
+        assert_eq!(iter.by_ref().collect::<Vec<_>>(), [(0, 1, 2), (3, 4, 5)]);
+        assert!(iter.into_leftover().is_empty());
+
+        let mut iter = (0..11).tuple_chunks();
+        let mut sums = vec![];
+        for (a, b, c, d) in iter.by_ref() {
+            sums.push(a + b + c + d);
+        }
+        assert_eq!(sums, [6, 22]);
+        assert_eq!(iter.into_leftover(), [8, 9, 10]);
+    }
+}
@@ -0,0 +1,92 @@
+use std::mem::MaybeUninit;
+
+use crate::{Chunk, Chunks};
+
+impl<I: Iterator> Chunk<'_, I> {
+    /// Drains the chunk into the front of the uninitialized `buf`, and
+    /// returns the initialized part.
+    ///
+    /// Like [`Chunk::copy_to_slice`], the remaining elements are left in the
+    /// chunk if `buf` is too short. Like [`MaybeUninit::write`], the written
+    /// elements are never dropped unless the caller drops them, e.g. with
+    /// [`std::ptr::drop_in_place`].
+    ///
+    /// ```
+    /// use std::mem::MaybeUninit;
+    ///
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut scratch = [MaybeUninit::<f32>::uninit(); 4];
+    /// let mut chunks = [1.0, 2.0, 3.0].into_iter().chunks(4);
+    /// let filled = chunks.next().unwrap().fill_uninit(&mut scratch);
+    /// assert_eq!(filled, [1.0, 2.0, 3.0]);
+    /// ```
+    pub fn fill_uninit<'b>(&mut self, buf: &'b mut [MaybeUninit<I::Item>]) -> &'b mut [I::Item] {
+        let mut len = 0;
+        for (slot, v) in buf.iter_mut().zip(self) {
+            slot.write(v);
+            len += 1;
+        }
+        // SAFETY: The first `len` elements of `buf` are initialized above, and
+        // `MaybeUninit<T>` has the same layout as `T`.
+        unsafe { std::slice::from_raw_parts_mut(buf.as_mut_ptr().cast(), len) }
+    }
+}
+
+impl<I: Iterator> Chunks<I> {
+    /// Similar to [`Chunks::next_buffered`], but fills the uninitialized `buf`
+    /// with the next chunk, see [`Chunk::fill_uninit`].
+    ///
+    /// Returns `None` when [`Chunks::next`] would return `None`. If `buf` is
+    /// shorter than `n`, the rest of the chunk is yielded by the next chunk.
+    ///
+    /// ```
+    /// use std::mem::MaybeUninit;
+    ///
+    /// use iter_chunks::IterChunks;
+    ///
+    /// let mut scratch = [MaybeUninit::<u64>::uninit(); 2];
+    /// let mut chunks = (1..=5).chunks(2);
+    /// let mut sums = vec![];
+    /// while let Some(chunk) = chunks.next_uninit(&mut scratch) {
+    ///     sums.push(chunk.iter().sum::<u64>());
+    /// }
+    /// assert_eq!(sums, [3, 7, 5]);
+    /// ```
+    pub fn next_uninit<'b>(
+        &mut self,
+        buf: &'b mut [MaybeUninit<I::Item>],
+    ) -> Option<&'b mut [I::Item]> {
+        Some(self.next()?.fill_uninit(buf))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::mem::MaybeUninit;
+
+    use crate::IterChunks;
+
+    #[test]
+    fn test_fill_uninit() {
+        let mut buf = [const { MaybeUninit::<String>::uninit() }; 2];
+        let mut chunks = ["a", "b", "c", "d"].into_iter().map(String::from).chunks(3);
+
+        let filled = chunks.next_uninit(&mut buf).unwrap();
+        assert_eq!(filled, ["a", "b"]);
+        // SAFETY: The strings are initialized, and not used afterwards.
+        unsafe { std::ptr::drop_in_place(filled) };
+
+        let filled = chunks.next_uninit(&mut buf).unwrap();
+        assert_eq!(filled, ["c", "d"]);
+        // SAFETY: The strings are initialized, and not used afterwards.
+        unsafe { std::ptr::drop_in_place(filled) };
+        assert!(chunks.next_uninit(&mut buf).is_none());
+
+        let mut chunks = (0..3).chunks(3);
+        let mut chunk = chunks.next().unwrap();
+        assert!(chunk.fill_uninit(&mut []).is_empty());
+        let mut ints = [MaybeUninit::uninit(); 4];
+        assert_eq!(chunk.fill_uninit(&mut ints), [0, 1, 2]);
+    }
+}
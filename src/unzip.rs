@@ -0,0 +1,69 @@
+/// An iterator that yields every chunk of `n` pairs unzipped into two
+/// vectors.
+///
+/// This `struct` is created by [`unzip_chunks`] method on [`IterChunks`].
+/// See its documentation for more.
+///
+/// [`unzip_chunks`]: crate::IterChunks::unzip_chunks
+/// [`IterChunks`]: crate::IterChunks
+#[derive(Debug, Clone)]
+pub struct UnzipChunks<I> {
+    inner: I,
+    n: usize,
+}
+
+impl<I> UnzipChunks<I> {
+    pub(crate) fn new(inner: I, n: usize) -> Self {
+        assert_ne!(n, 0);
+        Self { inner, n }
+    }
+}
+
+impl<I, A, B> Iterator for UnzipChunks<I>
+where
+    I: Iterator<Item = (A, B)>,
+{
+    type Item = (Vec<A>, Vec<B>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (a, b) = self.inner.next()?;
+        let len = self.inner.size_hint().0.min(self.n - 1) + 1;
+        let mut left = Vec::with_capacity(len);
+        let mut right = Vec::with_capacity(len);
+        left.push(a);
+        right.push(b);
+        for (a, b) in self.inner.by_ref().take(self.n - 1) {
+            left.push(a);
+            right.push(b);
+        }
+        Some((left, right))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        (lower.div_ceil(self.n), upper.map(|v| v.div_ceil(self.n)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IterChunks;
+
+    #[test]
+    fn test_unzip_chunks() {
+        let rows = (0..5).map(|i| (i, i.to_string()));
+        let res = rows.unzip_chunks(2).collect::<Vec<_>>();
+        assert_eq!(
+            res,
+            vec![
+                (vec![0, 1], vec!["0".to_string(), "1".to_string()]),
+                (vec![2, 3], vec!["2".to_string(), "3".to_string()]),
+                (vec![4], vec!["4".to_string()]),
+            ]
+        );
+
+        let chunks = std::iter::empty::<(i32, i32)>().unzip_chunks(2);
+        assert_eq!(chunks.size_hint(), (0, Some(0)));
+        assert_eq!(chunks.count(), 0);
+    }
+}
@@ -0,0 +1,234 @@
+use std::collections::VecDeque;
+use std::{error::Error, fmt};
+
+/// What [`WeightChunks`] does with a single element heavier than the budget.
+pub enum OversizedPolicy<T> {
+    /// Yields the element alone in its own chunk, over the budget.
+    OwnChunk,
+    /// Yields an [`OversizedItem`] error holding the element, and goes on with
+    /// the next element.
+    Error,
+    /// Splits the element into pieces with the closure, and chunks the pieces
+    /// in its place. A piece still heavier than the budget is yielded alone in
+    /// its own chunk, and never split again.
+    Split(Box<dyn FnMut(T) -> Vec<T> + Send + Sync>),
+}
+
+impl<T> OversizedPolicy<T> {
+    /// Creates an [`OversizedPolicy::Split`] from a closure.
+    pub fn split(f: impl FnMut(T) -> Vec<T> + Send + Sync + 'static) -> Self {
+        Self::Split(Box::new(f))
+    }
+}
+
+impl<T> fmt::Debug for OversizedPolicy<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OwnChunk => f.write_str("OwnChunk"),
+            Self::Error => f.write_str("Error"),
+            Self::Split(_) => f.write_str("Split(..)"),
+        }
+    }
+}
+
+/// The error yielded by [`WeightChunks`] for an element heavier than the
+/// budget, under [`OversizedPolicy::Error`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OversizedItem<T> {
+    /// The oversized element.
+    pub item: T,
+    /// The weight of the element.
+    pub weight: usize,
+    /// The configured budget.
+    pub budget: usize,
+}
+
+impl<T> fmt::Display for OversizedItem<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "oversized item: weight {} exceeds the budget of {}",
+            self.weight, self.budget
+        )
+    }
+}
+
+impl<T: fmt::Debug> Error for OversizedItem<T> {}
+
+/// An iterator that yields chunks whose total weight stays within a budget.
+///
+/// This `struct` is created by [`chunks_by_weight`] method on [`IterChunks`].
+/// See its documentation for more.
+///
+/// [`chunks_by_weight`]: crate::IterChunks::chunks_by_weight
+/// [`IterChunks`]: crate::IterChunks
+pub struct WeightChunks<I: Iterator, F> {
+    inner: I,
+    budget: usize,
+    weight_fn: F,
+    policy: OversizedPolicy<I::Item>,
+    // The element that closed the previous chunk, with its weight and whether
+    // it's a split piece.
+    pending: Option<(I::Item, usize, bool)>,
+    // The pieces of a split element, yielded before the rest of `inner`.
+    pieces: VecDeque<I::Item>,
+}
+
+impl<I, F> WeightChunks<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> usize,
+{
+    pub(crate) fn new(
+        inner: I,
+        budget: usize,
+        weight_fn: F,
+        policy: OversizedPolicy<I::Item>,
+    ) -> Self {
+        assert_ne!(budget, 0);
+        Self {
+            inner,
+            budget,
+            weight_fn,
+            policy,
+            pending: None,
+            pieces: VecDeque::new(),
+        }
+    }
+
+    /// Returns the configured budget.
+    pub fn budget(&self) -> usize {
+        self.budget
+    }
+
+    fn next_weighted(&mut self) -> Option<(I::Item, usize, bool)> {
+        if let Some(v) = self.pending.take() {
+            return Some(v);
+        }
+        let (v, piece) = match self.pieces.pop_front() {
+            Some(v) => (v, true),
+            None => (self.inner.next()?, false),
+        };
+        let weight = (self.weight_fn)(&v);
+        Some((v, weight, piece))
+    }
+}
+
+impl<I, F> Iterator for WeightChunks<I, F>
+where
+    I: Iterator,
+    F: FnMut(&I::Item) -> usize,
+{
+    type Item = Result<Vec<I::Item>, OversizedItem<I::Item>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut buf = vec![];
+        let mut used = 0;
+        while let Some((v, weight, piece)) = self.next_weighted() {
+            if weight > self.budget {
+                if let (OversizedPolicy::Split(f), false) = (&mut self.policy, piece) {
+                    let pieces = f(v);
+                    for v in pieces.into_iter().rev() {
+                        self.pieces.push_front(v);
+                    }
+                    continue;
+                }
+                // Close the current chunk first, the element is handled on the
+                // next call.
+                if !buf.is_empty() {
+                    self.pending = Some((v, weight, piece));
+                    break;
+                }
+                if let OversizedPolicy::Error = self.policy {
+                    return Some(Err(OversizedItem {
+                        item: v,
+                        weight,
+                        budget: self.budget,
+                    }));
+                }
+                return Some(Ok(vec![v]));
+            }
+            if weight > self.budget - used {
+                self.pending = Some((v, weight, piece));
+                break;
+            }
+            used += weight;
+            buf.push(v);
+        }
+        (!buf.is_empty()).then_some(Ok(buf))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{OversizedItem, OversizedPolicy};
+    use crate::IterChunks;
+
+    #[test]
+    fn test_chunks_by_weight() {
+        let arr = [1, 2, 3, 4, 1, 1, 5];
+        let res = arr
+            .into_iter()
+            .chunks_by_weight(5, |&v| v, OversizedPolicy::OwnChunk)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(res, vec![vec![1, 2], vec![3], vec![4, 1], vec![1], vec![5]]);
+
+        // Zero weights never close a chunk.
+        let res = [0, 0, 3, 0]
+            .into_iter()
+            .chunks_by_weight(2, |&v| v, OversizedPolicy::OwnChunk)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(res, vec![vec![0, 0], vec![3], vec![0]]);
+
+        // The accumulated weight doesn't overflow.
+        let res = [usize::MAX - 1, 2]
+            .into_iter()
+            .chunks_by_weight(usize::MAX, |&v| v, OversizedPolicy::OwnChunk)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(res, vec![vec![usize::MAX - 1], vec![2]]);
+    }
+
+    #[test]
+    fn test_oversized_policy() {
+        let arr = [1, 7, 2, 2, 9];
+
+        let res = arr
+            .into_iter()
+            .chunks_by_weight(4, |&v| v, OversizedPolicy::OwnChunk)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            res,
+            vec![Ok(vec![1]), Ok(vec![7]), Ok(vec![2, 2]), Ok(vec![9])]
+        );
+
+        let res = arr
+            .into_iter()
+            .chunks_by_weight(4, |&v| v, OversizedPolicy::Error)
+            .collect::<Vec<_>>();
+        let err = |item| OversizedItem {
+            item,
+            weight: item,
+            budget: 4,
+        };
+        assert_eq!(
+            res,
+            vec![Ok(vec![1]), Err(err(7)), Ok(vec![2, 2]), Err(err(9))]
+        );
+        assert_eq!(
+            err(7).to_string(),
+            "oversized item: weight 7 exceeds the budget of 4"
+        );
+
+        // 7 is split into 3 + 4, and 9 into 4 + 5, whose 5 is still oversized.
+        let policy = OversizedPolicy::split(|v| vec![v / 2, v - v / 2]);
+        let res = arr
+            .into_iter()
+            .chunks_by_weight(4, |&v| v, policy)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(res, vec![vec![1, 3], vec![4], vec![2, 2], vec![4], vec![5]]);
+    }
+}
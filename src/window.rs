@@ -0,0 +1,128 @@
+use crate::ChunkStrategy;
+
+/// A [`ChunkStrategy`] that closes a chunk whenever the timestamp of the next
+/// element falls into another window of a fixed width.
+///
+/// This `struct` is created by [`chunk_by_window`] method on [`IterChunks`].
+/// See its documentation for more.
+///
+/// [`chunk_by_window`]: crate::IterChunks::chunk_by_window
+/// [`IterChunks`]: crate::IterChunks
+#[derive(Debug, Clone)]
+pub struct TumblingWindow<F> {
+    ts_fn: F,
+    window: u64,
+    // The window index of the current chunk.
+    bucket: Option<u64>,
+}
+
+impl<F> TumblingWindow<F> {
+    pub(crate) fn new(ts_fn: F, window: u64) -> Self {
+        assert_ne!(window, 0);
+        Self {
+            ts_fn,
+            window,
+            bucket: None,
+        }
+    }
+}
+
+impl<T, F> ChunkStrategy<T> for TumblingWindow<F>
+where
+    F: FnMut(&T) -> u64,
+{
+    fn should_close(&mut self, next: &T) -> bool {
+        let bucket = (self.ts_fn)(next) / self.window;
+        if self.bucket.is_some_and(|v| v != bucket) {
+            return true;
+        }
+        self.bucket = Some(bucket);
+        false
+    }
+
+    fn reset(&mut self) {
+        self.bucket = None;
+    }
+}
+
+/// A [`ChunkStrategy`] that closes a chunk whenever the timestamp gap between
+/// two consecutive elements exceeds a threshold.
+///
+/// This `struct` is created by [`sessionize`] method on [`IterChunks`]. See
+/// its documentation for more.
+///
+/// [`sessionize`]: crate::IterChunks::sessionize
+/// [`IterChunks`]: crate::IterChunks
+#[derive(Debug, Clone)]
+pub struct SessionWindow<F> {
+    gap: u64,
+    ts_fn: F,
+    // The timestamp of the last element of the current chunk.
+    last: Option<u64>,
+}
+
+impl<F> SessionWindow<F> {
+    pub(crate) fn new(gap: u64, ts_fn: F) -> Self {
+        Self {
+            gap,
+            ts_fn,
+            last: None,
+        }
+    }
+}
+
+impl<T, F> ChunkStrategy<T> for SessionWindow<F>
+where
+    F: FnMut(&T) -> u64,
+{
+    fn should_close(&mut self, next: &T) -> bool {
+        let ts = (self.ts_fn)(next);
+        if self.last.is_some_and(|v| ts.saturating_sub(v) > self.gap) {
+            return true;
+        }
+        self.last = Some(ts);
+        false
+    }
+
+    fn reset(&mut self) {
+        self.last = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IterChunks;
+
+    #[test]
+    fn test_chunk_by_window() {
+        let ts = [0, 3, 9, 10, 25, 29, 30, 5];
+        let mut chunks = ts.into_iter().chunk_by_window(|&ts| ts, 10);
+        let mut res = vec![];
+        chunks.for_each(|chunk| res.push(chunk.collect::<Vec<_>>()));
+        assert_eq!(
+            res,
+            vec![vec![0, 3, 9], vec![10], vec![25, 29], vec![30], vec![5]]
+        );
+
+        let mut chunks = std::iter::empty::<u64>().chunk_by_window(|&ts| ts, 10);
+        assert!(chunks.next().is_none());
+    }
+
+    #[test]
+    fn test_sessionize() {
+        let ts = [0, 5, 10, 20, 21, 31, 40, 35];
+        let mut chunks = ts.into_iter().sessionize(5, |&ts| ts);
+        let mut res = vec![];
+        chunks.for_each(|chunk| res.push(chunk.collect::<Vec<_>>()));
+        // A gap of exactly 5 stays in the session, and a timestamp going
+        // backwards is a gap of 0.
+        assert_eq!(
+            res,
+            vec![vec![0, 5, 10], vec![20, 21], vec![31], vec![40, 35]]
+        );
+
+        let mut chunks = [1, 1, 1].into_iter().sessionize(0, |&ts| ts);
+        assert_eq!(chunks.next().unwrap().count(), 3);
+        assert!(chunks.next().is_none());
+    }
+}
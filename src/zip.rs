@@ -0,0 +1,150 @@
+use crate::{Chunk, Chunks};
+
+/// A pair of chunks from two chunked streams, or a chunk from one of them
+/// once the other is exhausted.
+///
+/// This `enum` is yielded by [`ZipLongestChunks`].
+pub enum EitherOrBothChunk<'a, A: Iterator, B: Iterator> {
+    /// Chunks from both streams.
+    Both(Chunk<'a, A>, Chunk<'a, B>),
+    /// A chunk from the first stream, after the second one is exhausted.
+    Left(Chunk<'a, A>),
+    /// A chunk from the second stream, after the first one is exhausted.
+    Right(Chunk<'a, B>),
+}
+
+impl<'a, A: Iterator, B: Iterator> EitherOrBothChunk<'a, A, B> {
+    /// Returns `true` if there are chunks from both streams.
+    pub fn is_both(&self) -> bool {
+        matches!(self, EitherOrBothChunk::Both(..))
+    }
+
+    /// Returns the chunk from the first stream, if any.
+    pub fn left(self) -> Option<Chunk<'a, A>> {
+        match self {
+            EitherOrBothChunk::Both(a, _) | EitherOrBothChunk::Left(a) => Some(a),
+            EitherOrBothChunk::Right(_) => None,
+        }
+    }
+
+    /// Returns the chunk from the second stream, if any.
+    pub fn right(self) -> Option<Chunk<'a, B>> {
+        match self {
+            EitherOrBothChunk::Both(_, b) | EitherOrBothChunk::Right(b) => Some(b),
+            EitherOrBothChunk::Left(_) => None,
+        }
+    }
+
+    /// Returns the chunks from both streams, `None` for an exhausted one.
+    pub fn into_options(self) -> (Option<Chunk<'a, A>>, Option<Chunk<'a, B>>) {
+        match self {
+            EitherOrBothChunk::Both(a, b) => (Some(a), Some(b)),
+            EitherOrBothChunk::Left(a) => (Some(a), None),
+            EitherOrBothChunk::Right(b) => (None, Some(b)),
+        }
+    }
+}
+
+/// An iterator-like struct that yields the chunks of two chunked streams in
+/// lockstep, until both are exhausted.
+///
+/// This `struct` is created by [`Chunks::zip_longest_chunks`]. See its
+/// documentation for more.
+pub struct ZipLongestChunks<A: Iterator, B: Iterator> {
+    a: Chunks<A>,
+    b: Chunks<B>,
+    a_done: bool,
+    b_done: bool,
+}
+
+impl<A: Iterator, B: Iterator> ZipLongestChunks<A, B> {
+    pub(crate) fn new(a: Chunks<A>, b: Chunks<B>) -> Self {
+        Self {
+            a,
+            b,
+            a_done: false,
+            b_done: false,
+        }
+    }
+
+    /// Similar to [`Iterator::next`], but not implements [`Iterator`] due to
+    /// lifetime.
+    ///
+    /// Once a stream returns `None`, it's never polled again.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<EitherOrBothChunk<'_, A, B>> {
+        let a = if self.a_done {
+            None
+        } else {
+            let first = self.a.next_first();
+            self.a_done = first.is_none();
+            first
+        };
+        let b = if self.b_done {
+            None
+        } else {
+            let first = self.b.next_first();
+            self.b_done = first.is_none();
+            first
+        };
+        match (a, b) {
+            (Some(a), Some(b)) => Some(EitherOrBothChunk::Both(
+                self.a.chunk_with_first(a),
+                self.b.chunk_with_first(b),
+            )),
+            (Some(a), None) => Some(EitherOrBothChunk::Left(self.a.chunk_with_first(a))),
+            (None, Some(b)) => Some(EitherOrBothChunk::Right(self.b.chunk_with_first(b))),
+            (None, None) => None,
+        }
+    }
+
+    /// Similar to [`Iterator::for_each`].
+    pub fn for_each(&mut self, mut f: impl FnMut(EitherOrBothChunk<'_, A, B>)) {
+        while let Some(item) = self.next() {
+            f(item)
+        }
+    }
+
+    /// Consumes the adaptor and returns the two underlying chunked streams.
+    pub fn into_inner(self) -> (Chunks<A>, Chunks<B>) {
+        (self.a, self.b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IterChunks;
+
+    #[test]
+    fn test_zip_longest_chunks() {
+        let a = (0..5).chunks(2);
+        let b = "abcdefgh".chars().chunks(3);
+        let mut iter = a.zip_longest_chunks(b);
+        let mut res = vec![];
+        while let Some(pair) = iter.next() {
+            let (a, b) = pair.into_options();
+            res.push((
+                a.map(|chunk| chunk.collect::<Vec<_>>()),
+                b.map(|chunk| chunk.collect::<String>()),
+            ));
+        }
+        assert_eq!(
+            res,
+            vec![
+                (Some(vec![0, 1]), Some("abc".to_owned())),
+                (Some(vec![2, 3]), Some("def".to_owned())),
+                (Some(vec![4]), Some("gh".to_owned())),
+            ]
+        );
+        assert!(iter.next().is_none());
+
+        // The longer stream goes on alone.
+        let a = (0..1).chunks(1);
+        let b = (0..5).chunks(2);
+        let mut res = vec![];
+        a.zip_longest_chunks(b).for_each(|pair| {
+            res.push((pair.is_both(), pair.right().unwrap().count()));
+        });
+        assert_eq!(res, vec![(true, 2), (false, 2), (false, 1)]);
+    }
+}